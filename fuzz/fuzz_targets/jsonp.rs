@@ -0,0 +1,11 @@
+#![no_main]
+
+use akinator_rs::parse;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|body: &str| {
+    let json = parse::strip_jsonp(body);
+
+    assert!(body.contains(json));
+    let _ = serde_json::from_str::<serde_json::Value>(json);
+});
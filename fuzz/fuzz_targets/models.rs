@@ -0,0 +1,30 @@
+#![no_main]
+
+use akinator_rs::{
+    models::raw::{MoveJson, StartJson, WinJson},
+    parse,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|body: &str| {
+    let json = parse::strip_jsonp(body);
+
+    if let Ok(start) = serde_json::from_str::<StartJson>(json) {
+        if let Some(params) = start.parameters {
+            let _ = parse::identification(&params.identification);
+            let _ = parse::step(&params.step_information);
+        }
+    }
+
+    if let Ok(Some(params)) = serde_json::from_str::<MoveJson>(json).map(|json| json.parameters) {
+        if let Ok(question) = parse::step(&params) {
+            assert!((0.0..=100.0).contains(&question.progression.value()));
+        }
+    }
+
+    if let Ok(Some(params)) = serde_json::from_str::<WinJson>(json).map(|json| json.parameters) {
+        for guess in parse::guesses(params) {
+            let _ = guess.probability();
+        }
+    }
+});
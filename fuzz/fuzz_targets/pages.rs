@@ -0,0 +1,9 @@
+#![no_main]
+
+use akinator_rs::parse;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|html: &str| {
+    let _ = parse::server_url(html, "1");
+    let _ = parse::session_info(html);
+});
@@ -0,0 +1,61 @@
+/*
+ * C header for the akinator-rs FFI layer, available when built with the `ffi` feature:
+ * `cargo rustc --release --lib --features ffi --crate-type cdylib` (or `staticlib`).
+ *
+ * Strings written to out-params are owned by the caller and must be freed with `akinator_string_free`.
+ * Out-params are only written to when a function returns `AKINATOR_OK`.
+ */
+
+#ifndef AKINATOR_H
+#define AKINATOR_H
+
+#include <stdbool.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct AkinatorHandle AkinatorHandle;
+
+typedef enum AkinatorStatus {
+    AKINATOR_OK = 0,
+    AKINATOR_INVALID_ARGUMENT = 1,
+    AKINATOR_NO_MORE_QUESTIONS = 2,
+    AKINATOR_CANT_GO_BACK_ANY_FURTHER = 3,
+    AKINATOR_ERROR = 4,
+    AKINATOR_PANIC = 5,
+} AkinatorStatus;
+
+/* answers passed into `akinator_answer` */
+enum {
+    AKINATOR_ANSWER_YES = 0,
+    AKINATOR_ANSWER_NO = 1,
+    AKINATOR_ANSWER_IDK = 2,
+    AKINATOR_ANSWER_PROBABLY = 3,
+    AKINATOR_ANSWER_PROBABLY_NOT = 4,
+};
+
+/* `language` and `theme` may be NULL to use the defaults, returns NULL on failure, including unknown ones */
+AkinatorHandle *akinator_new(const char *language, const char *theme, bool child_mode);
+void akinator_free(AkinatorHandle *handle);
+void akinator_string_free(char *string);
+char *akinator_last_error(void);
+
+AkinatorStatus akinator_start(AkinatorHandle *handle, char **out_question);
+AkinatorStatus akinator_answer(AkinatorHandle *handle, int answer, char **out_question);
+AkinatorStatus akinator_back(AkinatorHandle *handle, char **out_question);
+AkinatorStatus akinator_win(
+    AkinatorHandle *handle,
+    char **out_name,
+    char **out_description,
+    char **out_picture_url
+);
+
+float akinator_progression(const AkinatorHandle *handle);
+int akinator_step(const AkinatorHandle *handle);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AKINATOR_H */
@@ -0,0 +1,149 @@
+//! An actor-style API, running a game in its own task driven by messages
+//!
+//! Only available with the `actor` feature enabled.
+//! [`crate::Akinator::spawn`] moves the game into a task and returns an [`AkinatorHandle`],
+//! which can be cheaply cloned and shared across tasks without wrapping the game in an `Arc<Mutex<_>>`.
+//! Commands are processed one at a time in the order they are received.
+//!
+//! ```no_run
+//! # async fn run() -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{Akinator, enums::Answer};
+//!
+//! let handle = Akinator::new()?.spawn();
+//!
+//! handle.start().await?;
+//! handle.answer(Answer::Yes).await?;
+//!
+//! let progression = handle.with(|akinator| akinator.progression).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    Akinator,
+    enums::Answer,
+    error::{Error, Result},
+    models::WinResult,
+};
+
+
+/// the amount of commands that can be queued before senders wait for the game's task
+const COMMAND_BUFFER: usize = 32;
+
+/// a closure run by the game's task with access to the game, see [`AkinatorHandle::with`]
+type Inspect = Box<dyn FnOnce(&Akinator) + Send>;
+
+/// a message sent to the game's task, along with where to send back its result
+enum Command {
+    /// [`Akinator::start`]
+    Start(oneshot::Sender<Result<Option<String>>>),
+    /// [`Akinator::answer`]
+    Answer(Answer, oneshot::Sender<Result<Option<String>>>),
+    /// [`Akinator::back`]
+    Back(oneshot::Sender<Result<Option<String>>>),
+    /// [`Akinator::win`]
+    Win(oneshot::Sender<Result<WinResult>>),
+    /// runs the closure with the game
+    Inspect(Inspect),
+}
+
+/// A cheap, cloneable handle to an akinator game running in its own task, see [`crate::Akinator::spawn`]
+///
+/// the task stops once every handle has been dropped
+#[derive(Debug, Clone)]
+pub struct AkinatorHandle {
+    /// the sending half of the game's command queue
+    sender: mpsc::Sender<Command>,
+}
+
+impl AkinatorHandle {
+    /// internal function spawning the task owning `akinator`
+    pub(crate) fn spawn(akinator: Akinator) -> Self {
+        let (sender, receiver) = mpsc::channel(COMMAND_BUFFER);
+        tokio::spawn(run(akinator, receiver));
+
+        Self { sender }
+    }
+
+    /// internal method sending the command built by `command` and waiting for its result
+    async fn request<T>(&self, command: impl FnOnce(oneshot::Sender<T>) -> Command) -> Result<T> {
+        let (reply, response) = oneshot::channel();
+
+        self.sender.send(command(reply))
+            .await
+            .map_err(|_| Error::TaskStopped)?;
+
+        response.await
+            .map_err(|_| Error::TaskStopped)
+    }
+
+    /// starts the game, see [`Akinator::start`]
+    ///
+    /// # Errors
+    /// [`Error::TaskStopped`] if the game's task has stopped, or the error of [`Akinator::start`]
+    pub async fn start(&self) -> Result<Option<String>> {
+        self.request(Command::Start).await?
+    }
+
+    /// answers the current question, see [`Akinator::answer`]
+    ///
+    /// # Errors
+    /// [`Error::TaskStopped`] if the game's task has stopped, or the error of [`Akinator::answer`]
+    pub async fn answer(&self, answer: Answer) -> Result<Option<String>> {
+        self.request(|reply| Command::Answer(answer, reply)).await?
+    }
+
+    /// goes back to the previous question, see [`Akinator::back`]
+    ///
+    /// # Errors
+    /// [`Error::TaskStopped`] if the game's task has stopped, or the error of [`Akinator::back`]
+    pub async fn back(&self) -> Result<Option<String>> {
+        self.request(Command::Back).await?
+    }
+
+    /// ends the game and makes the akinator guess, see [`Akinator::win`]
+    ///
+    /// # Errors
+    /// [`Error::TaskStopped`] if the game's task has stopped, or the error of [`Akinator::win`]
+    pub async fn win(&self) -> Result<WinResult> {
+        self.request(Command::Win).await?
+    }
+
+    /// runs `f` with the game once the commands sent before it have been processed,
+    /// returning its output, ex: to read the current progression
+    ///
+    /// # Errors
+    /// [`Error::TaskStopped`] if the game's task has stopped
+    pub async fn with<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Akinator) -> T + Send + 'static,
+    {
+        self.request(|reply| Command::Inspect(Box::new(move |akinator| {
+            let _ = reply.send(f(akinator));
+        }))).await
+    }
+
+    /// returns whether or not the game's task has stopped, ex: if it panicked
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+/// internal function processing the commands sent to the game until every handle is dropped
+///
+/// results are dropped if the caller stopped waiting for them
+async fn run(mut akinator: Akinator, mut receiver: mpsc::Receiver<Command>) {
+    while let Some(command) = receiver.recv().await {
+        match command {
+            Command::Start(reply) => { let _ = reply.send(akinator.start().await); },
+            Command::Answer(answer, reply) => { let _ = reply.send(akinator.answer(answer).await); },
+            Command::Back(reply) => { let _ = reply.send(akinator.back().await); },
+            Command::Win(reply) => { let _ = reply.send(akinator.win().await); },
+            Command::Inspect(f) => f(&akinator),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Mapping of a game's state to the akinator's "akitudes" (the mascot's poses shown on the website),
+//! so frontends can show the same visual feedback, see [`crate::Akinator::akitude`]
+
+use std::fmt;
+
+use serde::{Serialize, Deserialize};
+
+
+/// the base URL the official akitude images are served from
+pub const AKITUDE_BASE_URL: &str = "https://en.akinator.com/assets/img/akitudes_670x1096";
+
+/// the step after which the akinator starts looking discouraged when its progression stays low
+const DISCOURAGED_STEP: usize = 30;
+
+/// the step after which the akinator looks truly discouraged when its progression stays low
+const VERY_DISCOURAGED_STEP: usize = 50;
+
+
+/// an official pose of the akinator mascot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Akitude {
+    /// the challenging pose shown at the start of a game
+    Defi,
+    /// the calm pose shown while the akinator has little idea yet
+    Serein,
+    /// the pose shown when the akinator starts having an idea
+    InspirationLegere,
+    /// the pose shown when the akinator has a strong idea
+    InspirationForte,
+    /// the confident pose shown right before guessing
+    Confiant,
+    /// the pose shown when the akinator is about to make a guess
+    Mobile,
+    /// the slightly discouraged pose shown when a game drags on without progress
+    LegerDecouragement,
+    /// the discouraged pose shown when a game drags on for very long without progress
+    VraiDecouragement,
+    /// the disappointed pose shown when a guess was wrong
+    Deception,
+    /// the surprised pose
+    Surprise,
+    /// the triumphant pose shown when a guess was right
+    Triomphe,
+}
+
+impl Akitude {
+    /// returns the akitude the website would show for the given progression and step
+    ///
+    /// only returns the poses reachable while answering questions,
+    /// the others ([`Self::Deception`], [`Self::Surprise`], [`Self::Triomphe`])
+    /// depend on the outcome of a guess and are left up to the frontend
+    #[must_use]
+    pub fn from_progress(progression: f32, step: usize) -> Self {
+        if step == 0 {
+            return Self::Defi;
+        }
+
+        if progression < 50.0 {
+            if step >= VERY_DISCOURAGED_STEP {
+                return Self::VraiDecouragement;
+            }
+            if step >= DISCOURAGED_STEP {
+                return Self::LegerDecouragement;
+            }
+        }
+
+        match progression {
+            p if p < 20.0 => Self::Serein,
+            p if p < 50.0 => Self::InspirationLegere,
+            p if p < 80.0 => Self::InspirationForte,
+            p if p < 95.0 => Self::Confiant,
+            _ => Self::Mobile,
+        }
+    }
+
+    /// returns the official file name of the akitude's image, ex: `inspiration_legere.png`
+    #[must_use]
+    pub fn file_name(self) -> String {
+        format!("{self}.png")
+    }
+
+    /// returns the full URL of the akitude's official image
+    #[must_use]
+    pub fn url(self) -> String {
+        format!("{AKITUDE_BASE_URL}/{}", self.file_name())
+    }
+}
+
+impl fmt::Display for Akitude {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Defi => "defi",
+            Self::Serein => "serein",
+            Self::InspirationLegere => "inspiration_legere",
+            Self::InspirationForte => "inspiration_forte",
+            Self::Confiant => "confiant",
+            Self::Mobile => "mobile",
+            Self::LegerDecouragement => "leger_decouragement",
+            Self::VraiDecouragement => "vrai_decouragement",
+            Self::Deception => "deception",
+            Self::Surprise => "surprise",
+            Self::Triomphe => "triomphe",
+        };
+
+        write!(f, "{name}")
+    }
+}
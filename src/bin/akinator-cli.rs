@@ -0,0 +1,285 @@
+//! A simple command line interface for playing an akinator game in the terminal
+
+use std::{
+    collections::VecDeque,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use clap::{CommandFactory, Parser, error::ErrorKind};
+use serde_json::json;
+use akinator_rs::{
+    Akinator,
+    diagnose::DiagnosticReport,
+    enums::{Language, Theme},
+    error::{Error, Result},
+};
+
+
+/// Play a game of akinator in the terminal
+#[derive(Debug, Parser)]
+#[command(name = "akinator-cli", version)]
+struct Args {
+    /// The language for the akinator game (ex: "en", "french")
+    #[arg(short, long, default_value = "en")]
+    language: Language,
+
+    /// The theme for the akinator game, one of "characters", "animals", or "objects"
+    #[arg(short, long, default_value = "characters")]
+    theme: Theme,
+
+    /// Filter out NSFW questions and content
+    #[arg(short, long)]
+    child_mode: bool,
+
+    /// Filter out NSFW questions without the other child mode restrictions
+    #[arg(long)]
+    nsfw_filter: bool,
+
+    /// Emit questions, progression and guesses as line-delimited JSON instead of human readable text
+    #[arg(long)]
+    json: bool,
+
+    /// A comma separated sequence of answers to replay instead of reading from standard input
+    /// (ex: "y,n,idk,back,p")
+    #[arg(long, conflicts_with = "answers_file")]
+    answers: Option<String>,
+
+    /// A file containing a comma or newline separated sequence of answers to replay
+    /// instead of reading from standard input
+    #[arg(long)]
+    answers_file: Option<PathBuf>,
+
+    /// Check every stage of starting a game and print a report to include in bug reports, instead of playing
+    /// (as a single JSON line with `--json`)
+    #[arg(long)]
+    diagnose: bool,
+}
+
+/// where the answers to the akinator's questions are read from
+enum Input {
+    /// answers are read interactively from standard input
+    Stdin,
+    /// a predetermined sequence of answers is replayed
+    Scripted(VecDeque<String>),
+}
+
+impl Input {
+    /// creates the [`Input`] from the `--answers` / `--answers-file` options,
+    /// defaulting to [`Input::Stdin`]
+    fn from_args(args: &Args) -> Self {
+        let script = if let Some(path) = &args.answers_file {
+            fs::read_to_string(path)
+                .unwrap_or_else(|err| {
+                    Args::command()
+                        .error(ErrorKind::Io, format!("failed to read {}: {err}", path.display()))
+                        .exit()
+                })
+        } else if let Some(answers) = &args.answers {
+            answers.clone()
+        } else {
+            return Self::Stdin;
+        };
+
+        Self::Scripted(
+            script
+                .split([',', '\n'])
+                .map(str::trim)
+                .filter(|answer| !answer.is_empty())
+                .map(str::to_string)
+                .collect()
+        )
+    }
+
+    /// returns the next answer, or [`None`] once there are no more answers to give
+    fn next(&mut self, output: &Output) -> io::Result<Option<String>> {
+        match self {
+            Self::Stdin => {
+                output.prompt("> ")?;
+                read_line()
+            },
+            Self::Scripted(answers) => {
+                let answer = answers.pop_front();
+
+                if let Some(answer) = &answer {
+                    output.prompt(&format!("> {answer}\n"))?;
+                }
+                Ok(answer)
+            },
+        }
+    }
+}
+
+/// handles printing out the game's events,
+/// either as human readable text or as line-delimited JSON
+struct Output {
+    /// whether or not to emit line-delimited JSON
+    json: bool,
+}
+
+impl Output {
+    /// prints out `prompt` before reading an answer, only in human readable mode
+    fn prompt(&self, prompt: &str) -> io::Result<()> {
+        if !self.json {
+            print!("{prompt}");
+            io::stdout().flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// prints out an informational message
+    fn message(&self, message: &str) {
+        if self.json {
+            println!("{}", json!({ "type": "message", "message": message }));
+        } else {
+            println!("{message}");
+        }
+    }
+
+    /// prints out a question along with the current progression
+    fn question(&self, akinator: &Akinator, question: Option<String>) {
+        if self.json {
+            println!("{}", json!({
+                "type": "question",
+                "step": akinator.step,
+                "progression": akinator.progression,
+                "question": question,
+            }));
+        } else {
+            println!(
+                "\n[{:.2}] Q{}: {}",
+                akinator.progression,
+                akinator.step + 1,
+                question.unwrap_or_else(|| "no question".to_string()),
+            );
+        }
+    }
+
+    /// prints out the report of [`Akinator::diagnose`]
+    fn diagnostics(&self, report: &DiagnosticReport) {
+        if !self.json {
+            print!("{report}");
+            return;
+        }
+
+        let stages = report.stages
+            .iter()
+            .map(|stage| match &stage.outcome {
+                Ok(detail) => json!({
+                    "stage": stage.stage.to_string(),
+                    "elapsed_ms": stage.elapsed.as_millis(),
+                    "ok": true,
+                    "detail": detail,
+                }),
+                Err(err) => json!({
+                    "stage": stage.stage.to_string(),
+                    "elapsed_ms": stage.elapsed.as_millis(),
+                    "ok": false,
+                    "kind": err.kind(),
+                    "error": err.to_string(),
+                }),
+            })
+            .collect::<Vec<_>>();
+
+        println!("{}", json!({
+            "type": "diagnostics",
+            "version": env!("CARGO_PKG_VERSION"),
+            "uri": report.uri,
+            "language": report.language,
+            "theme": report.theme,
+            "ok": report.is_ok(),
+            "stages": stages,
+        }));
+    }
+
+    /// prints out the akinator's guesses after the game has ended
+    fn guesses(&self, akinator: &Akinator) {
+        if self.json {
+            println!("{}", json!({
+                "type": "guesses",
+                "guesses": akinator.guesses,
+            }));
+        } else if let Some(guess) = &akinator.first_guess {
+            println!("\nI think of: {}", guess.name);
+            println!("{}", guess.description);
+            println!("{}", guess.absolute_picture_path);
+        } else {
+            println!("\nI have no idea who or what you are thinking of!");
+        }
+    }
+}
+
+/// reads a trimmed line from standard input
+///
+/// returns [`None`] once standard input has been closed
+fn read_line() -> io::Result<Option<String>> {
+    let mut line = String::new();
+
+    if io::stdin().read_line(&mut line)? == 0 {
+        Ok(None)
+    } else {
+        Ok(Some(line.trim().to_string()))
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let output = Output { json: args.json };
+    let mut input = Input::from_args(&args);
+
+    let mut akinator = Akinator::new()?
+        .with_language(args.language)
+        .with_theme(args.theme)
+        .with_nsfw_filter(args.nsfw_filter);
+
+    if args.child_mode {
+        akinator = akinator.with_child_mode();
+    }
+
+    if args.diagnose {
+        output.diagnostics(&akinator.diagnose().await);
+        return Ok(());
+    }
+
+    let question = akinator.start().await?;
+    output.question(&akinator, question);
+
+    if !output.json && matches!(input, Input::Stdin) {
+        println!("(answer with: yes / no / idk / probably / probably not, or \"back\" to undo)");
+    }
+
+    while akinator.progression <= 80.0 {
+        let Some(line) = input.next(&output)
+            .expect("Failed to read input from console")
+        else {
+            if matches!(input, Input::Stdin) {
+                return Ok(());
+            }
+            break;
+        };
+
+        if line.eq_ignore_ascii_case("back") {
+            match akinator.back().await {
+                Ok(question) => output.question(&akinator, question),
+                Err(Error::CantGoBackAnyFurther) => output.message("Cannot go back anymore!"),
+                Err(err) => return Err(err),
+            }
+        } else if let Ok(answer) = akinator.parse_answer(&line) {
+            match akinator.answer(answer).await {
+                Ok(question) => output.question(&akinator, question),
+                Err(Error::NoMoreQuestions) => break,
+                Err(err) => return Err(err),
+            }
+        } else {
+            output.message("Invalid Answer");
+        }
+    }
+
+    akinator.win().await?;
+    output.guesses(&akinator);
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+//! A REST microservice exposing akinator games over HTTP
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use clap::Parser;
+use tokio::net::TcpListener;
+use utoipa::OpenApi;
+use akinator_rs::{
+    save::SESSION_LIFETIME,
+    store::{MemoryStore, SessionStore},
+};
+
+mod routes;
+mod state;
+
+/// how often expired games are deleted from the `--data-dir` directory
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// how often running games idle for longer than `--ttl` are evicted
+const EVICTION_INTERVAL: Duration = Duration::from_secs(60);
+
+
+/// Serve akinator games over a JSON REST API
+#[derive(Debug, Parser)]
+#[command(name = "akinator-server", version)]
+struct Args {
+    /// The address to listen on
+    #[arg(short, long, default_value = "127.0.0.1:3000")]
+    addr: SocketAddr,
+
+    /// How long a running game can stay idle, in seconds, before being evicted from memory,
+    /// defaults to the lifetime of a session
+    #[arg(long, value_name = "SECONDS")]
+    ttl: Option<u64>,
+
+    /// Print the OpenAPI document describing the REST API as JSON and exit
+    #[arg(long)]
+    print_openapi: bool,
+
+    /// The directory to store paused games in, one JSON file per game,
+    /// instead of keeping them in memory
+    #[arg(long)]
+    data_dir: Option<std::path::PathBuf>,
+
+    /// The URL of a Redis server to store paused games in, ex: redis://127.0.0.1:6379,
+    /// instead of keeping them in memory
+    #[cfg(feature = "redis")]
+    #[arg(long)]
+    redis: Option<String>,
+
+    /// The path of an SQLite database to store paused games in, created if it does not exist,
+    /// instead of keeping them in memory
+    #[cfg(feature = "sqlite")]
+    #[arg(long)]
+    sqlite: Option<std::path::PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let args = Args::parse();
+
+    if args.print_openapi {
+        let document = routes::ApiDoc::openapi()
+            .to_pretty_json()
+            .map_err(std::io::Error::other)?;
+
+        println!("{document}");
+        return Ok(());
+    }
+
+    let ttl = args.ttl.map_or(SESSION_LIFETIME, Duration::from_secs);
+    let store = store(&args)
+        .await?
+        .unwrap_or_else(|| Arc::new(MemoryStore::new()));
+
+    let state = state::AppState::new(store, ttl);
+    state.spawn_eviction_task(EVICTION_INTERVAL);
+
+    let listener = TcpListener::bind(args.addr).await?;
+    println!("listening on http://{}", listener.local_addr()?);
+
+    axum::serve(listener, routes::router(state)).await
+}
+
+/// returns the session store selected with the command line arguments, if any
+async fn store(args: &Args) -> std::io::Result<Option<Arc<dyn SessionStore>>> {
+    #[cfg(feature = "redis")]
+    if let Some(url) = &args.redis {
+        let store = akinator_rs::store::RedisStore::connect(url)
+            .await
+            .map_err(std::io::Error::other)?;
+
+        return Ok(Some(Arc::new(store)));
+    }
+
+    #[cfg(feature = "sqlite")]
+    if let Some(path) = &args.sqlite {
+        let store = akinator_rs::store::SqliteStore::open(path)
+            .map_err(std::io::Error::other)?;
+
+        return Ok(Some(Arc::new(store)));
+    }
+
+    if let Some(dir) = &args.data_dir {
+        let store = Arc::new(akinator_rs::store::FileStore::new(dir));
+        store.spawn_cleanup_task(CLEANUP_INTERVAL);
+
+        return Ok(Some(store));
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,409 @@
+//! The routes and JSON models of the REST API
+
+use std::convert::Infallible;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+    routing::{delete, get, post},
+};
+use serde::{Deserialize, Serialize};
+use tokio_stream::{
+    Stream, StreamExt,
+    once,
+    wrappers::BroadcastStream,
+};
+use utoipa::{OpenApi, ToSchema};
+use uuid::Uuid;
+use akinator_rs::{
+    Akinator,
+    enums::{Answer, Language, Theme},
+    error::Error,
+    models::Guess,
+};
+
+use crate::state::{AppState, Game};
+
+
+/// the OpenAPI document describing the REST API
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "akinator-server", description = "A REST API for playing akinator games"),
+    paths(new_game, delete_game, pause, answer, back, win, events),
+    components(schemas(Answer, Guess)),
+)]
+pub struct ApiDoc;
+
+/// the JSON body of an error response
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorResponse {
+    /// a description of the error
+    pub error: String,
+}
+
+/// the error returned from the routes, serialized as an [`ErrorResponse`]
+pub struct ApiError {
+    /// the HTTP status code to respond with
+    status: StatusCode,
+    /// a description of the error
+    message: String,
+}
+
+impl ApiError {
+    /// the error returned when no game exists with the requested id
+    fn game_not_found() -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            message: "Game not found".to_string(),
+        }
+    }
+}
+
+impl From<Error> for ApiError {
+    fn from(err: Error) -> Self {
+        let status = match err {
+            Error::InvalidAnswer | Error::InvalidLanguage => StatusCode::BAD_REQUEST,
+            Error::CantGoBackAnyFurther | Error::NoMoreQuestions | Error::WrongState { .. } => StatusCode::CONFLICT,
+            Error::StoreError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::DeadlineExceeded | Error::CallTimeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            _ => StatusCode::BAD_GATEWAY,
+        };
+
+        Self { status, message: err.to_string() }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = Json(ErrorResponse { error: self.message });
+
+        (self.status, body).into_response()
+    }
+}
+
+/// the body of `POST /games`
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(default)]
+pub struct NewGame {
+    /// the language for the game (ex: "en", "french"), defaults to english
+    #[schema(example = "en")]
+    pub language: Option<String>,
+    /// the theme for the game, one of "characters", "animals", or "objects"
+    #[schema(example = "characters")]
+    pub theme: Option<String>,
+    /// whether or not to filter out NSFW questions and content
+    pub child_mode: bool,
+    /// whether or not to filter out NSFW questions, without the other child mode restrictions
+    pub nsfw_filter: bool,
+}
+
+/// the body of `POST /games/{id}/answer`
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AnswerBody {
+    /// the answer to the current question,
+    /// shorthands such as "y", "n", "idk", "p" and "pn" are also accepted
+    #[schema(value_type = Answer)]
+    pub answer: String,
+}
+
+/// the current question of a game
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuestionResponse {
+    /// the id of the game
+    pub id: Uuid,
+    /// the current question to answer
+    pub question: Option<String>,
+    /// the progress of the akinator, a float out of 100.0
+    pub progression: f32,
+    /// the amount of questions asked and answered
+    pub step: usize,
+}
+
+impl QuestionResponse {
+    /// creates the response from the current state of a game
+    fn new(id: Uuid, akinator: &Akinator) -> Self {
+        Self {
+            id,
+            question: akinator.current_question.clone(),
+            progression: akinator.progression.value(),
+            step: akinator.step,
+        }
+    }
+}
+
+/// the guesses made by the akinator at the end of a game
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WinResponse {
+    /// the id of the game
+    pub id: Uuid,
+    /// the akinator's best guess
+    pub guess: Option<Guess>,
+    /// all the possible guesses by the akinator
+    pub guesses: Vec<Guess>,
+}
+
+/// an event streamed to the subscribers of `GET /games/{id}/events`
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    /// a new question has been asked, sent as a `question` event
+    Question(QuestionResponse),
+    /// the akinator has made its guesses, sent as a `guesses` event
+    Guesses(WinResponse),
+}
+
+impl GameEvent {
+    /// converts the event into a server-sent [`Event`]
+    fn into_sse(self) -> Event {
+        let event = match self {
+            Self::Question(question) => Event::default()
+                .event("question")
+                .json_data(question),
+            Self::Guesses(guesses) => Event::default()
+                .event("guesses")
+                .json_data(guesses),
+        };
+
+        event.expect("Failed to serialize event data")
+    }
+}
+
+/// builds the router for the REST API
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/games", post(new_game))
+        .route("/games/{id}", delete(delete_game))
+        .route("/games/{id}/pause", post(pause))
+        .route("/games/{id}/answer", post(answer))
+        .route("/games/{id}/back", post(back))
+        .route("/games/{id}/win", post(win))
+        .route("/games/{id}/events", get(events))
+        .route("/openapi.json", get(openapi))
+        .with_state(state)
+}
+
+/// internal function retrieving the game with the given id, resuming it if it was paused
+async fn game(state: &AppState, id: &Uuid) -> Result<Game, ApiError> {
+    state.resume(id)
+        .await?
+        .ok_or_else(ApiError::game_not_found)
+}
+
+/// `GET /openapi.json`: returns the OpenAPI document describing the REST API
+async fn openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+/// `POST /games`: starts a new game and returns its first question
+#[utoipa::path(
+    post,
+    path = "/games",
+    request_body(content = Option<NewGame>, content_type = "application/json"),
+    responses(
+        (status = 201, description = "The game was started", body = QuestionResponse),
+        (status = 400, description = "An invalid language was passed", body = ErrorResponse),
+        (status = 502, description = "Failed to start the game", body = ErrorResponse),
+    ),
+)]
+async fn new_game(
+    State(state): State<AppState>,
+    body: Option<Json<NewGame>>,
+) -> Result<(StatusCode, Json<QuestionResponse>), ApiError> {
+    let Json(body) = body.unwrap_or_default();
+
+    let mut akinator = Akinator::new()?
+        .with_language(
+            body.language
+                .as_deref()
+                .map_or(Ok(Language::default()), str::parse)?
+        )
+        .with_theme(
+            body.theme
+                .map_or_else(Theme::default, Theme::from)
+        );
+    akinator.child_mode = body.child_mode;
+    akinator.nsfw_filter = body.nsfw_filter;
+
+    akinator.start().await?;
+
+    let (id, game) = state.insert(akinator);
+    let akinator = game.akinator.lock().await;
+
+    Ok((StatusCode::CREATED, Json(QuestionResponse::new(id, &akinator))))
+}
+
+/// `DELETE /games/{id}`: removes a game from the registry, whether it is running or paused
+#[utoipa::path(
+    delete,
+    path = "/games/{id}",
+    params(("id" = Uuid, Path, description = "The id of the game")),
+    responses(
+        (status = 204, description = "The game was removed"),
+        (status = 404, description = "The game was not found", body = ErrorResponse),
+        (status = 500, description = "Failed to remove the paused game", body = ErrorResponse),
+    ),
+)]
+async fn delete_game(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    if state.remove(&id).await? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::game_not_found())
+    }
+}
+
+/// `POST /games/{id}/pause`: moves a game out of memory into the session store,
+/// it is resumed by the next request made to it
+#[utoipa::path(
+    post,
+    path = "/games/{id}/pause",
+    params(("id" = Uuid, Path, description = "The id of the game")),
+    responses(
+        (status = 204, description = "The game was paused"),
+        (status = 404, description = "The game was not found", body = ErrorResponse),
+        (status = 500, description = "Failed to store the game", body = ErrorResponse),
+    ),
+)]
+async fn pause(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    if state.pause(&id).await? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiError::game_not_found())
+    }
+}
+
+/// `POST /games/{id}/answer`: answers the current question and returns the next one
+#[utoipa::path(
+    post,
+    path = "/games/{id}/answer",
+    params(("id" = Uuid, Path, description = "The id of the game")),
+    request_body = AnswerBody,
+    responses(
+        (status = 200, description = "The next question", body = QuestionResponse),
+        (status = 400, description = "An invalid answer was passed", body = ErrorResponse),
+        (status = 404, description = "The game was not found", body = ErrorResponse),
+        (status = 409, description = "There are no more available questions", body = ErrorResponse),
+        (status = 502, description = "Failed to answer the question", body = ErrorResponse),
+    ),
+)]
+async fn answer(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(body): Json<AnswerBody>,
+) -> Result<Json<QuestionResponse>, ApiError> {
+    let answer = body.answer.parse::<Answer>()?;
+
+    let game = game(&state, &id).await?;
+    let mut akinator = game.akinator.lock().await;
+
+    akinator.answer(answer).await?;
+
+    let response = QuestionResponse::new(id, &akinator);
+    game.emit(GameEvent::Question(response.clone()));
+
+    Ok(Json(response))
+}
+
+/// `POST /games/{id}/back`: goes back one question and returns it
+#[utoipa::path(
+    post,
+    path = "/games/{id}/back",
+    params(("id" = Uuid, Path, description = "The id of the game")),
+    responses(
+        (status = 200, description = "The previous question", body = QuestionResponse),
+        (status = 404, description = "The game was not found", body = ErrorResponse),
+        (status = 409, description = "Already on the first question", body = ErrorResponse),
+        (status = 502, description = "Failed to go back", body = ErrorResponse),
+    ),
+)]
+async fn back(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<QuestionResponse>, ApiError> {
+    let game = game(&state, &id).await?;
+    let mut akinator = game.akinator.lock().await;
+
+    akinator.back().await?;
+
+    let response = QuestionResponse::new(id, &akinator);
+    game.emit(GameEvent::Question(response.clone()));
+
+    Ok(Json(response))
+}
+
+/// `POST /games/{id}/win`: tells the akinator to make its guesses
+#[utoipa::path(
+    post,
+    path = "/games/{id}/win",
+    params(("id" = Uuid, Path, description = "The id of the game")),
+    responses(
+        (status = 200, description = "The akinator's guesses", body = WinResponse),
+        (status = 404, description = "The game was not found", body = ErrorResponse),
+        (status = 502, description = "Failed to make the guesses", body = ErrorResponse),
+    ),
+)]
+async fn win(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WinResponse>, ApiError> {
+    let game = game(&state, &id).await?;
+    let mut akinator = game.akinator.lock().await;
+
+    let result = akinator.win().await?;
+
+    let response = WinResponse {
+        id,
+        guess: result.first,
+        guesses: result.all.into_vec(),
+    };
+    game.emit(GameEvent::Guesses(response.clone()));
+
+    Ok(Json(response))
+}
+
+/// `GET /games/{id}/events`: streams the game's events as server-sent events,
+/// starting with the current question
+#[utoipa::path(
+    get,
+    path = "/games/{id}/events",
+    params(("id" = Uuid, Path, description = "The id of the game")),
+    responses(
+        (
+            status = 200,
+            description = "A stream of `question` events containing a `QuestionResponse` \
+                and `guesses` events containing a `WinResponse`",
+            content_type = "text/event-stream",
+        ),
+        (status = 404, description = "The game was not found", body = ErrorResponse),
+    ),
+)]
+async fn events(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let game = game(&state, &id).await?;
+
+    let (current, receiver) = {
+        let akinator = game.akinator.lock().await;
+        (QuestionResponse::new(id, &akinator), game.events.subscribe())
+    };
+
+    // lagging subscribers simply skip the events they missed
+    let stream = once(GameEvent::Question(current))
+        .chain(
+            BroadcastStream::new(receiver)
+                .filter_map(Result::ok)
+        )
+        .map(|event| Ok(event.into_sse()));
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
@@ -0,0 +1,136 @@
+//! The shared state of the server, holding the registry of running games
+//! and the store of paused ones
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex as StdMutex},
+    time::Duration,
+};
+
+use tokio::{sync::broadcast, task::JoinHandle};
+use uuid::Uuid;
+use akinator_rs::{
+    Akinator,
+    error::Result,
+    registry::{GameRegistry, SharedGame},
+    store::SessionStore,
+};
+
+use crate::routes::GameEvent;
+
+
+/// the amount of events buffered for each game's subscribers before lagging ones start missing events
+const EVENT_CAPACITY: usize = 16;
+
+/// the senders used to stream each running game's events to its subscribers, keyed by the game's id
+type EventSenders = Arc<StdMutex<HashMap<Uuid, broadcast::Sender<GameEvent>>>>;
+
+/// a running game, shared between requests
+pub struct Game {
+    /// the akinator game
+    pub akinator: SharedGame,
+    /// the sender used to stream the game's events to its subscribers
+    pub events: broadcast::Sender<GameEvent>,
+}
+
+impl Game {
+    /// sends an event to the game's subscribers, ignoring the case where there are none
+    pub fn emit(&self, event: GameEvent) {
+        let _ = self.events.send(event);
+    }
+}
+
+/// the shared state of the server
+#[derive(Clone)]
+pub struct AppState {
+    /// the registry of running games, keyed by their id and evicted once idle for too long,
+    /// along with the store of paused ones
+    games: Arc<GameRegistry<Uuid>>,
+    /// the event senders of the running games, dropped along with their game
+    events: EventSenders,
+}
+
+impl AppState {
+    /// creates the state of the server, pausing games into `store`
+    /// and evicting running games once they have been idle for longer than `ttl`
+    pub fn new(store: Arc<dyn SessionStore>, ttl: Duration) -> Self {
+        let events = EventSenders::default();
+        let evicted = Arc::clone(&events);
+
+        let games = GameRegistry::new(ttl)
+            .with_store(store)
+            .with_eviction_hook(move |id, _| {
+                lock(&evicted).remove(id);
+            });
+
+        Self {
+            games: Arc::new(games),
+            events,
+        }
+    }
+
+    /// spawns a task evicting the games that have been idle for too long every `interval`
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime
+    pub fn spawn_eviction_task(&self, interval: Duration) -> JoinHandle<()> {
+        self.games.spawn_eviction_task(interval)
+    }
+
+    /// internal method pairing a running game with its event sender, creating it if needed
+    fn game(&self, id: Uuid, akinator: SharedGame) -> Game {
+        let events = lock(&self.events)
+            .entry(id)
+            .or_insert_with(|| broadcast::channel(EVENT_CAPACITY).0)
+            .clone();
+
+        Game { akinator, events }
+    }
+
+    /// registers a new game, returning its id along with the registered game
+    pub fn insert(&self, akinator: Akinator) -> (Uuid, Game) {
+        let id = Uuid::new_v4();
+        (id, self.game(id, self.games.insert(id, akinator)))
+    }
+
+    /// returns the running game with the given id, resuming it if it was paused with [`Self::pause`]
+    pub async fn resume(&self, id: &Uuid) -> Result<Option<Game>> {
+        Ok(self.games
+            .resume(id)
+            .await?
+            .map(|akinator| self.game(*id, akinator)))
+    }
+
+    /// pauses the game with the given id, moving it to the store until its session expires,
+    /// returning whether or not it existed
+    pub async fn pause(&self, id: &Uuid) -> Result<bool> {
+        if !self.games.pause(id).await? {
+            return Ok(false);
+        }
+
+        // pausing bypasses the eviction hooks, the game's subscribers are dropped here instead
+        lock(&self.events).remove(id);
+        Ok(true)
+    }
+
+    /// removes the game with the given id, running or paused, returning whether or not it existed
+    pub async fn remove(&self, id: &Uuid) -> Result<bool> {
+        let removed = self.games
+            .remove(id)
+            .is_some();
+
+        let store = self.games.store();
+        let key = id.to_string();
+        let paused = store.get(&key).await?.is_some();
+        store.delete(&key).await?;
+
+        Ok(removed || paused)
+    }
+}
+
+/// internal function locking the event senders, recovering from a poisoned lock
+fn lock(events: &EventSenders) -> std::sync::MutexGuard<'_, HashMap<Uuid, broadcast::Sender<GameEvent>>> {
+    events
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
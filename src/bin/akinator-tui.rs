@@ -0,0 +1,288 @@
+//! An interactive terminal user interface for playing an akinator game
+
+use clap::Parser;
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Gauge, List, ListItem, ListState, Paragraph, Wrap},
+};
+use akinator_rs::{
+    Akinator,
+    enums::{Answer, Language, Theme},
+    error::{Error, Result},
+};
+
+
+/// Play a game of akinator in an interactive terminal user interface
+#[derive(Debug, Parser)]
+#[command(name = "akinator-tui", version)]
+struct Args {
+    /// The language for the akinator game (ex: "en", "french")
+    #[arg(short, long, default_value = "en")]
+    language: Language,
+
+    /// The theme for the akinator game, one of "characters", "animals", or "objects"
+    #[arg(short, long, default_value = "characters")]
+    theme: Theme,
+
+    /// Filter out NSFW questions and content
+    #[arg(short, long)]
+    child_mode: bool,
+
+    /// Filter out NSFW questions without the other child mode restrictions
+    #[arg(long)]
+    nsfw_filter: bool,
+}
+
+/// the screen currently being displayed
+enum Screen {
+    /// the akinator is asking questions
+    Playing,
+    /// the game has ended and the guesses are displayed in a gallery
+    Guesses,
+}
+
+/// the state of the terminal user interface
+struct App {
+    /// the akinator game being played
+    akinator: Akinator,
+    /// the question currently being asked
+    question: Option<String>,
+    /// the screen currently being displayed
+    screen: Screen,
+    /// the selected guess in the guess gallery
+    selected: ListState,
+    /// a status message displayed at the bottom of the screen, for errors and such
+    status: String,
+    /// whether or not the user has requested to quit
+    quit: bool,
+}
+
+impl App {
+    /// handles a key press, making the appropriate calls to the akinator
+    async fn handle_key(&mut self, code: KeyCode) {
+        self.status.clear();
+
+        match (&self.screen, code) {
+            (_, KeyCode::Char('q') | KeyCode::Esc) => self.quit = true,
+            (Screen::Playing, KeyCode::Char('b')) => {
+                match self.akinator.back().await {
+                    Ok(question) => self.question = question,
+                    Err(err) => self.status = err.to_string(),
+                }
+            },
+            (Screen::Playing, KeyCode::Char('w')) => self.win().await,
+            (Screen::Playing, KeyCode::Char(key)) => {
+                let answer = match key {
+                    'y' => Answer::Yes,
+                    'n' => Answer::No,
+                    'i' => Answer::Idk,
+                    'p' => Answer::Probably,
+                    'o' => Answer::ProbablyNot,
+                    _ => return,
+                };
+
+                match self.akinator.answer(answer).await {
+                    Ok(question) => {
+                        self.question = question;
+
+                        if self.akinator.progression > 80.0 {
+                            self.win().await;
+                        }
+                    },
+                    Err(Error::NoMoreQuestions) => self.win().await,
+                    Err(err) => self.status = err.to_string(),
+                }
+            },
+            (Screen::Guesses, KeyCode::Down | KeyCode::Char('j')) => self.selected.select_next(),
+            (Screen::Guesses, KeyCode::Up | KeyCode::Char('k')) => self.selected.select_previous(),
+            (Screen::Guesses, KeyCode::Char('m')) => {
+                match self.akinator.more_guesses().await {
+                    Ok(guesses) if guesses.is_empty() =>
+                        self.status = "The akinator has no more guesses".to_string(),
+                    Ok(_) => {},
+                    Err(err) => self.status = err.to_string(),
+                }
+            },
+            _ => {},
+        }
+    }
+
+    /// tells the akinator to make its guesses and switches to the guess gallery
+    async fn win(&mut self) {
+        match self.akinator.win().await {
+            Ok(_) => {
+                self.screen = Screen::Guesses;
+                self.selected.select_first();
+            },
+            Err(err) => self.status = err.to_string(),
+        }
+    }
+
+    /// draws the current screen
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, body, help, status] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(5),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let title = format!(
+            " Akinator — {:?} / {} ",
+            self.akinator.theme,
+            self.akinator.language,
+        );
+        frame.render_widget(
+            Gauge::default()
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .gauge_style(Style::default().fg(Color::Yellow))
+                .ratio(f64::from(self.akinator.progression.as_fraction()))
+                .label(format!("{:.2}", self.akinator.progression)),
+            header,
+        );
+
+        match self.screen {
+            Screen::Playing => {
+                self.draw_question(frame, body);
+                frame.render_widget(
+                    Paragraph::new(
+                        "[y] yes  [n] no  [i] don't know  [p] probably  [o] probably not  [b] back  [w] guess  [q] quit"
+                    ),
+                    help,
+                );
+            },
+            Screen::Guesses => {
+                self.draw_guesses(frame, body);
+                frame.render_widget(
+                    Paragraph::new("[↑/k] previous  [↓/j] next  [m] more guesses  [q] quit"),
+                    help,
+                );
+            },
+        }
+
+        frame.render_widget(
+            Paragraph::new(self.status.as_str())
+                .style(Style::default().fg(Color::Red)),
+            status,
+        );
+    }
+
+    /// draws the question pane
+    fn draw_question(&self, frame: &mut Frame, area: Rect) {
+        let question = self.question
+            .as_deref()
+            .unwrap_or("no question");
+
+        frame.render_widget(
+            Paragraph::new(question)
+                .wrap(Wrap { trim: true })
+                .style(Style::default().add_modifier(Modifier::BOLD))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!(" Question {} ", self.akinator.step + 1)),
+                ),
+            area,
+        );
+    }
+
+    /// draws the guess gallery, a list of guesses alongside the selected guess' details
+    fn draw_guesses(&mut self, frame: &mut Frame, area: Rect) {
+        let [list, details] = Layout::horizontal([
+            Constraint::Percentage(35),
+            Constraint::Percentage(65),
+        ])
+        .areas(area);
+
+        let items = self.akinator.guesses
+            .iter()
+            .map(|guess| ListItem::new(format!("{}. {}", guess.ranking, guess.name)))
+            .collect::<Vec<ListItem>>();
+
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(Block::default().borders(Borders::ALL).title(" Guesses "))
+                .highlight_style(Style::default().fg(Color::Black).bg(Color::Yellow))
+                .highlight_symbol("> "),
+            list,
+            &mut self.selected,
+        );
+
+        let text = self.selected
+            .selected()
+            .and_then(|index| self.akinator.guesses.get(index))
+            .map_or_else(
+                || vec![Line::from("The akinator has no idea who or what you are thinking of!")],
+                |guess| vec![
+                    Line::from(Span::styled(
+                        guess.name.clone(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Line::from(guess.description.clone()),
+                    Line::from(""),
+                    Line::from(format!("confidence: {}", guess.confidence)),
+                    Line::from(format!("picture: {}", guess.absolute_picture_path)),
+                ],
+            );
+
+        frame.render_widget(
+            Paragraph::new(text)
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL).title(" Details ")),
+            details,
+        );
+    }
+}
+
+/// runs the main event loop until the user quits
+async fn run(terminal: &mut DefaultTerminal, app: &mut App) -> std::io::Result<()> {
+    while !app.quit {
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                app.handle_key(key.code).await;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let mut akinator = Akinator::new()?
+        .with_language(args.language)
+        .with_theme(args.theme)
+        .with_nsfw_filter(args.nsfw_filter);
+
+    if args.child_mode {
+        akinator = akinator.with_child_mode();
+    }
+
+    let question = akinator.start().await?;
+
+    let mut app = App {
+        akinator,
+        question,
+        screen: Screen::Playing,
+        selected: ListState::default(),
+        status: String::new(),
+        quit: false,
+    };
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app).await;
+    ratatui::restore();
+
+    result.expect("Failed to draw to the terminal");
+
+    Ok(())
+}
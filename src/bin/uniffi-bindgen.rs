@@ -0,0 +1,5 @@
+//! The UniFFI bindings generator, used to generate the Swift and Kotlin bindings
+
+fn main() {
+    uniffi::uniffi_bindgen_main();
+}
@@ -0,0 +1,53 @@
+//! A process-wide cache of the akinator servers found for each language and theme,
+//! avoiding re-downloading and scanning the homepage for every new game, see [`crate::Akinator::with_server_cache_ttl`]
+
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use lazy_static::lazy_static;
+
+use crate::enums::{Language, Theme};
+
+
+/// how long found servers are cached by default
+pub const DEFAULT_SERVER_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+lazy_static! {
+    /// the cached server URLs, along with when they were found
+    static ref SERVERS: Mutex<HashMap<(Language, Theme), (String, Instant)>> = Mutex::new(HashMap::new());
+}
+
+/// internal function returning the cached server for `language` and `theme`, if it is younger than `ttl`
+pub(crate) fn get(language: Language, theme: Theme, ttl: Duration) -> Option<String> {
+    let servers = SERVERS.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    servers.get(&(language, theme))
+        .filter(|(_, found_at)| found_at.elapsed() < ttl)
+        .map(|(url, _)| url.clone())
+}
+
+/// internal function caching the server found for `language` and `theme`
+pub(crate) fn insert(language: Language, theme: Theme, url: String) {
+    SERVERS.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert((language, theme), (url, Instant::now()));
+}
+
+/// removes the cached server for `language` and `theme`,
+/// for example after it stopped responding
+pub fn invalidate(language: Language, theme: Theme) {
+    SERVERS.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(&(language, theme));
+}
+
+/// removes every cached server
+pub fn clear() {
+    SERVERS.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clear();
+}
@@ -0,0 +1,54 @@
+//! Detection of the anti-bot challenge pages (ex: Cloudflare's "Just a moment...") sometimes served by the website,
+//! and an extension point to plug in a solver, see [`crate::Akinator::with_challenge_solver`]
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+};
+
+use reqwest::{
+    StatusCode,
+    Url,
+    header::HeaderMap,
+};
+
+use crate::transport::BoxError;
+
+
+/// markers found in the body of challenge and interstitial pages
+const CHALLENGE_MARKERS: &[&str] = &[
+    "challenge-platform",
+    "cf-chl-",
+    "cf_chl_opt",
+    "<title>Just a moment...</title>",
+    "<title>Attention Required! | Cloudflare</title>",
+];
+
+/// the future returned by [`ChallengeSolver::solve`]
+pub type SolverFuture<'a> = Pin<Box<dyn Future<Output = std::result::Result<Vec<String>, BoxError>> + Send + 'a>>;
+
+/// an anti-bot challenge page served instead of the requested page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    /// the URL of the request that was challenged
+    pub url: Url,
+    /// the status code of the challenge page
+    pub status: StatusCode,
+    /// the full body of the challenge page
+    pub body: String,
+}
+
+/// a solver for anti-bot challenges, ex: a headless browser or an external solving service
+pub trait ChallengeSolver: fmt::Debug + Send + Sync {
+    /// solves `challenge`, returning the cookies to send when retrying the request,
+    /// as `name=value` strings (ex: `cf_clearance=...`)
+    fn solve<'a>(&'a self, challenge: &'a Challenge) -> SolverFuture<'a>;
+}
+
+/// internal function returning whether or not a response is a challenge page,
+/// from Cloudflare's `cf-mitigated` header or the markers of known challenge pages
+pub(crate) fn is_challenge(headers: &HeaderMap, body: &str) -> bool {
+    headers.get("cf-mitigated").is_some_and(|value| value == "challenge")
+        || CHALLENGE_MARKERS.iter().any(|marker| body.contains(marker))
+}
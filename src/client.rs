@@ -0,0 +1,87 @@
+//! The configuration of the HTTP client of a game, kept around so the client can be rebuilt
+//! whenever a builder method changes it
+
+use std::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use reqwest::{Client, cookie::Jar};
+
+use crate::{
+    enums::{HttpVersion, IpVersion},
+    error::Result,
+};
+
+
+/// the settings the HTTP client of a game is built with
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ClientConfig {
+    /// the static addresses hostnames are resolved to, see [`crate::Akinator::with_resolve`]
+    pub(crate) resolve: Vec<(String, SocketAddr)>,
+    /// the IP version connections are made over, see [`crate::Akinator::with_ip_version`]
+    pub(crate) ip_version: IpVersion,
+    /// the max amount of idle connections kept per host, see [`crate::Akinator::with_pool_max_idle_per_host`]
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+    /// how long idle connections are kept, [`None`] to use reqwest's default,
+    /// see [`crate::Akinator::with_pool_idle_timeout`]
+    pub(crate) pool_idle_timeout: Option<Option<Duration>>,
+    /// the HTTP version requests are made with, see [`crate::Akinator::with_http_version`]
+    pub(crate) http_version: HttpVersion,
+    /// whether or not to use adaptive HTTP/2 flow control, see [`crate::Akinator::with_http2_adaptive_window`]
+    pub(crate) http2_adaptive_window: bool,
+}
+
+impl ClientConfig {
+    /// internal method building the HTTP client, persisting cookies in `cookie_jar` if provided
+    ///
+    /// uses the TLS backend selected by the `rustls-tls` or `native-tls` feature,
+    /// and requests gzip / brotli compressed responses with the `compression` feature enabled
+    pub(crate) fn build(&self, cookie_jar: Option<&Arc<Jar>>) -> Result<Client> {
+        let mut builder = Client::builder();
+
+        #[cfg(feature = "rustls-tls")]
+        {
+            builder = builder.use_rustls_tls();
+        }
+        #[cfg(all(feature = "native-tls", not(feature = "rustls-tls")))]
+        {
+            builder = builder.use_native_tls();
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        if let Some(cookie_jar) = cookie_jar {
+            builder = builder.cookie_provider(Arc::clone(cookie_jar));
+        }
+
+        for (host, addr) in &self.resolve {
+            builder = builder.resolve(host, *addr);
+        }
+
+        builder = match self.ip_version {
+            IpVersion::Any => builder,
+            IpVersion::V4 => builder.local_address(Some(Ipv4Addr::UNSPECIFIED.into())),
+            IpVersion::V6 => builder.local_address(Some(Ipv6Addr::UNSPECIFIED.into())),
+        };
+
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        builder = match self.http_version {
+            HttpVersion::Auto => builder,
+            HttpVersion::Http1Only => builder.http1_only(),
+            HttpVersion::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+        };
+        builder = builder.http2_adaptive_window(self.http2_adaptive_window);
+
+        Ok(builder.build()?)
+    }
+}
@@ -0,0 +1,65 @@
+//! The clock timestamps are read from, see [`crate::Akinator::with_clock`]
+//!
+//! tests can pin timestamps with a [`FixedClock`] so requests and saved games are reproducible
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+
+/// a source of the current time
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// returns the current time
+    fn now(&self) -> SystemTime;
+}
+
+/// the system's clock, the default clock of a game
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// a clock pinned to a set time, which only moves when told to
+///
+/// clones share the same time, so a clone kept by a test can advance the clock of a game
+#[derive(Debug, Clone)]
+pub struct FixedClock {
+    /// the current time of the clock
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl FixedClock {
+    /// Creates a new [`FixedClock`] pinned to `now`
+    #[must_use]
+    pub fn new(now: SystemTime) -> Self {
+        Self { now: Arc::new(Mutex::new(now)) }
+    }
+
+    /// Creates a new [`FixedClock`] pinned to the POSIX timestamp `secs`
+    #[must_use]
+    pub fn from_timestamp(secs: u64) -> Self {
+        Self::new(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    /// sets the current time of the clock
+    pub fn set(&self, now: SystemTime) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    /// moves the clock forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
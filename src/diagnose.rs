@@ -0,0 +1,133 @@
+//! Self-check reports of every stage of starting a game, see [`crate::Akinator::diagnose`]
+//!
+//! meant to be pasted into bug reports, turning a bare [`crate::error::Error::NoDataFound`]
+//! into the exact stage that failed and why
+
+use std::{
+    fmt,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    enums::{Language, Theme},
+    error::{Error, Result},
+};
+
+
+/// a stage of starting a game, in the order they are run in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticStage {
+    /// finding the API server on the homepage, skipped with [`crate::Akinator::with_ws_url`]
+    ServerDiscovery,
+    /// extracting the session's uid and frontaddr from the game page
+    SessionInfo,
+    /// requesting a new session from the API, which is then left unused
+    NewSession,
+}
+
+impl fmt::Display for DiagnosticStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ServerDiscovery => "server discovery",
+            Self::SessionInfo => "session info",
+            Self::NewSession => "new session",
+        })
+    }
+}
+
+/// the outcome of a single [`DiagnosticStage`]
+#[derive(Debug)]
+pub struct StageReport {
+    /// the stage that was run
+    pub stage: DiagnosticStage,
+    /// how long the stage took
+    pub elapsed: Duration,
+    /// what the stage found, ex: the URL of the server, or the error it failed with
+    pub outcome: Result<String>,
+}
+
+/// the report of [`crate::Akinator::diagnose`]
+///
+/// stages are run until one fails, as each stage depends on the previous ones
+#[derive(Debug)]
+pub struct DiagnosticReport {
+    /// the language the game was checked for
+    pub language: Language,
+    /// the theme the game was checked for
+    pub theme: Theme,
+    /// the base URI of the website that was checked
+    pub uri: String,
+    /// the stages that were run, in order
+    pub stages: Vec<StageReport>,
+}
+
+impl DiagnosticReport {
+    /// internal function creating an empty report
+    pub(crate) const fn new(language: Language, theme: Theme, uri: String) -> Self {
+        Self { language, theme, uri, stages: Vec::new() }
+    }
+
+    /// internal method running `stage`, recording its outcome described with `describe`
+    ///
+    /// returns the stage's output, or [`None`] if it failed
+    pub(crate) async fn run<T>(
+        &mut self,
+        stage: DiagnosticStage,
+        future: impl Future<Output = Result<T>>,
+        describe: impl FnOnce(&T) -> String,
+    ) -> Option<T> {
+        let started = Instant::now();
+        let result = future.await;
+
+        let (outcome, output) = match result {
+            Ok(output) => (Ok(describe(&output)), Some(output)),
+            Err(err) => (Err(err), None),
+        };
+
+        self.stages.push(StageReport {
+            stage,
+            elapsed: started.elapsed(),
+            outcome,
+        });
+
+        output
+    }
+
+    /// returns whether or not every stage succeeded
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.failure().is_none()
+            && self.stages.last().is_some_and(|report| report.stage == DiagnosticStage::NewSession)
+    }
+
+    /// returns the stage that failed, if any
+    #[must_use]
+    pub fn failure(&self) -> Option<&StageReport> {
+        self.stages
+            .iter()
+            .find(|report| report.outcome.is_err())
+    }
+
+    /// returns the error of the stage that failed, if any
+    #[must_use]
+    pub fn error(&self) -> Option<&Error> {
+        self.failure()
+            .and_then(|report| report.outcome.as_ref().err())
+    }
+}
+
+impl fmt::Display for DiagnosticReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "akinator-rs {} diagnostics for {} ({}, {:?})", env!("CARGO_PKG_VERSION"), self.uri, self.language, self.theme)?;
+
+        for report in &self.stages {
+            match &report.outcome {
+                Ok(detail) => writeln!(f, "  {}: ok in {:?}, {detail}", report.stage, report.elapsed)?,
+                Err(err) => writeln!(f, "  {}: FAILED in {:?}, {err}", report.stage, report.elapsed)?,
+            }
+        }
+
+        Ok(())
+    }
+}
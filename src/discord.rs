@@ -0,0 +1,162 @@
+//! Helpers building the Discord messages of a game with [serenity](https://docs.rs/serenity), also usable with poise
+//!
+//! Only available with the `discord` feature enabled.
+//! [`question_embed`] and [`question_components`] render a question along with a row of answer buttons,
+//! [`guess_embed`] renders a guess, and [`parse_interaction`] maps a button press back to a [`ButtonAction`].
+//!
+//! ```no_run
+//! # async fn run(interaction: serenity::all::ComponentInteraction, mut akinator: akinator_rs::Akinator) -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{discord::{self, ButtonAction}, models::StepOutcome};
+//! use serenity::all::CreateInteractionResponseMessage;
+//!
+//! if let Some(ButtonAction::Answer(answer)) = discord::parse_interaction(&interaction) {
+//!     if let StepOutcome::NextQuestion(question) = akinator.answer_step(answer).await? {
+//!         let message = CreateInteractionResponseMessage::new()
+//!             .embed(discord::question_embed(&question))
+//!             .components(discord::question_components(question.step > 0));
+//!         // respond to the interaction with `message`
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serenity::all::{
+    ButtonStyle,
+    ComponentInteraction,
+    CreateActionRow,
+    CreateButton,
+    CreateEmbed,
+    CreateEmbedFooter,
+};
+
+use crate::{
+    enums::{Answer, ImageSize},
+    models::{Guess, Question},
+};
+
+
+/// the prefix of the custom ids of the buttons, telling them apart from the bot's other components
+pub const CUSTOM_ID_PREFIX: &str = "akinator:";
+
+/// the custom id suffix of the button going back to the previous question
+const BACK_ID: &str = "back";
+
+/// the answers, in the order their buttons are shown in
+const ANSWERS: [Answer; 5] = [Answer::Yes, Answer::No, Answer::Idk, Answer::Probably, Answer::ProbablyNot];
+
+/// the action taken by pressing one of the buttons of [`question_components`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonAction {
+    /// answers the question, see [`crate::Akinator::answer`]
+    Answer(Answer),
+    /// goes back to the previous question, see [`crate::Akinator::back`]
+    Back,
+}
+
+impl ButtonAction {
+    /// returns the custom id of the button taking this action
+    #[must_use]
+    pub fn custom_id(self) -> String {
+        let suffix = match self {
+            Self::Answer(answer) => answer_id(answer),
+            Self::Back => BACK_ID,
+        };
+
+        format!("{CUSTOM_ID_PREFIX}{suffix}")
+    }
+
+    /// parses the custom id of a button, returning [`None`] if it is not one of [`question_components`]
+    #[must_use]
+    pub fn from_custom_id(custom_id: &str) -> Option<Self> {
+        let suffix = custom_id.strip_prefix(CUSTOM_ID_PREFIX)?;
+
+        if suffix == BACK_ID {
+            return Some(Self::Back);
+        }
+
+        ANSWERS
+            .into_iter()
+            .find(|&answer| answer_id(answer) == suffix)
+            .map(Self::Answer)
+    }
+}
+
+/// internal function returning the custom id suffix of the button of `answer`
+const fn answer_id(answer: Answer) -> &'static str {
+    match answer {
+        Answer::Yes => "yes",
+        Answer::No => "no",
+        Answer::Idk => "idk",
+        Answer::Probably => "probably",
+        Answer::ProbablyNot => "probably_not",
+    }
+}
+
+/// returns the English label of the button of `answer`
+#[must_use]
+pub const fn answer_label(answer: Answer) -> &'static str {
+    match answer {
+        Answer::Yes => "Yes",
+        Answer::No => "No",
+        Answer::Idk => "Don't know",
+        Answer::Probably => "Probably",
+        Answer::ProbablyNot => "Probably not",
+    }
+}
+
+/// returns an embed asking `question`, with the progression in its footer
+pub fn question_embed(question: &Question) -> CreateEmbed {
+    CreateEmbed::new()
+        .title(format!("Question {}", question.step + 1))
+        .description(&question.text)
+        .footer(CreateEmbedFooter::new(format!("Progression: {:.1}%", question.progression.value())))
+}
+
+/// returns the rows of buttons answering a question:
+/// one button per [`Answer`], and a button going back if `can_go_back`, ex: past the first question
+#[must_use]
+pub fn question_components(can_go_back: bool) -> Vec<CreateActionRow> {
+    let answers = ANSWERS
+        .into_iter()
+        .map(|answer| {
+            CreateButton::new(ButtonAction::Answer(answer).custom_id())
+                .label(answer_label(answer))
+                .style(ButtonStyle::Primary)
+        })
+        .collect();
+
+    let mut rows = vec![CreateActionRow::Buttons(answers)];
+
+    if can_go_back {
+        rows.push(CreateActionRow::Buttons(vec![
+            CreateButton::new(ButtonAction::Back.custom_id())
+                .label("Back")
+                .style(ButtonStyle::Secondary),
+        ]));
+    }
+
+    rows
+}
+
+/// returns an embed presenting `guess`: its name, description, picture and the akinator's confidence,
+/// the picture being requested at [`ImageSize::Medium`] to spare bandwidth
+pub fn guess_embed(guess: &Guess) -> CreateEmbed {
+    let embed = CreateEmbed::new()
+        .title(&guess.name)
+        .description(&guess.description)
+        .field("Confidence", format!("{:.1}%", guess.probability() * 100.0), true);
+
+    if guess.absolute_picture_path.is_empty() {
+        embed
+    } else {
+        embed.image(guess.picture_url(ImageSize::Medium))
+    }
+}
+
+/// maps a button press back to the action it takes,
+/// returning [`None`] if the button is not one of [`question_components`]
+#[must_use]
+pub fn parse_interaction(interaction: &ComponentInteraction) -> Option<ButtonAction> {
+    ButtonAction::from_custom_id(&interaction.data.custom_id)
+}
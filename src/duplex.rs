@@ -0,0 +1,143 @@
+//! A duplex API playing a game through a [`Sink`] of answers and a [`Stream`] of events,
+//! see [`crate::Akinator::into_duplex`]
+//!
+//! Only available with the `duplex` feature enabled.
+//! Bridging a game to a WebSocket frontend becomes a matter of forwarding the incoming answers into the [`AnswerSink`]
+//! and the [`EventStream`] into the outgoing frames.
+//!
+//! ```no_run
+//! # async fn run() -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{Akinator, enums::Answer, events::GameEvent};
+//! use futures::{SinkExt, StreamExt};
+//!
+//! let (mut answers, mut events) = Akinator::new()?.into_duplex();
+//!
+//! while let Some(event) = events.next().await {
+//!     match event {
+//!         GameEvent::QuestionAsked { question } => {
+//!             println!("{}", question.text);
+//!             answers.send(Answer::Yes).await?;
+//!         },
+//!         GameEvent::GuessesReady { guesses } => println!("{:?}", guesses.first()),
+//!         _ => {},
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_sink::Sink;
+use tokio::sync::mpsc;
+use tokio_stream::{
+    Stream,
+    wrappers::BroadcastStream,
+};
+use tokio_util::sync::PollSender;
+
+use crate::{
+    Akinator,
+    DEFAULT_WIN_THRESHOLD,
+    enums::Answer,
+    error::{Error, Result},
+    events::GameEvent,
+};
+
+
+/// the amount of answers that can be queued before the sink waits for the game's task
+const ANSWER_BUFFER: usize = 8;
+
+/// the sending half of a duplex game, answering the current question with each [`Answer`] sent into it
+///
+/// the game's task stops once the sink is closed or dropped, ending the [`EventStream`]
+#[derive(Debug)]
+pub struct AnswerSink {
+    /// the sender of the game's answer queue
+    sender: PollSender<Answer>,
+}
+
+/// the receiving half of a duplex game, yielding the events emitted as the game is played, see [`GameEvent`]
+///
+/// ends once the game is over, after [`GameEvent::GuessesReady`], or once the [`AnswerSink`] is closed.
+/// Events missed by lagging more than [`crate::events::EVENT_CAPACITY`] events behind are skipped
+#[derive(Debug)]
+pub struct EventStream {
+    /// the game's events
+    events: BroadcastStream<GameEvent>,
+}
+
+/// internal function spawning the task playing `akinator` with the answers sent into the returned sink
+pub(crate) fn spawn(mut akinator: Akinator) -> (AnswerSink, EventStream) {
+    let (sender, receiver) = mpsc::channel(ANSWER_BUFFER);
+    let events = akinator.subscribe();
+
+    tokio::spawn(run(akinator, receiver));
+
+    (
+        AnswerSink { sender: PollSender::new(sender) },
+        EventStream { events: BroadcastStream::new(events) },
+    )
+}
+
+/// internal function starting the game if needed, then answering it with the received answers
+/// until the akinator is confident or runs out of questions, at which point it makes its guesses
+///
+/// failed requests are reported as [`GameEvent::Errored`] and do not stop the game
+async fn run(mut akinator: Akinator, mut receiver: mpsc::Receiver<Answer>) {
+    if akinator.session.is_none() && akinator.start().await.is_err() {
+        return;
+    }
+
+    while let Some(answer) = receiver.recv().await {
+        let out_of_questions = matches!(akinator.answer(answer).await, Err(Error::NoMoreQuestions));
+
+        if out_of_questions || akinator.progression >= DEFAULT_WIN_THRESHOLD {
+            let _ = akinator.win().await;
+            break;
+        }
+    }
+}
+
+impl Sink<Answer> for AnswerSink {
+    type Error = Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.sender
+            .poll_reserve(cx)
+            .map_err(|_| Error::TaskStopped)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, answer: Answer) -> Result<()> {
+        self.sender
+            .send_item(answer)
+            .map_err(|_| Error::TaskStopped)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<()>> {
+        self.sender.close();
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Stream for EventStream {
+    type Item = GameEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<GameEvent>> {
+        loop {
+            match Pin::new(&mut self.events).poll_next(cx) {
+                Poll::Ready(Some(Err(_))) => continue,
+                Poll::Ready(Some(Ok(event))) => return Poll::Ready(Some(event)),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -3,6 +3,8 @@ use std::{
     str::FromStr,
 };
 
+use serde::{Serialize, Deserialize};
+
 use crate::error::{Result, Error};
 
 
@@ -11,7 +13,14 @@ use crate::error::{Result, Error};
 /// intended to be passed into [`Akinator::answer`]
 ///
 /// for parsing from a string, use the `from_str` / [`str::parse`] or `try_from` methods
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int, module = "akinator_rs"))]
+#[cfg_attr(
+    feature = "openapi",
+    derive(utoipa::ToSchema),
+    schema(rename_all = "snake_case", description = "A user's answer to the akinator's questions"),
+)]
 pub enum Answer {
     Yes = 0,
     No = 1,
@@ -25,8 +34,11 @@ pub enum Answer {
 /// intended to be pased into [`Akinator::with_theme`] when setting the theme of the game
 ///
 /// for parsing from a string, use the `from_str` / [`str::parse`] or `from` methods
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int, module = "akinator_rs"))]
 pub enum Theme {
+    #[default]
     Characters = 1,
     Animals = 14,
     Objects = 2,
@@ -37,8 +49,11 @@ pub enum Theme {
 /// intended to be pased into [`Akinator::with_language`] when setting the language of the game
 ///
 /// for parsing from a string, use the `from_str` / [`str::parse`] or `try_from` methods
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int, module = "akinator_rs"))]
 pub enum Language {
+    #[default]
     English,
     Arabic,
     Chinese,
@@ -57,6 +72,89 @@ pub enum Language {
     Indonesian,
 }
 
+/// Enum representing the IP version used to connect to the akinator servers
+///
+/// intended to be passed into [`Akinator::with_ip_version`], for networks where one of them is flaky
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IpVersion {
+    /// use whichever addresses DNS returns
+    #[default]
+    Any,
+    /// only connect over IPv4
+    V4,
+    /// only connect over IPv6
+    V6,
+}
+
+/// Enum representing the HTTP version used to talk to the akinator servers
+///
+/// intended to be passed into [`Akinator::with_http_version`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpVersion {
+    /// negotiate the HTTP version with the server
+    #[default]
+    Auto,
+    /// only use HTTP/1.1
+    Http1Only,
+    /// use HTTP/2 without negotiating it first
+    Http2PriorKnowledge,
+}
+
+/// Enum representing the resolution of a guess' picture
+///
+/// intended to be passed into [`crate::models::Guess::picture_url`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageSize {
+    /// a small picture for thumbnails, 100 pixels wide
+    Thumbnail,
+    /// a medium picture, ex: for chat embeds, 300 pixels wide
+    Medium,
+    /// the picture as served by the API
+    #[default]
+    Full,
+}
+
+/// Enum representing the state of a game, see [`crate::Akinator::state`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameState {
+    /// the game has not been started yet
+    #[default]
+    NotStarted,
+    /// the akinator is asking questions
+    InProgress,
+    /// the akinator has made its guesses and is waiting for them to be confirmed or rejected
+    AwaitingConfirmation,
+    /// the akinator's guess was confirmed, see [`crate::Akinator::confirm_guess`]
+    Finished,
+}
+
+impl ImageSize {
+    /// returns the width of the picture in pixels, [`None`] for [`Self::Full`] which keeps the API's width
+    #[must_use]
+    pub const fn width(self) -> Option<usize> {
+        match self {
+            Self::Thumbnail => Some(100),
+            Self::Medium => Some(300),
+            Self::Full => None,
+        }
+    }
+}
+
+impl fmt::Display for GameState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NotStarted => "not started",
+            Self::InProgress => "in progress",
+            Self::AwaitingConfirmation => "awaiting confirmation",
+            Self::Finished => "finished",
+        })
+    }
+}
+
 /// internal method attempting to convert a string answer: (ex: "yes")
 /// to an [`Answer`] variant
 ///
@@ -68,11 +166,98 @@ fn try_answer_from_string(ans: String) -> Result<Answer> {
         "no"  | "n" | "1" => Ok(Answer::No),
         "i dont know" | "i don't know" | "idk" | "i" | "2" => Ok(Answer::Idk),
         "probably" | "p" | "3" => Ok(Answer::Probably),
-        "probably not" | "pn" | "4" => Ok(Answer::ProbablyNot),
+        "probably not" | "probably_not" | "pn" | "4" => Ok(Answer::ProbablyNot),
         _ => Err(Error::InvalidAnswer),
     }
 }
 
+/// internal function returning the synonyms of each answer in `language`, on top of the English ones
+///
+/// used in [`Answer::parse_localized`]
+const fn localized_answers(language: Language) -> &'static [(&'static str, Answer)] {
+    use Answer::{Yes, No, Idk, Probably, ProbablyNot};
+
+    match language {
+        Language::English => &[],
+        Language::Arabic => &[
+            ("نعم", Yes), ("لا", No), ("لا أعرف", Idk), ("لا اعرف", Idk),
+            ("ربما", Probably), ("ربما لا", ProbablyNot),
+        ],
+        Language::Chinese => &[
+            ("是", Yes), ("是的", Yes), ("不是", No), ("否", No), ("不知道", Idk),
+            ("可能是", Probably), ("可能", Probably), ("可能不是", ProbablyNot),
+        ],
+        Language::German => &[
+            ("ja", Yes), ("nein", No), ("ich weiß nicht", Idk), ("weiß nicht", Idk), ("weiss nicht", Idk),
+            ("wahrscheinlich", Probably), ("wahrscheinlich nicht", ProbablyNot),
+        ],
+        Language::Spanish => &[
+            ("sí", Yes), ("si", Yes), ("no lo sé", Idk), ("no lo se", Idk), ("no sé", Idk), ("no se", Idk),
+            ("probablemente", Probably), ("probablemente no", ProbablyNot),
+        ],
+        Language::French => &[
+            ("oui", Yes), ("non", No), ("je ne sais pas", Idk), ("sais pas", Idk),
+            ("probablement", Probably), ("probablement pas", ProbablyNot),
+        ],
+        Language::Hebrew => &[
+            ("כן", Yes), ("לא", No), ("לא יודע", Idk), ("לא יודעת", Idk),
+            ("כנראה", Probably), ("אולי", Probably), ("כנראה שלא", ProbablyNot),
+        ],
+        Language::Italian => &[
+            ("sì", Yes), ("si", Yes), ("non so", Idk), ("non lo so", Idk),
+            ("probabilmente", Probably), ("probabilmente no", ProbablyNot),
+        ],
+        Language::Japanese => &[
+            ("はい", Yes), ("いいえ", No), ("分からない", Idk), ("わからない", Idk),
+            ("たぶんそう", Probably), ("たぶん", Probably), ("たぶん違う", ProbablyNot), ("たぶんちがう", ProbablyNot),
+        ],
+        Language::Korean => &[
+            ("네", Yes), ("예", Yes), ("아니요", No), ("아니오", No), ("모르겠어요", Idk), ("모르겠다", Idk),
+            ("아마도", Probably), ("아마 아닐 거예요", ProbablyNot), ("아마 아니요", ProbablyNot),
+        ],
+        Language::Dutch => &[
+            ("ja", Yes), ("nee", No), ("weet ik niet", Idk), ("ik weet het niet", Idk),
+            ("waarschijnlijk", Probably), ("waarschijnlijk niet", ProbablyNot),
+        ],
+        Language::Polish => &[
+            ("tak", Yes), ("nie", No), ("nie wiem", Idk),
+            ("prawdopodobnie", Probably), ("prawdopodobnie nie", ProbablyNot),
+        ],
+        Language::Portugese => &[
+            ("sim", Yes), ("não", No), ("nao", No), ("não sei", Idk), ("nao sei", Idk),
+            ("provavelmente", Probably), ("provavelmente não", ProbablyNot), ("provavelmente nao", ProbablyNot),
+        ],
+        Language::Russian => &[
+            ("да", Yes), ("нет", No), ("не знаю", Idk), ("я не знаю", Idk),
+            ("возможно", Probably), ("скорее да", Probably), ("скорее нет", ProbablyNot),
+        ],
+        Language::Turkish => &[
+            ("evet", Yes), ("hayır", No), ("hayir", No), ("bilmiyorum", Idk),
+            ("muhtemelen", Probably), ("muhtemelen değil", ProbablyNot), ("muhtemelen degil", ProbablyNot),
+        ],
+        Language::Indonesian => &[
+            ("ya", Yes), ("tidak", No), ("tidak tahu", Idk), ("saya tidak tahu", Idk),
+            ("mungkin", Probably), ("mungkin tidak", ProbablyNot),
+        ],
+    }
+}
+
+impl Answer {
+    /// attempts to parse an answer in `language` (ex: "oui" in [`Language::French`]),
+    /// falling back to the English answers and numbers accepted by [`str::parse`]
+    ///
+    /// # Errors
+    /// [`Error::InvalidAnswer`] if the answer is not recognized
+    pub fn parse_localized(string: &str, language: Language) -> Result<Self> {
+        let string = string.trim().to_lowercase();
+
+        localized_answers(language)
+            .iter()
+            .find(|(synonym, _)| *synonym == string)
+            .map_or_else(|| string.parse(), |&(_, answer)| Ok(answer))
+    }
+}
+
 impl FromStr for Answer {
     type Err = Error;
 
@@ -106,22 +291,23 @@ impl TryFrom<usize> for Answer {
 }
 
 /// internal method to convert a string representing a theme: (ex: "animals")
-/// to a [`Theme`] variant
-///
-/// used in [`FromStr`] and [`From`] implementations
-#[allow(clippy::needless_pass_by_value)]
-fn theme_from_string(theme: String) -> Theme {
+/// to a [`Theme`] variant, returning [`None`] for unknown themes
+pub(crate) fn parse_theme(theme: &str) -> Option<Theme> {
     match theme.trim().to_lowercase().as_str() {
-        "a" | "animals" => Theme::Animals,
-        "o" | "objects" => Theme::Objects,
-        _ => Theme::default(),
+        "c" | "characters" => Some(Theme::Characters),
+        "a" | "animals" => Some(Theme::Animals),
+        "o" | "objects" => Some(Theme::Objects),
+        _ => None,
     }
 }
 
-impl Default for Theme {
-    fn default() -> Self {
-        Self::Characters
-    }
+/// internal method to convert a string representing a theme: (ex: "animals")
+/// to a [`Theme`] variant, falling back to the default theme
+///
+/// used in [`FromStr`] and [`From`] implementations
+#[allow(clippy::needless_pass_by_value)]
+fn theme_from_string(theme: String) -> Theme {
+    parse_theme(&theme).unwrap_or_default()
 }
 
 impl FromStr for Theme {
@@ -201,12 +387,6 @@ fn try_lang_from_string(lang: String) -> Result<Language> {
     }
 }
 
-impl Default for Language {
-    fn default() -> Self {
-        Self::English
-    }
-}
-
 impl FromStr for Language {
     type Err = Error;
 
@@ -1,5 +1,6 @@
 use thiserror::Error as ErrorBase;
 
+use std::fmt;
 use std::time::SystemTimeError;
 use serde_json::error::Error as SerdeJsonError;
 use reqwest::Error as ReqwestError;
@@ -9,6 +10,68 @@ use std::num::{
 };
 
 
+/// Enum representing a non-`OK` completion code returned by the akinator API
+///
+/// carried inside [`Error::Completion`] for codes without a dedicated [`Error`] variant
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompletionCode {
+    /// `KO - SERVER DOWN`
+    ServerDown,
+    /// `KO - TECHNICAL ERROR`
+    TechnicalError,
+    /// `KO - TIMEOUT`
+    Timeout,
+    /// `KO - ELEM LIST IS EMPTY`
+    ElemListIsEmpty,
+    /// `WARN - NO QUESTION`
+    NoQuestion,
+    /// `KO - MISSING KEY`
+    MissingKey,
+    /// `KO - MISSING PARAMETERS`
+    MissingParameters,
+    /// `KO - INCORRECT PARAMETER`
+    IncorrectParameter,
+    /// `KO - UNAUTHORIZED`, usually from an invalid or expired session / signature
+    Unauthorized,
+    /// any other completion code not recognized by this crate, containing the raw value
+    Unknown(String),
+}
+
+impl From<&str> for CompletionCode {
+    fn from(completion: &str) -> Self {
+        match completion.trim().to_uppercase().as_str() {
+            "KO - SERVER DOWN" => Self::ServerDown,
+            "KO - TECHNICAL ERROR" => Self::TechnicalError,
+            "KO - TIMEOUT" => Self::Timeout,
+            "KO - ELEM LIST IS EMPTY" => Self::ElemListIsEmpty,
+            "WARN - NO QUESTION" => Self::NoQuestion,
+            "KO - MISSING KEY" => Self::MissingKey,
+            "KO - MISSING PARAMETERS" => Self::MissingParameters,
+            "KO - INCORRECT PARAMETER" => Self::IncorrectParameter,
+            "KO - UNAUTHORIZED" => Self::Unauthorized,
+            _ => Self::Unknown(completion.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for CompletionCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ServerDown => "KO - SERVER DOWN",
+            Self::TechnicalError => "KO - TECHNICAL ERROR",
+            Self::Timeout => "KO - TIMEOUT",
+            Self::ElemListIsEmpty => "KO - ELEM LIST IS EMPTY",
+            Self::NoQuestion => "WARN - NO QUESTION",
+            Self::MissingKey => "KO - MISSING KEY",
+            Self::MissingParameters => "KO - MISSING PARAMETERS",
+            Self::IncorrectParameter => "KO - INCORRECT PARAMETER",
+            Self::Unauthorized => "KO - UNAUTHORIZED",
+            Self::Unknown(completion) => completion.as_str(),
+        })
+    }
+}
+
+
 /// returned in the update info methods
 #[derive(Debug, ErrorBase)]
 #[allow(clippy::module_name_repetitions)]
@@ -27,6 +90,43 @@ pub enum UpdateInfoError {
 }
 
 
+/// the request a protocol error happened in, see [`Error::WithContext`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestContext {
+    /// the name of the API endpoint, ex: `answer_api`
+    pub endpoint: &'static str,
+    /// the step of the game when the request was made
+    pub step: usize,
+    /// the id of the game the request was made in, see [`crate::Akinator::game_id`]
+    pub game_id: Option<String>,
+    /// the id of the request, see [`crate::Akinator::last_request_id`],
+    /// [`None`] for the requests made concurrently when starting a game
+    pub request_id: Option<String>,
+    /// a truncated snippet of the body of the response that failed,
+    /// [`None`] if the request itself failed
+    pub body: Option<String>,
+}
+
+impl fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "endpoint: {}, step: {}", self.endpoint, self.step)?;
+
+        if let Some(game_id) = &self.game_id {
+            write!(f, ", game: {game_id}")?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request: {request_id}")?;
+        }
+
+        if let Some(body) = &self.body {
+            write!(f, ", response: {body}")?;
+        }
+
+        Ok(())
+    }
+}
+
+
 /// the main Error enum for errors returned from akinator functions
 #[derive(Debug, ErrorBase)]
 pub enum Error {
@@ -42,6 +142,30 @@ pub enum Error {
     #[error("Failed to parse JSON: {0}")]
     JsonParseError(#[from] SerdeJsonError),
 
+    /// from when deserializing json fails while in debug mode,
+    /// containing a snippet of the raw response that failed to parse
+    #[error("Failed to parse JSON: {source}, response: {snippet}")]
+    DebugJsonParseError {
+        source: SerdeJsonError,
+        snippet: String,
+    },
+
+    /// from when a response body is malformed in its detected charset, containing the charset's name
+    #[error("Failed to decode the response body as {0}")]
+    DecodeError(String),
+
+    /// from a custom transport failing to send a request, see [`crate::transport`]
+    #[error("Transport error: {0}")]
+    TransportError(crate::transport::BoxError),
+
+    /// from a [`crate::store::SessionStore`] failing to get, put or delete a game
+    #[error("Session store error: {0}")]
+    StoreError(crate::transport::BoxError),
+
+    /// from propogating [`std::io::Error`] when reading or writing saved games fails
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
     /// conversion from [`UpdateInfoError`]
     #[error("Failed to update data fields: {0}")]
     UpdateInfoError(#[from] UpdateInfoError),
@@ -66,14 +190,96 @@ pub enum Error {
     #[error("There are no more available questions")]
     NoMoreQuestions,
 
+    /// from when the akinator API responds with any other non-`OK` completion code
+    #[error("The akinator API responded with an error: {0}")]
+    Completion(CompletionCode),
+
     /// from any other form of connection or server error
     #[error("Failed to connect to akinator servers")]
     ConnectionError,
 
+    /// from when the game's session has expired on the akinator servers,
+    /// ex: when the API times out or rejects the session mid-game,
+    /// or when loading a game saved too long ago with [`crate::Akinator::load_from_file`]
+    ///
+    /// the game can be continued in a new session with [`crate::Akinator::renew`]
+    #[error("The game's session has expired")]
+    SessionExpired,
+
+    /// from when calling a method that is invalid in the current state of the game,
+    /// ex: answering a question before the game was started, see [`crate::Akinator::state`]
+    #[error("Cannot {action} while the game is {state}")]
+    WrongState {
+        action: &'static str,
+        state: crate::enums::GameState,
+    },
+
     /// from when calling `back`, fails often when we are already on the first questions so we can't go back any more
     #[error("Cannot go back any further, you are already on the first question")]
     CantGoBackAnyFurther,
 
+    /// from when calling `forward` without having gone back, or after answering differently since,
+    /// so there is no answer to redo
+    #[error("Cannot go forward, there is no answer to redo")]
+    CantGoForward,
+
+    /// from when changing the answer of a step at which no answer was given, containing the step,
+    /// see [`crate::Akinator::change_answer`]
+    #[error("No answer was given at step {0}")]
+    InvalidStep(usize),
+
+    /// from when the akinator servers respond with a non-2xx HTTP status (other than 429),
+    /// such as a 403 or 503 from Cloudflare, containing a truncated snippet of the response body
+    #[error("The akinator servers responded with HTTP {status}")]
+    HttpStatus {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+
+    /// from when the akinator servers rate limited the request (HTTP 429),
+    /// containing the delay requested by the `Retry-After` header, if any
+    ///
+    /// requests can be retried automatically with [`crate::Akinator::with_retry_policy`]
+    #[error("Rate limited by the akinator servers")]
+    RateLimited {
+        retry_after: Option<std::time::Duration>,
+    },
+
+    /// from when the website serves an anti-bot challenge page (ex: Cloudflare's) instead of the requested page
+    ///
+    /// challenges can be solved automatically with [`crate::Akinator::with_challenge_solver`]
+    #[error("The akinator website served an anti-bot challenge (status {})", .0.status)]
+    BotChallenge(Box<crate::challenge::Challenge>),
+
+    /// from when a request is aborted as the game's cancellation token was cancelled
+    #[error("The request was cancelled")]
+    Cancelled,
+
+    /// from when the task running a game spawned with [`crate::Akinator::spawn`] has stopped,
+    /// ex: if it panicked
+    #[error("The game's task has stopped")]
+    TaskStopped,
+
+    /// from when the game's overall time budget set with [`crate::Akinator::with_game_deadline`] runs out,
+    /// either before a request is sent or while waiting for it
+    #[error("The game's deadline has been exceeded")]
+    DeadlineExceeded,
+
+    /// from when a call made with a timeout, ex: [`crate::Akinator::answer_with_timeout`],
+    /// does not complete in time
+    #[error("The call did not complete within {0:?}")]
+    CallTimeout(std::time::Duration),
+
+    /// wraps a protocol error ([`Error::NoDataFound`], JSON parse, decode and update info errors)
+    /// with the [`RequestContext`] it happened in, to make it diagnosable
+    ///
+    /// use [`Error::root`] to retrieve the underlying error
+    #[error("{source} ({context})")]
+    WithContext {
+        context: RequestContext,
+        source: Box<Error>,
+    },
+
     /// Simply an invalid answer to respond to the question when parsing from string
     #[error("Invalid Answer")]
     InvalidAnswer,
@@ -81,6 +287,131 @@ pub enum Error {
     /// from when an invalid or not supported language is passed when parsing from string
     #[error("Invalid Language")]
     InvalidLanguage,
+
+    /// from when a knowledge base for the offline engine is malformed, containing the reason
+    #[error("Invalid knowledge base: {0}")]
+    InvalidKnowledgeBase(String),
+}
+
+impl Error {
+    /// returns the underlying error, stripping any [`Error::WithContext`]
+    #[must_use]
+    pub const fn root(&self) -> &Self {
+        match self {
+            Self::WithContext { source, .. } => source.root(),
+            _ => self,
+        }
+    }
+
+    /// returns the [`RequestContext`] the error happened in, if known
+    #[must_use]
+    pub const fn context(&self) -> Option<&RequestContext> {
+        match self {
+            Self::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// returns whether or not the error is a protocol error, for which a [`RequestContext`] is attached
+    pub(crate) const fn is_protocol_error(&self) -> bool {
+        matches!(
+            self,
+            Self::JsonParseError(_)
+                | Self::DebugJsonParseError { .. }
+                | Self::DecodeError(_)
+                | Self::UpdateInfoError(_)
+                | Self::NoDataFound
+        )
+    }
+
+    /// returns whether or not the failed call can be retried as is, as the error is likely transient
+    ///
+    /// ex: timeouts and connection failures, rate limiting and temporary server issues
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self.root() {
+            Self::RequestError(err) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err.status().is_some_and(|status| status.is_server_error())
+            },
+            Self::HttpStatus { status, .. } => status.is_server_error(),
+            Self::ServersDown
+            | Self::TechnicalError
+            | Self::ConnectionError
+            | Self::CallTimeout(_)
+            | Self::RateLimited { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// returns the name of the kind of the error, ex: `"NoDataFound"`, looking through [`Self::WithContext`]
+    ///
+    /// JSON parse errors are reported as `"JsonParseError"` whether or not they were raised in debug mode
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::TimeError(_) => "TimeError",
+            Self::RequestError(_) => "RequestError",
+            Self::JsonParseError(_) | Self::DebugJsonParseError { .. } => "JsonParseError",
+            Self::DecodeError(_) => "DecodeError",
+            Self::TransportError(_) => "TransportError",
+            Self::StoreError(_) => "StoreError",
+            Self::IoError(_) => "IoError",
+            Self::UpdateInfoError(_) => "UpdateInfoError",
+            Self::NoDataFound => "NoDataFound",
+            Self::ServersDown => "ServersDown",
+            Self::TechnicalError => "TechnicalError",
+            Self::TimeoutError => "TimeoutError",
+            Self::NoMoreQuestions => "NoMoreQuestions",
+            Self::Completion(_) => "Completion",
+            Self::ConnectionError => "ConnectionError",
+            Self::SessionExpired => "SessionExpired",
+            Self::WrongState { .. } => "WrongState",
+            Self::CantGoBackAnyFurther => "CantGoBackAnyFurther",
+            Self::CantGoForward => "CantGoForward",
+            Self::InvalidStep(_) => "InvalidStep",
+            Self::HttpStatus { .. } => "HttpStatus",
+            Self::RateLimited { .. } => "RateLimited",
+            Self::BotChallenge(_) => "BotChallenge",
+            Self::WithContext { source, .. } => source.kind(),
+            Self::Cancelled => "Cancelled",
+            Self::TaskStopped => "TaskStopped",
+            Self::DeadlineExceeded => "DeadlineExceeded",
+            Self::CallTimeout(_) => "CallTimeout",
+            Self::InvalidAnswer => "InvalidAnswer",
+            Self::InvalidLanguage => "InvalidLanguage",
+            Self::InvalidKnowledgeBase(_) => "InvalidKnowledgeBase",
+        }
+    }
+
+    /// returns whether or not the game's session is no longer usable,
+    /// meaning the game has to be continued in a new session with [`crate::Akinator::renew`]
+    #[must_use]
+    pub const fn is_session_dead(&self) -> bool {
+        matches!(
+            self.root(),
+            Self::TimeoutError
+                | Self::SessionExpired
+                | Self::Completion(CompletionCode::Unauthorized | CompletionCode::MissingKey)
+        )
+    }
+
+    /// returns whether or not the error originates from the akinator servers rather than from the caller or this crate
+    #[must_use]
+    pub fn is_server_side(&self) -> bool {
+        match self.root() {
+            Self::RequestError(err) => err.status().is_some_and(|status| status.is_server_error()),
+            Self::HttpStatus { status, .. } => status.is_server_error(),
+            Self::ServersDown
+            | Self::TechnicalError
+            | Self::TimeoutError
+            | Self::ConnectionError
+            | Self::RateLimited { .. }
+            | Self::BotChallenge(_) => true,
+            _ => false,
+        }
+    }
 }
 
 /// result typealias with `E` that defaults to [`Error`]
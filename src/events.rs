@@ -0,0 +1,93 @@
+//! Events emitted as a game is played, to mirror its state to several UI surfaces at once,
+//! see [`crate::Akinator::subscribe`]
+//!
+//! subscribing is only available with the `events` feature enabled,
+//! without it no event is ever built
+
+#[cfg(feature = "events")]
+use tokio::sync::broadcast;
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    enums::Answer,
+    models::{GuessList, Progression, Question},
+};
+
+
+/// the amount of events buffered for each subscriber,
+/// subscribers lagging further behind miss the oldest events
+#[cfg(feature = "events")]
+pub const EVENT_CAPACITY: usize = 64;
+
+/// an event emitted as a game is played
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum GameEvent {
+    /// the akinator asked a question, after the game was started, a question answered or the game went back
+    QuestionAsked {
+        /// the question asked
+        question: Question,
+    },
+    /// the question of `step` was answered
+    Answered {
+        /// the step of the answered question
+        step: usize,
+        /// the answer given
+        answer: Answer,
+    },
+    /// the progression of the akinator changed
+    ProgressChanged {
+        /// the previous progression
+        from: Progression,
+        /// the new progression
+        to: Progression,
+    },
+    /// the akinator made its guesses, after [`crate::Akinator::win`] or [`crate::Akinator::more_guesses`]
+    GuessesReady {
+        /// every guess made so far, best first
+        guesses: GuessList,
+    },
+    /// a request to the akinator API failed
+    Errored {
+        /// the message of the error the request failed with
+        message: String,
+    },
+}
+
+/// the sending half of a game's events, created on the first subscription
+///
+/// a cloned game starts without subscribers, so the original's subscribers do not see the events of its copies
+#[derive(Debug, Default)]
+pub(crate) struct Events {
+    /// the channel the events are broadcast on, [`None`] until subscribed to
+    #[cfg(feature = "events")]
+    sender: Option<broadcast::Sender<GameEvent>>,
+}
+
+impl Clone for Events {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Events {
+    /// internal method returning a new receiver of the events
+    #[cfg(feature = "events")]
+    pub(crate) fn subscribe(&mut self) -> broadcast::Receiver<GameEvent> {
+        self.sender
+            .get_or_insert_with(|| broadcast::channel(EVENT_CAPACITY).0)
+            .subscribe()
+    }
+
+    /// internal method broadcasting the event built by `event`, only built if there are subscribers
+    pub(crate) fn emit(&self, event: impl FnOnce() -> GameEvent) {
+        #[cfg(feature = "events")]
+        if let Some(sender) = self.sender.as_ref().filter(|sender| sender.receiver_count() > 0) {
+            // fails only if every receiver was dropped in the meantime
+            let _ = sender.send(event());
+        }
+
+        #[cfg(not(feature = "events"))]
+        let _ = event;
+    }
+}
@@ -0,0 +1,365 @@
+//! A C-compatible FFI layer for driving akinator games from non-Rust applications
+//!
+//! Only available with the `ffi` feature enabled, see `include/akinator.h` for the C header.
+//! The C library is built with `cargo rustc --release --lib --features ffi --crate-type cdylib` (or `staticlib`).
+//!
+//! Every function returning an [`AkinatorStatus`] writes its results into the provided out-params
+//! only when returning [`AkinatorStatus::Ok`]. Strings written to out-params are owned by the caller
+//! and must be freed with [`akinator_string_free`]. When a function fails, a description of the error
+//! can be retrieved with [`akinator_last_error`].
+//! Panics never unwind into the caller: they are reported as [`AkinatorStatus::Panic`],
+//! or as a null pointer or negative value for functions which do not return a status.
+
+use std::{
+    any::Any,
+    cell::RefCell,
+    ffi::{c_char, c_int, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    Akinator,
+    enums::{self, Answer, Language, Theme},
+    error::{Error, Result},
+};
+
+
+thread_local! {
+    /// the message of the last error that occurred on this thread
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// an opaque handle to an akinator game, created with [`akinator_new`] and freed with [`akinator_free`]
+pub struct AkinatorHandle {
+    /// the akinator game
+    akinator: Akinator,
+    /// the runtime used to drive the akinator's futures to completion
+    runtime: Runtime,
+}
+
+/// status codes returned from the FFI functions
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AkinatorStatus {
+    /// the call succeeded
+    Ok = 0,
+    /// a null pointer or an invalid value was passed in
+    InvalidArgument = 1,
+    /// there are no more available questions, see [`Error::NoMoreQuestions`]
+    NoMoreQuestions = 2,
+    /// already on the first question, see [`Error::CantGoBackAnyFurther`]
+    CantGoBackAnyFurther = 3,
+    /// any other error, see [`akinator_last_error`] for details
+    Error = 4,
+    /// the call panicked, see [`akinator_last_error`] for details
+    Panic = 5,
+}
+
+/// internal function recording `message` as the last error on this thread
+fn set_last_error(message: String) {
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// internal function running `body`, catching any panic so it does not unwind into the caller,
+/// in which case the panic is recorded as the last error and `on_panic` is returned instead
+fn guard<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        set_last_error(format!("panicked: {}", panic_message(payload.as_ref())));
+        on_panic
+    })
+}
+
+/// internal function extracting the message of a panic from its payload
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic")
+}
+
+/// internal function converting an akinator [`Result`] into an [`AkinatorStatus`],
+/// recording the error message if there is one
+fn status_from<T>(result: &Result<T>) -> AkinatorStatus {
+    match result {
+        Ok(_) => AkinatorStatus::Ok,
+        Err(err) => {
+            set_last_error(err.to_string());
+
+            match err {
+                Error::NoMoreQuestions => AkinatorStatus::NoMoreQuestions,
+                Error::CantGoBackAnyFurther => AkinatorStatus::CantGoBackAnyFurther,
+                _ => AkinatorStatus::Error,
+            }
+        },
+    }
+}
+
+/// internal function converting an optional rust string into an owned C string,
+/// returning a null pointer for [`None`] or strings containing nul bytes
+fn into_c_string(string: Option<String>) -> *mut c_char {
+    string
+        .and_then(|string| CString::new(string).ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// internal function reading an optional C string argument
+///
+/// # Safety
+/// `string` must either be null or a valid nul-terminated C string
+unsafe fn read_c_str<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        None
+    } else {
+        CStr::from_ptr(string).to_str().ok()
+    }
+}
+
+/// internal function writing `value` into the out-param `out`, if it is not null
+///
+/// # Safety
+/// `out` must either be null or valid for writes
+unsafe fn write_out(out: *mut *mut c_char, value: Option<String>) {
+    if !out.is_null() {
+        *out = into_c_string(value);
+    }
+}
+
+/// Creates a new akinator game, returning null on failure
+///
+/// `language` and `theme` are parsed the same way as [`Language`] and [`Theme`] from strings,
+/// (ex: "en", "animals") and may be null to use the defaults, unknown ones are rejected
+///
+/// # Safety
+/// `language` and `theme` must either be null or valid nul-terminated C strings
+#[no_mangle]
+pub unsafe extern "C" fn akinator_new(
+    language: *const c_char,
+    theme: *const c_char,
+    child_mode: bool,
+) -> *mut AkinatorHandle {
+    guard(ptr::null_mut(), || {
+        let language = match read_c_str(language).map(str::parse::<Language>) {
+            Some(Ok(language)) => language,
+            Some(Err(err)) => {
+                set_last_error(err.to_string());
+                return ptr::null_mut();
+            },
+            None => Language::default(),
+        };
+
+        let theme = match read_c_str(theme).map(enums::parse_theme) {
+            Some(Some(theme)) => theme,
+            Some(None) => {
+                set_last_error("Invalid theme, expected one of: characters, animals or objects".to_string());
+                return ptr::null_mut();
+            },
+            None => Theme::default(),
+        };
+
+        let runtime = match Builder::new_current_thread().enable_all().build() {
+            Ok(runtime) => runtime,
+            Err(err) => {
+                set_last_error(err.to_string());
+                return ptr::null_mut();
+            },
+        };
+
+        let mut akinator = match Akinator::new() {
+            Ok(akinator) => akinator
+                .with_language(language)
+                .with_theme(theme),
+            Err(err) => {
+                set_last_error(err.to_string());
+                return ptr::null_mut();
+            },
+        };
+        akinator.child_mode = child_mode;
+
+        Box::into_raw(Box::new(AkinatorHandle { akinator, runtime }))
+    })
+}
+
+/// Frees an akinator game created with [`akinator_new`]
+///
+/// # Safety
+/// `handle` must either be null or a pointer returned from [`akinator_new`] that has not yet been freed
+#[no_mangle]
+pub unsafe extern "C" fn akinator_free(handle: *mut AkinatorHandle) {
+    guard((), || {
+        if !handle.is_null() {
+            drop(Box::from_raw(handle));
+        }
+    })
+}
+
+/// Frees a string returned from any of the akinator FFI functions
+///
+/// # Safety
+/// `string` must either be null or a string returned from this library that has not yet been freed
+#[no_mangle]
+pub unsafe extern "C" fn akinator_string_free(string: *mut c_char) {
+    guard((), || {
+        if !string.is_null() {
+            drop(CString::from_raw(string));
+        }
+    })
+}
+
+/// Returns a description of the last error that occurred on this thread, or null if there was none
+///
+/// the returned string must be freed with [`akinator_string_free`]
+#[no_mangle]
+pub extern "C" fn akinator_last_error() -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        into_c_string(LAST_ERROR.with(|last| last.borrow().clone()))
+    })
+}
+
+/// Starts the akinator game, writing the first question into `out_question`
+///
+/// # Safety
+/// `handle` must be a valid pointer returned from [`akinator_new`],
+/// and `out_question` must either be null or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn akinator_start(
+    handle: *mut AkinatorHandle,
+    out_question: *mut *mut c_char,
+) -> AkinatorStatus {
+    guard(AkinatorStatus::Panic, || {
+        let Some(handle) = handle.as_mut() else {
+            return AkinatorStatus::InvalidArgument;
+        };
+
+        let result = handle.runtime.block_on(handle.akinator.start());
+        let status = status_from(&result);
+
+        if let Ok(question) = result {
+            write_out(out_question, question);
+        }
+        status
+    })
+}
+
+/// Answers the current question, writing the next question into `out_question`
+///
+/// `answer` is one of: 0 (yes), 1 (no), 2 (don't know), 3 (probably), 4 (probably not)
+///
+/// # Safety
+/// `handle` must be a valid pointer returned from [`akinator_new`],
+/// and `out_question` must either be null or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn akinator_answer(
+    handle: *mut AkinatorHandle,
+    answer: c_int,
+    out_question: *mut *mut c_char,
+) -> AkinatorStatus {
+    guard(AkinatorStatus::Panic, || {
+        let Some(handle) = handle.as_mut() else {
+            return AkinatorStatus::InvalidArgument;
+        };
+
+        let Some(answer) = usize::try_from(answer)
+            .ok()
+            .and_then(|answer| Answer::try_from(answer).ok())
+        else {
+            set_last_error(Error::InvalidAnswer.to_string());
+            return AkinatorStatus::InvalidArgument;
+        };
+
+        let result = handle.runtime.block_on(handle.akinator.answer(answer));
+        let status = status_from(&result);
+
+        if let Ok(question) = result {
+            write_out(out_question, question);
+        }
+        status
+    })
+}
+
+/// Goes back one question, writing the previous question into `out_question`
+///
+/// # Safety
+/// `handle` must be a valid pointer returned from [`akinator_new`],
+/// and `out_question` must either be null or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn akinator_back(
+    handle: *mut AkinatorHandle,
+    out_question: *mut *mut c_char,
+) -> AkinatorStatus {
+    guard(AkinatorStatus::Panic, || {
+        let Some(handle) = handle.as_mut() else {
+            return AkinatorStatus::InvalidArgument;
+        };
+
+        let result = handle.runtime.block_on(handle.akinator.back());
+        let status = status_from(&result);
+
+        if let Ok(question) = result {
+            write_out(out_question, question);
+        }
+        status
+    })
+}
+
+/// Tells the akinator to make its guess, writing its best guess' name, description
+/// and absolute picture url into the respective out-params (or null if it has no guess)
+///
+/// # Safety
+/// `handle` must be a valid pointer returned from [`akinator_new`],
+/// and the out-params must either be null or valid for writes
+#[no_mangle]
+pub unsafe extern "C" fn akinator_win(
+    handle: *mut AkinatorHandle,
+    out_name: *mut *mut c_char,
+    out_description: *mut *mut c_char,
+    out_picture_url: *mut *mut c_char,
+) -> AkinatorStatus {
+    guard(AkinatorStatus::Panic, || {
+        let Some(handle) = handle.as_mut() else {
+            return AkinatorStatus::InvalidArgument;
+        };
+
+        let result = handle.runtime.block_on(handle.akinator.win())
+            .map(|result| result.first);
+        let status = status_from(&result);
+
+        if let Ok(guess) = result {
+            write_out(out_name, guess.as_ref().map(|guess| guess.name.clone()));
+            write_out(out_description, guess.as_ref().map(|guess| guess.description.clone()));
+            write_out(out_picture_url, guess.map(|guess| guess.absolute_picture_path));
+        }
+        status
+    })
+}
+
+/// Returns the progression of the akinator, a float out of 100.0, or a negative value if `handle` is null
+///
+/// # Safety
+/// `handle` must either be null or a valid pointer returned from [`akinator_new`]
+#[no_mangle]
+pub unsafe extern "C" fn akinator_progression(handle: *const AkinatorHandle) -> f32 {
+    guard(-1.0, || {
+        handle
+            .as_ref()
+            .map_or(-1.0, |handle| handle.akinator.progression.value())
+    })
+}
+
+/// Returns the amount of questions asked and answered, or a negative value if `handle` is null
+///
+/// # Safety
+/// `handle` must either be null or a valid pointer returned from [`akinator_new`]
+#[no_mangle]
+pub unsafe extern "C" fn akinator_step(handle: *const AkinatorHandle) -> c_int {
+    guard(-1, || {
+        handle
+            .as_ref()
+            .map_or(-1, |handle| {
+                c_int::try_from(handle.akinator.step).unwrap_or(c_int::MAX)
+            })
+    })
+}
@@ -0,0 +1,286 @@
+//! An on-disk cache of the pictures of guesses, so the same portraits are not downloaded over and over
+//!
+//! Only available with the `image-cache` feature enabled.
+//! [`ImageCache`] is content-addressed: pictures are stored once under the SHA-256 of their bytes,
+//! and each URL points to the picture it was last downloaded as. Once the pictures exceed the cache's
+//! maximum size, the least recently used ones are evicted.
+//!
+//! ```no_run
+//! # async fn run(guess: akinator_rs::models::Guess) -> akinator_rs::error::Result<()> {
+//! use akinator_rs::images::ImageCache;
+//!
+//! let cache = ImageCache::new("pictures", 64 * 1024 * 1024);
+//! let picture: Vec<u8> = cache.guess_picture(&guess).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
+
+use reqwest::Client;
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+use crate::{
+    error::{Error, Result},
+    models::Guess,
+};
+
+
+/// the directory pictures are stored in, named after the hash of their contents
+const OBJECTS_DIR: &str = "objects";
+
+/// the directory URLs are indexed in, each file named after the hash of a URL and containing the hash of its picture
+const URLS_DIR: &str = "urls";
+
+/// a content-addressed disk cache of the pictures of guesses, bounded to a maximum size
+///
+/// the maximum size only accounts for the pictures themselves, not the small files indexing their URLs
+#[derive(Debug)]
+pub struct ImageCache {
+    /// the directory the cache is stored in
+    dir: PathBuf,
+    /// the maximum size of the stored pictures in bytes
+    max_size: u64,
+    /// the client pictures are downloaded with
+    client: Client,
+    /// incremented to give every temporary file a unique name
+    writes: AtomicU64,
+}
+
+impl ImageCache {
+    /// Creates a new [`ImageCache`] stored in `dir`, created on the first write if it does not exist,
+    /// holding up to `max_size` bytes of pictures
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>, max_size: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            max_size,
+            client: Client::new(),
+            writes: AtomicU64::new(0),
+        }
+    }
+
+    /// builder method to set the client pictures are downloaded with
+    #[must_use]
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// returns the directory the cache is stored in
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// returns the maximum size of the stored pictures in bytes
+    #[must_use]
+    pub const fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    /// returns the picture of `guess`, see [`Self::fetch`]
+    ///
+    /// # Errors
+    /// same as [`Self::fetch`]
+    pub async fn guess_picture(&self, guess: &Guess) -> Result<Vec<u8>> {
+        self.fetch(&guess.absolute_picture_path).await
+    }
+
+    /// returns the picture at `url`, downloading and storing it if it is not cached yet
+    ///
+    /// # Errors
+    /// [`Error::RequestError`] or [`Error::HttpStatus`] if the picture cannot be downloaded,
+    /// [`Error::IoError`] if the cache cannot be read or written
+    pub async fn fetch(&self, url: &str) -> Result<Vec<u8>> {
+        if let Some(picture) = self.get(url).await? {
+            return Ok(picture);
+        }
+
+        let response = self.client.get(url).send().await?;
+        let status = response.status();
+        let body = response.bytes().await?;
+
+        if !status.is_success() {
+            return Err(Error::HttpStatus {
+                status,
+                body: String::from_utf8_lossy(&body).chars().take(200).collect(),
+            });
+        }
+
+        self.insert(url, &body).await?;
+        Ok(body.to_vec())
+    }
+
+    /// returns the cached picture at `url`, [`None`] if it is not cached,
+    /// marking it as recently used
+    ///
+    /// # Errors
+    /// [`Error::IoError`] if the cache cannot be read
+    pub async fn get(&self, url: &str) -> Result<Option<Vec<u8>>> {
+        let Some(hash) = read_optional(&self.url_path(url)).await? else {
+            return Ok(None);
+        };
+
+        let path = self.object_path(&String::from_utf8_lossy(&hash));
+        let Some(picture) = read_optional(&path).await? else {
+            return Ok(None);
+        };
+
+        let _ = touch(path).await;
+        Ok(Some(picture))
+    }
+
+    /// stores `picture` as the picture at `url`, then evicts the least recently used pictures
+    /// if the cache exceeds its maximum size
+    ///
+    /// # Errors
+    /// [`Error::IoError`] if the cache cannot be written
+    pub async fn insert(&self, url: &str, picture: &[u8]) -> Result<()> {
+        let hash = hex_digest(picture);
+        let object_path = self.object_path(&hash);
+
+        if fs::try_exists(&object_path).await? {
+            let _ = touch(object_path).await;
+        } else {
+            self.write(&object_path, picture).await?;
+        }
+
+        self.write(&self.url_path(url), hash.as_bytes()).await?;
+        self.evict().await?;
+
+        Ok(())
+    }
+
+    /// returns the total size of the stored pictures in bytes
+    ///
+    /// # Errors
+    /// [`Error::IoError`] if the cache cannot be read
+    pub async fn size(&self) -> Result<u64> {
+        Ok(self.objects().await?
+            .iter()
+            .map(|(_, len, _)| len)
+            .sum())
+    }
+
+    /// evicts the least recently used pictures until the cache fits in its maximum size,
+    /// returning the amount of evicted pictures
+    ///
+    /// # Errors
+    /// [`Error::IoError`] if the cache cannot be read or a picture cannot be deleted
+    pub async fn evict(&self) -> Result<usize> {
+        let mut objects = self.objects().await?;
+        let mut size: u64 = objects.iter().map(|(_, len, _)| len).sum();
+
+        objects.sort_by_key(|(_, _, used_at)| *used_at);
+
+        let mut evicted = 0;
+        for (path, len, _) in objects {
+            if size <= self.max_size {
+                break;
+            }
+
+            match fs::remove_file(&path).await {
+                Err(err) if err.kind() != ErrorKind::NotFound => return Err(err.into()),
+                _ => {},
+            }
+
+            size = size.saturating_sub(len);
+            evicted += 1;
+        }
+
+        Ok(evicted)
+    }
+
+    /// internal method listing the stored pictures along with their size and when they were last used
+    async fn objects(&self) -> Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let mut entries = match fs::read_dir(self.dir.join(OBJECTS_DIR)).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut objects = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+
+            if metadata.is_file() && entry.path().extension().is_none() {
+                let used_at = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                objects.push((entry.path(), metadata.len(), used_at));
+            }
+        }
+
+        Ok(objects)
+    }
+
+    /// internal method writing `contents` to a temporary file renamed to `path`,
+    /// so readers never see a half-written file
+    async fn write(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+
+        let temp_path = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            self.writes.fetch_add(1, Ordering::Relaxed),
+        ));
+
+        fs::write(&temp_path, contents).await?;
+
+        if let Err(err) = fs::rename(&temp_path, path).await {
+            let _ = fs::remove_file(&temp_path).await;
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    /// internal method returning the path of the picture whose contents hash to `hash`
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.dir.join(OBJECTS_DIR).join(hash)
+    }
+
+    /// internal method returning the path of the file indexing `url`
+    fn url_path(&self, url: &str) -> PathBuf {
+        self.dir.join(URLS_DIR).join(hex_digest(url.as_bytes()))
+    }
+}
+
+/// internal function returning the SHA-256 of `bytes` as lowercase hex
+fn hex_digest(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// internal function reading the file at `path`, returning [`None`] if it does not exist
+async fn read_optional(path: &Path) -> Result<Option<Vec<u8>>> {
+    match fs::read(path).await {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// internal function marking the file at `path` as recently used by setting its modification time to now
+async fn touch(path: PathBuf) -> Result<()> {
+    tokio::task::spawn_blocking(move || {
+        std::fs::File::options()
+            .append(true)
+            .open(path)?
+            .set_modified(SystemTime::now())
+    })
+        .await
+        .map_err(|err| Error::IoError(err.into()))?
+        .map_err(Error::from)
+}
@@ -1,21 +1,32 @@
 //! A simple wrapper crate around the Akinator API
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    collections::HashSet,
+    net::{IpAddr, SocketAddr},
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant, UNIX_EPOCH},
+};
 
 use lazy_static::lazy_static;
-use regex::{Regex, RegexBuilder};
+use serde::de::DeserializeOwned;
+use tokio_util::sync::CancellationToken;
 use reqwest::{
     Client,
+    RequestBuilder,
+    StatusCode,
+    cookie::Jar,
     header::{
-        HeaderMap, HeaderName, HeaderValue, USER_AGENT,
+        HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT,
     },
 };
 
 use crate::{
-    enums::{Theme, Answer, Language},
+    enums::{Theme, Answer, Language, GameState, HttpVersion, IpVersion},
     error::{
         Result,
         Error,
+        CompletionCode,
         UpdateInfoError,
     },
 };
@@ -23,6 +34,58 @@ use crate::{
 pub mod models;
 pub mod error;
 pub mod enums;
+pub mod strategy;
+pub mod transcript;
+pub mod akitude;
+pub mod retry;
+pub mod cache;
+pub mod save;
+pub mod statistics;
+pub mod transport;
+pub mod clock;
+pub mod challenge;
+pub mod parse;
+pub mod typestate;
+pub mod diagnose;
+pub mod events;
+pub mod store;
+pub mod render;
+mod client;
+mod text;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "pool")]
+pub mod pool;
+#[cfg(feature = "simulate")]
+pub mod simulate;
+#[cfg(feature = "offline")]
+pub mod offline;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+#[cfg(feature = "actor")]
+pub mod actor;
+#[cfg(feature = "tower")]
+pub mod service;
+#[cfg(feature = "discord")]
+pub mod discord;
+#[cfg(feature = "indicatif")]
+pub mod progress;
+#[cfg(feature = "duplex")]
+pub mod duplex;
+#[cfg(feature = "image-cache")]
+pub mod images;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+/// the cookie store persisting cookies across a game's requests, see [`Akinator::with_cookie_jar`]
+pub use reqwest::cookie::Jar as CookieJar;
 
 
 lazy_static! {
@@ -45,6 +108,139 @@ lazy_static! {
     };
 }
 
+/// the progression at which [`Akinator::auto_play`] stops answering and makes the akinator guess
+pub const DEFAULT_WIN_THRESHOLD: f32 = 80.0;
+
+/// the amount of guesses fetched per page in [`Akinator::more_guesses`]
+/// when [`Akinator.guess_list_size`] is not set
+pub const DEFAULT_GUESS_PAGE_SIZE: usize = 10;
+
+/// the progression at which the akinator is ready to propose a guess, see [`Akinator::is_guess_ready`]
+pub const GUESS_READY_PROGRESSION: f32 = 97.0;
+
+/// the amount of questions after which the akinator proposes a guess again,
+/// even if it is not confident enough yet, see [`Akinator::is_guess_ready`]
+pub const GUESS_READY_INTERVAL: usize = 25;
+
+/// the last step of a game, at which the akinator always proposes a guess
+pub const LAST_STEP: usize = 79;
+
+/// the amount of questions without any progress after which a game is stalled, see [`Akinator::is_stalled`]
+pub const DEFAULT_STALL_WINDOW: usize = 5;
+
+/// the progression above which [`Akinator::recommend_win`] recommends guessing once the game is stalled
+pub const STALL_WIN_PROGRESSION: f32 = 50.0;
+
+/// the amount of recent steps the progression rate is averaged over, see [`Akinator::estimated_steps_remaining`]
+const ESTIMATE_WINDOW: usize = 5;
+
+/// the partner id sent when starting a game, the one used by the website
+pub const DEFAULT_PARTNER: usize = 1;
+
+/// internal function generating a JSONP callback name the way jQuery does:
+/// `jQuery` followed by its version and random digits, then `_` and the timestamp
+fn jquery_callback(timestamp: u64) -> String {
+    format!("jQuery331{:018}_{timestamp}", random_u64() % 1_000_000_000_000_000_000)
+}
+
+/// internal function generating a random number, seeded by the standard library's hasher
+pub(crate) fn random_u64() -> u64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish()
+}
+
+/// the max amount of characters of a response body to include in errors, see [`snippet`]
+const SNIPPET_LEN: usize = 200;
+
+/// internal function truncating a response body to the snippet included in errors
+fn snippet(body: &str) -> String {
+    body.chars().take(SNIPPET_LEN).collect()
+}
+
+/// internal function generating a random 16 hexadecimal digits id, for the game and request ids
+fn random_id() -> String {
+    format!("{:016x}", random_u64())
+}
+
+/// internal function running `call`, failing with [`Error::CallTimeout`] if it does not complete within `timeout`
+async fn within<T>(timeout: Duration, call: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+    tokio::time::timeout(timeout, call)
+        .await
+        .unwrap_or(Err(Error::CallTimeout(timeout)))
+}
+
+/// guard restoring a game to its state before [`Akinator::start`] or [`Akinator::restart`]
+/// if starting a new game fails or is aborted part-way, see [`Rollback::finish`]
+struct Rollback<'a> {
+    /// the game being started
+    akinator: &'a mut Akinator,
+    /// a copy of the game before it was started, [`None`] once the new game has started
+    previous: Option<Akinator>,
+}
+
+impl<'a> Rollback<'a> {
+    /// internal method copying `akinator` to restore it if starting a new game fails
+    fn new(akinator: &'a mut Akinator) -> Self {
+        let previous = Some(akinator.clone());
+        Self { akinator, previous }
+    }
+
+    /// keeps the new game if it has started, or restores the previous one if starting it failed
+    ///
+    /// the request id and responses recorded for debugging are kept either way,
+    /// as well as the discovered server and session info if no game was in progress
+    fn finish<T>(mut self, result: Result<T>) -> Result<T> {
+        if result.is_ok() {
+            self.previous = None;
+        } else if let Some(previous) = &mut self.previous {
+            let game = &*self.akinator;
+
+            if previous.session.is_none() {
+                previous.uri.clone_from(&game.uri);
+                previous.ws_url.clone_from(&game.ws_url);
+                previous.server_for = game.server_for;
+                previous.uid.clone_from(&game.uid);
+                previous.frontaddr.clone_from(&game.frontaddr);
+                previous.session_info_strategy = game.session_info_strategy;
+            }
+
+            previous.last_request_id.clone_from(&game.last_request_id);
+            previous.last_response_snippet.clone_from(&game.last_response_snippet);
+            previous.last_raw_response.clone_from(&game.last_raw_response);
+            previous.last_json_response.clone_from(&game.last_json_response);
+        }
+
+        self.akinator.report(result)
+    }
+}
+
+impl std::ops::Deref for Rollback<'_> {
+    type Target = Akinator;
+
+    fn deref(&self) -> &Self::Target {
+        self.akinator
+    }
+}
+
+impl std::ops::DerefMut for Rollback<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.akinator
+    }
+}
+
+impl Drop for Rollback<'_> {
+    fn drop(&mut self) {
+        if let Some(mut previous) = self.previous.take() {
+            // copies of a game start without subscribers, the game's own are kept
+            previous.events = std::mem::take(&mut self.akinator.events);
+            *self.akinator = previous;
+        }
+    }
+}
+
 /// simple macro for retrieving an `Option` field's value
 /// to avoid repetition as this is frequently used
 macro_rules! get_field {
@@ -67,19 +263,107 @@ pub struct Akinator {
     pub theme: Theme,
     /// indicates whether or not to filter out NSFW questions and content
     pub child_mode: bool,
+    /// indicates whether or not to filter out NSFW questions,
+    /// without the other restrictions of [`Self.child_mode`]
+    pub nsfw_filter: bool,
+    /// indicates whether or not to store the last raw response from the API
+    /// and include a snippet of it in JSON parse errors
+    pub debug: bool,
+    /// the amount of guesses to request when calling [`Self::win`]
+    ///
+    /// uses the API's default when [`None`]
+    pub guess_list_size: Option<usize>,
+    /// the min [`models::Guess::probability`] of the guesses kept when calling [`Self::win`],
+    /// as the tail of the list is usually noise
+    ///
+    /// keeps every guess when [`None`]
+    pub min_confidence: Option<f64>,
+    /// the max (width, height) of the guess pictures to request when calling [`Self::win`]
+    pub max_pic_size: Option<(usize, usize)>,
+    /// a custom question filter replacing the default one, see [`Self::with_question_filter`]
+    custom_question_filter: Option<String>,
+    /// the partner id sent when starting a game, see [`Self::with_partner`]
+    partner: usize,
+    /// a custom base URI replacing `https://{language}.akinator.com`, see [`Self::with_base_url`]
+    base_url: Option<String>,
+    /// a custom API server replacing the one found by [`Self::find_server`], see [`Self::with_ws_url`]
+    custom_ws_url: Option<String>,
+    /// a token used to abort in-flight requests, see [`Self::with_cancellation_token`]
+    cancellation_token: Option<CancellationToken>,
+    /// the overall time budget of a game, see [`Self::with_game_deadline`]
+    game_deadline: Option<Duration>,
+    /// when the time budget of the current game runs out, set when the game is started
+    deadline: Option<Instant>,
+    /// the amount of questions without progress after which the game is stalled, see [`Self::with_stall_window`]
+    stall_window: usize,
+    /// the id of the current game, see [`Self::game_id`]
+    game_id: String,
+    /// the id of the last request sent to the API, see [`Self::last_request_id`]
+    last_request_id: Option<String>,
+    /// a snippet of the body of the response to the last request sent with [`Self::send`],
+    /// regardless of [`Self.debug`] mode, included in the context of protocol errors
+    last_response_snippet: Option<String>,
+    /// the policy for retrying rate limited requests, see [`Self::with_retry_policy`]
+    retry_policy: Option<retry::RetryPolicy>,
+    /// the solver for anti-bot challenges, see [`Self::with_challenge_solver`]
+    challenge_solver: Option<Arc<dyn challenge::ChallengeSolver>>,
+    /// how long servers found by [`Self::find_server`] are cached, see [`Self::with_server_cache_ttl`]
+    server_cache_ttl: Duration,
+    /// when the game was started, used for the timings in [`Self::transcript`]
+    started_at: Option<Instant>,
+    /// the first question asked when the game was started
+    first_question: Option<String>,
+    /// every step taken in the game, see [`Self::transcript`]
+    history: Vec<transcript::TranscriptStep>,
+    /// the step at which the akinator last proposed a guess with [`Self::win`]
+    last_guess_step: usize,
+    /// the amount of rate limited requests retried during the game, see [`Self::statistics`]
+    retries: usize,
+    /// the progression at each step of the game, see [`Self::progression_history`]
+    progression_history: Vec<f32>,
+    /// the ids of the guesses the player said were wrong, see [`Self::reject_guess`]
+    rejected_guesses: HashSet<String>,
+    /// the answers given to reach the current question, undone by [`Self::back`]
+    answers: Vec<Answer>,
+    /// the answers undone by [`Self::back`], most recent last, redone by [`Self::forward`]
+    redo_answers: Vec<Answer>,
+    /// the events broadcast to the subscribers of the game, see [`Self::subscribe`]
+    events: events::Events,
+    /// the hook called with the serialized state after each step, see [`Self::on_state_change`]
+    state_change_hook: Option<save::StateChangeHook>,
+    /// the state of the game, see [`Self::state`]
+    state: GameState,
 
     /// The reqwest client used for this akinator session
     http_client: Client,
+    /// the cookie store of [`Self.http_client`], [`None`] if cookies are disabled
+    cookie_jar: Option<Arc<Jar>>,
+    /// the configuration [`Self.http_client`] is built with
+    client_config: client::ClientConfig,
+    /// the transport requests are sent through, see [`Self::with_service`]
+    transport: transport::Transport,
+    /// the sink every request and response is logged to, see [`Self::with_http_logger`]
+    http_logger: Option<transport::Logger>,
+    /// the clock timestamps are read from, see [`Self::with_clock`]
+    clock: Arc<dyn clock::Clock>,
     /// The POSIX timestamp the game session was started
     /// used for keeping track of sessions
     timestamp: u64,
+    /// the JSONP callback name sent with the API requests, generated per session like jQuery does
+    callback: String,
+    /// the POSIX timestamp of the last successful request, see [`Self::expires_in`]
+    last_active: u64,
     /// the base URI to use when making requests
     /// usually: https://{language}.akinator.com/
     uri: String,
     /// The unique identifier for the akinator session
     uid: Option<String>,
+    /// the strategy [`Self.uid`] and [`Self.frontaddr`] were extracted with, see [`Self::session_info_strategy`]
+    session_info_strategy: Option<parse::ExtractionStrategy>,
     /// the websocket url (server) used for the game
     ws_url: Option<String>,
+    /// the language and theme [`Self.ws_url`] was found for
+    server_for: Option<(Language, Theme)>,
     /// a (0 - 100) number representing the game's session
     session: Option<usize>,
     /// An IP address encoded in Base64, for authentication purposes
@@ -87,12 +371,16 @@ pub struct Akinator {
     /// A 9 - 10ish digit number that represents the game's signature
     signature: Option<usize>,
     question_filter: Option<String>,
+    /// the untouched body of the last response received from the API, only set in [`Self.debug`] mode
+    last_raw_response: Option<String>,
+    /// the last successfully parsed JSON response from the API, only set in [`Self.debug`] mode
+    last_json_response: Option<serde_json::Value>,
 
     /// returns the current question to answer
     pub current_question: Option<String>,
     /// returns the progress of the akinator
     /// a float out of 100.0
-    pub progression: f32,
+    pub progression: models::Progression,
     /// returns the a counter of questions asked and answered
     /// starts at 0
     pub step: usize,
@@ -104,7 +392,7 @@ pub struct Akinator {
     /// a vec containing all the possible guesses by the akinator
     ///
     /// Only will be set when [`Self::win`] has been called
-    pub guesses: Vec<models::Guess>,
+    pub guesses: models::GuessList,
 }
 
 impl Akinator {
@@ -114,32 +402,80 @@ impl Akinator {
     /// # Errors
     /// If failed to create HTTP [`reqwest`] client
     pub fn new() -> Result<Self> {
+        let cookie_jar = Arc::new(Jar::default());
+
         Ok(Self {
             language: Language::default(),
             theme: Theme::default(),
             child_mode: false,
-
-            http_client: Client::builder()
-                .danger_accept_invalid_certs(true)
-                .build()?,
+            nsfw_filter: false,
+            debug: false,
+            guess_list_size: None,
+            min_confidence: None,
+            max_pic_size: None,
+            custom_question_filter: None,
+            partner: DEFAULT_PARTNER,
+            base_url: None,
+            custom_ws_url: None,
+            cancellation_token: None,
+            game_deadline: None,
+            deadline: None,
+            stall_window: DEFAULT_STALL_WINDOW,
+            game_id: random_id(),
+            last_request_id: None,
+            last_response_snippet: None,
+            retry_policy: None,
+            challenge_solver: None,
+            server_cache_ttl: cache::DEFAULT_SERVER_CACHE_TTL,
+            started_at: None,
+            first_question: None,
+            history: Vec::new(),
+            last_guess_step: 0,
+            retries: 0,
+            progression_history: Vec::new(),
+            rejected_guesses: HashSet::new(),
+            answers: Vec::new(),
+            redo_answers: Vec::new(),
+            events: events::Events::default(),
+            state_change_hook: None,
+            state: GameState::NotStarted,
+
+            http_client: client::ClientConfig::default().build(Some(&cookie_jar))?,
+            client_config: client::ClientConfig::default(),
+            cookie_jar: Some(cookie_jar),
+            transport: transport::Transport::default(),
+            http_logger: None,
+            clock: Arc::new(clock::SystemClock),
             timestamp: 0,
+            callback: String::new(),
+            last_active: 0,
             uri: "https://en.akinator.com".to_string(),
             uid: None,
+            session_info_strategy: None,
             ws_url: None,
+            server_for: None,
             session: None,
             frontaddr: None,
             signature: None,
             question_filter: None,
+            last_raw_response: None,
+            last_json_response: None,
 
             current_question: None,
-            progression: 0.0,
+            progression: models::Progression::default(),
             step: 0,
 
             first_guess: None,
-            guesses: Vec::new(),
+            guesses: models::GuessList::default(),
         })
     }
 
+    /// internal method rebuilding [`Self.http_client`] after its configuration or cookie jar changed
+    fn rebuild_client(&mut self) -> Result<()> {
+        self.http_client = self.client_config.build(self.cookie_jar.as_ref())?;
+        Ok(())
+    }
+
     /// builder method to set the [`Self.theme`] for the akinator game
     #[must_use]
     pub const fn with_theme(mut self, theme: Theme) -> Self {
@@ -161,351 +497,2234 @@ impl Akinator {
         self
     }
 
-    /// Internal method to handle an error response from the akinator API
-    /// and return an appropriate Err value
+    /// builder method to set [`Self.nsfw_filter`], filtering out NSFW questions
+    /// without fully enabling [`Self.child_mode`]
     #[must_use]
-    #[allow(clippy::needless_pass_by_value)]
-    fn handle_error_response(completion: String) -> Error {
-        match completion.to_uppercase().as_str() {
-            "KO - SERVER DOWN" => Error::ServersDown,
-            "KO - TECHNICAL ERROR" => Error::TechnicalError,
-            "KO - TIMEOUT" => Error::TimeoutError,
-            "KO - ELEM LIST IS EMPTY" | "WARN - NO QUESTION" => Error::NoMoreQuestions,
-            _ => Error::ConnectionError,
-        }
+    pub const fn with_nsfw_filter(mut self, enabled: bool) -> Self {
+        self.nsfw_filter = enabled;
+        self
     }
 
-    /// internal method used to parse and find the [`Self.ws_url`] for this game
-    async fn find_server(&self) -> Result<String> {
-        lazy_static! {
-            static ref DATA_REGEX: Regex = RegexBuilder::new(
-                r#"\[\{"translated_theme_name":".*","urlWs":"https:\\/\\/srv[0-9]+\.akinator\.com:[0-9]+\\/ws","subject_id":"[0-9]+"\}\]"#
-            )
-                .case_insensitive(true)
-                .multi_line(true)
-                .build()
-                .unwrap();
-        }
-
-        let html = self.http_client.get(&self.uri)
-            .send()
-            .await?
-            .text()
-            .await?;
-
-        let id = (self.theme as usize)
-            .to_string();
+    /// builder function to turn on [`Self.debug`] mode
+    #[must_use]
+    pub const fn with_debug(mut self) -> Self {
+        self.debug = true;
+        self
+    }
 
-        if let Some(mat) = DATA_REGEX.find(html.as_str()) {
-            let json: Vec<models::ServerData> =
-                serde_json::from_str(mat.as_str())?;
+    /// builder method to set the [`Self.guess_list_size`] requested when calling [`Self::win`]
+    #[must_use]
+    pub const fn with_guess_list_size(mut self, size: usize) -> Self {
+        self.guess_list_size = Some(size);
+        self
+    }
 
-            let mat = json
-                .into_iter()
-                .find(|entry| entry.subject_id == id)
-                .ok_or(Error::NoDataFound)?;
+    /// builder method to set the [`Self.min_confidence`] of the guesses kept when calling [`Self::win`],
+    /// from `0.0` to `1.0`
+    #[must_use]
+    pub const fn with_min_confidence(mut self, confidence: f64) -> Self {
+        self.min_confidence = Some(confidence);
+        self
+    }
 
-            Ok(mat.url_ws)
-        } else {
-            Err(Error::NoDataFound)
-        }
+    /// builder method to set the [`Self.max_pic_size`] of the guess pictures
+    #[must_use]
+    pub const fn with_max_pic_size(mut self, width: usize, height: usize) -> Self {
+        self.max_pic_size = Some((width, height));
+        self
     }
 
-    /// internal method used to parse and find the session uid and frontaddr for the akinator session
+    /// builder method to set a custom question filter, passed to the API just like the website does
+    /// (ex: `cat=1` to only ask family friendly questions)
     ///
-    /// Done by parsing the javascript of the site, extracting variable values
-    async fn find_session_info(&self) -> Result<(String, String)> {
-        lazy_static! {
-            static ref VARS_REGEX: Regex =
-                RegexBuilder::new(r"var uid_ext_session = '(.*)';\n.*var frontaddr = '(.*)';")
-                    .case_insensitive(true)
-                    .multi_line(true)
-                    .build()
-                    .unwrap();
-        }
+    /// replaces the default filter, which is `cat=1` in [`Self.child_mode`] or with [`Self.nsfw_filter`], and empty otherwise
+    #[must_use]
+    pub fn with_question_filter(mut self, filter: impl Into<String>) -> Self {
+        self.custom_question_filter = Some(filter.into());
+        self
+    }
 
-        let html = self.http_client
-            .get("https://en.akinator.com/game")
-            .send()
-            .await?
-            .text()
-            .await?;
-
-        if let Some(mat) = VARS_REGEX.captures(html.as_str()) {
-            let result = (
-                mat.get(1).ok_or(Error::NoDataFound)?
-                    .as_str().to_string(),
-                mat.get(2).ok_or(Error::NoDataFound)?
-                    .as_str().to_string(),
-            );
-
-            Ok(result)
-        } else {
-            Err(Error::NoDataFound)
-        }
+    /// returns the question filter used by the current game, if started
+    #[must_use]
+    pub fn question_filter(&self) -> Option<&str> {
+        self.question_filter.as_deref()
     }
 
-    /// internal method used to parse the response returned from the API
+    /// builder method to set the partner id sent when starting a game,
+    /// which changes the question pools served (the mobile apps and some mirrors use other ids)
     ///
-    /// strips the function call wrapped around the json, returning the json string
+    /// defaults to [`DEFAULT_PARTNER`], the one used by the website
     #[must_use]
-    #[allow(clippy::needless_pass_by_value)]
-    fn parse_response(html: String) -> String {
-        lazy_static! {
-            static ref RESPONSE_REGEX: Regex =
-                RegexBuilder::new(r"^jQuery\d+_\d+\(")
-                    .case_insensitive(true)
-                    .multi_line(true)
-                    .build()
-                    .unwrap();
-        }
-
-        RESPONSE_REGEX
-            .replace(html.as_str(), "")
-            .strip_suffix(')')
-            .unwrap_or(html.as_str())
-            .to_string()
+    pub const fn with_partner(mut self, partner: usize) -> Self {
+        self.partner = partner;
+        self
     }
 
-    /// updates the [`Akinator`] fields after each response
-    fn update_move_info(&mut self, json: models::MoveJson) -> Result<(), UpdateInfoError> {
-        let params = json.parameters
-            .ok_or(UpdateInfoError::MissingData)?;
-
-        self.current_question = Some(
-            params.question
-        );
-
-        self.progression = params.progression
-            .parse::<f32>()?;
+    /// returns the partner id sent when starting a game, see [`Self::with_partner`]
+    #[must_use]
+    pub const fn partner(&self) -> usize {
+        self.partner
+    }
 
-        self.step = params.step
-            .parse::<usize>()?;
+    /// builder method to send the website requests to a custom host instead of `https://{language}.akinator.com`,
+    /// ex: a reverse proxy or a regional mirror
+    ///
+    /// a `{language}` placeholder in `url` is replaced by the game's language code,
+    /// ex: `https://proxy.example.com/{language}`.
+    /// Servers found through a custom host are not cached, see [`Self::with_server_cache_ttl`]
+    #[must_use]
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into().trim_end_matches('/').to_string());
+        self
+    }
 
-        Ok(())
+    /// builder method to send the API requests to a custom server instead of the one found on the homepage,
+    /// ex: `https://proxy.example.com/ws`
+    #[must_use]
+    pub fn with_ws_url(mut self, url: impl Into<String>) -> Self {
+        self.custom_ws_url = Some(url.into().trim_end_matches('/').to_string());
+        self
     }
 
-    /// similar to [`Self::update_move_info`], but only called once when [`Self::start`] is called
-    fn update_start_info(&mut self, json: &models::StartJson) -> Result<(), UpdateInfoError> {
-        let ident = &json.parameters
-            .as_ref()
-            .ok_or(UpdateInfoError::MissingData)?
-            .identification;
+    /// internal method returning the base URI of the website for the game's language,
+    /// see [`Self::with_base_url`]
+    fn base_uri(&self) -> String {
+        self.base_url.as_ref().map_or_else(
+            || format!("https://{}.akinator.com", self.language),
+            |url| url.replace("{language}", &self.language.to_string()),
+        )
+    }
 
-        let step_info = &json.parameters
-            .as_ref()
-            .ok_or(UpdateInfoError::MissingData)?
-            .step_information;
+    /// builder method to set a [`CancellationToken`] for the akinator game
+    ///
+    /// once the token is cancelled, any in-flight and future requests are aborted
+    /// returning [`Error::Cancelled`]. As the game's fields are only updated once a response has been received,
+    /// and [`Self::start`] and [`Self::restart`] only replace the previous game once the new one has started,
+    /// an aborted request leaves the game as it was before it. Calls made of several requests,
+    /// such as [`Self::renew`] or [`Self::auto_play`], keep the progress of the requests completed before the abort.
+    #[must_use]
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
 
-        self.session = Some(
-            ident.session
-                .parse::<usize>()?
-        );
+    /// builder method to set the overall time budget of each game, counted from [`Self::start`] or [`Self::restart`]
+    ///
+    /// every request made once the budget has run out fails fast with [`Error::DeadlineExceeded`],
+    /// and in-flight requests are aborted when it runs out, bounding the worst-case time spent on a game.
+    /// Aborted requests leave the game as described in [`Self::with_cancellation_token`]
+    #[must_use]
+    pub const fn with_game_deadline(mut self, budget: Duration) -> Self {
+        self.game_deadline = Some(budget);
+        self
+    }
 
-        self.signature = Some(
-            ident.signature
-                .parse::<usize>()?
-        );
+    /// builder method to set the amount of questions without any progress after which the game is stalled,
+    /// defaults to [`DEFAULT_STALL_WINDOW`], `0` disables stall detection, see [`Self::is_stalled`]
+    #[must_use]
+    pub const fn with_stall_window(mut self, questions: usize) -> Self {
+        self.stall_window = questions;
+        self
+    }
 
-        self.current_question = Some(
-            step_info.question.clone()
-        );
+    /// builder method to automatically wait and retry requests rate limited by the akinator servers,
+    /// following the `Retry-After` header within the limits of `policy`
+    ///
+    /// without a retry policy, rate limited requests fail with [`Error::RateLimited`]
+    #[must_use]
+    pub const fn with_retry_policy(mut self, policy: retry::RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
 
-        self.progression = step_info.progression
-            .parse::<f32>()?;
+    /// returns the [`retry::RetryPolicy`] set with [`Self::with_retry_policy`], if any
+    #[must_use]
+    pub const fn retry_policy(&self) -> Option<&retry::RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
 
-        self.step = step_info.step
-            .parse::<usize>()?;
+    /// builder method to solve the anti-bot challenges sometimes served by the website,
+    /// the cookies returned by `solver` are added to the game's cookie jar and the request is retried once
+    ///
+    /// without a solver, or with cookies disabled, challenged requests fail with [`Error::BotChallenge`]
+    #[must_use]
+    pub fn with_challenge_solver(mut self, solver: impl challenge::ChallengeSolver + 'static) -> Self {
+        self.challenge_solver = Some(Arc::new(solver));
+        self
+    }
 
-        Ok(())
+    /// builder method to set how long the servers found when starting a game are cached,
+    /// shared by every game of the same language and theme in the process
+    ///
+    /// defaults to [`cache::DEFAULT_SERVER_CACHE_TTL`], use [`Duration::ZERO`] to always look up the server
+    #[must_use]
+    pub const fn with_server_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.server_cache_ttl = ttl;
+        self
     }
 
-    /// Starts the akinator game and returns the first question
+    /// builder method to persist cookies in `cookie_jar`,
+    /// which allows sharing cookies between several games
     ///
-    /// # Errors
+    /// cookies set by the API are sent back on subsequent requests just like the website does,
+    /// and are persisted in a fresh cookie jar by default
     ///
-    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
-    pub async fn start(&mut self) -> Result<Option<String>> {
-        self.uri = format!("https://{}.akinator.com", self.language);
-        self.ws_url = Some(self.find_server().await?);
-
-        let (uid, frontaddr) = self.find_session_info().await?;
-        self.uid = Some(uid);
-        self.frontaddr = Some(frontaddr);
-
-        self.timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)?
-            .as_secs();
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn with_cookie_jar(mut self, cookie_jar: Arc<CookieJar>) -> Result<Self> {
+        self.cookie_jar = Some(cookie_jar);
+        self.rebuild_client()?;
 
-        let soft_constraint =
-            if self.child_mode {
-                "ETAT='EN'"
-            } else {
-                ""
-            }
-            .to_string();
+        Ok(self)
+    }
 
-        self.question_filter = Some(
-            if self.child_mode {
-                "cat=1"
-            } else {
-                ""
-            }
-            .to_string()
-        );
+    /// builder method to disable the cookie store, discarding any cookies set by the API
+    ///
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn without_cookies(mut self) -> Result<Self> {
+        self.cookie_jar = None;
+        self.rebuild_client()?;
 
-        let params = [
-            (
-                "callback",
-                format!("jQuery331023608747682107778_{}", self.timestamp),
-            ),
-            ("urlApiWs", get_field!(self.ws_url)),
-            ("partner", 1.to_string()),
-            ("childMod", self.child_mode.to_string()),
-            ("player", "website-desktop".to_string()),
-            ("uid_ext_session", get_field!(self.uid)),
-            ("frontaddr", get_field!(self.frontaddr)),
-            ("constraint", "ETAT<>'AV'".to_string()),
-            ("soft_constraint", soft_constraint),
-            (
-                "question_filter",
-                get_field!(self.question_filter),
-            ),
-        ];
+        Ok(self)
+    }
 
-        let response = self.http_client
-            .get(format!("{}/new_session", &self.uri))
-            .headers(HEADERS.clone())
-            .query(&params)
-            .send()
-            .await?;
+    /// builder method to resolve `host` to `addr` instead of querying DNS,
+    /// for environments with broken DNS or to pin a known-good server
+    ///
+    /// can be called several times for different hosts (ex: `en.akinator.com` and the `srv*.akinator.com` API servers),
+    /// requests keep using the port of their URL
+    ///
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn with_resolve(mut self, host: impl Into<String>, addr: IpAddr) -> Result<Self> {
+        self.client_config.resolve.push((host.into(), SocketAddr::new(addr, 0)));
+        self.rebuild_client()?;
 
-        let json_string = Self::parse_response(response.text().await?);
-        let json: models::StartJson =
-            serde_json::from_str(json_string.as_str())?;
+        Ok(self)
+    }
 
-        if json.completion.as_str() == "OK" {
-            self.update_start_info(&json)?;
+    /// builder method to only connect to the akinator servers over IPv4 or IPv6,
+    /// ex: [`IpVersion::V4`] on networks where requests hang on the flaky IPv6 (AAAA) records
+    ///
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn with_ip_version(mut self, ip_version: IpVersion) -> Result<Self> {
+        self.client_config.ip_version = ip_version;
+        self.rebuild_client()?;
 
-            Ok(self.current_question.clone())
-        } else {
-            Err(Self::handle_error_response(json.completion))
-        }
+        Ok(self)
     }
 
-    /// answers the akinator's current question which can be retrieved with [`Self.current_question`]
+    /// builder method to set the max amount of idle connections kept open per host for reuse
     ///
     /// # Errors
-    ///
-    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
-    pub async fn answer(&mut self, answer: Answer) -> Result<Option<String>> {
-        let params = [
-            (
-                "callback",
-                format!("jQuery331023608747682107778_{}", self.timestamp),
-            ),
-            ("urlApiWs", get_field!(self.ws_url)),
-            ("childMod", self.child_mode.to_string()),
-            ("session", get_field!(self.session)),
-            ("signature", get_field!(self.signature)),
-            ("frontaddr", get_field!(self.frontaddr)),
-            ("step", self.step.to_string()),
-            ("answer", (answer as u8).to_string()),
-            (
-                "question_filter",
-                get_field!(self.question_filter),
-            ),
-        ];
-
-        let response = self.http_client
-            .get(format!("{}/answer_api", &self.uri))
-            .headers(HEADERS.clone())
-            .query(&params)
-            .send()
-            .await?
-            .text()
-            .await?;
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Result<Self> {
+        self.client_config.pool_max_idle_per_host = Some(max);
+        self.rebuild_client()?;
 
-        let json_string = Self::parse_response(response);
-        let json: models::MoveJson =
-            serde_json::from_str(json_string.as_str())?;
+        Ok(self)
+    }
 
-        if json.completion.as_str() == "OK" {
-            self.update_move_info(json)?;
+    /// builder method to set how long idle connections are kept open for reuse, [`None`] to keep them forever
+    ///
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn with_pool_idle_timeout(mut self, timeout: Option<Duration>) -> Result<Self> {
+        self.client_config.pool_idle_timeout = Some(timeout);
+        self.rebuild_client()?;
 
-            Ok(self.current_question.clone())
-        } else {
-            Err(Self::handle_error_response(json.completion))
-        }
+        Ok(self)
     }
 
-    /// tells the akinator to end the game and make it's guess
-    /// and returns its best guess, which also can be retrieved with [`Self.first_guess`]
+    /// builder method to set the [`HttpVersion`] requests are made with
     ///
     /// # Errors
-    ///
-    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
-    pub async fn win(&mut self) -> Result<Option<models::Guess>> {
-        let params = [
-            (
-                "callback",
-                format!("jQuery331023608747682107778_{}", self.timestamp),
-            ),
-            ("childMod", self.child_mode.to_string()),
-            ("session", get_field!(self.session)),
-            ("signature", get_field!(self.signature)),
-            ("step", self.step.to_string()),
-        ];
-
-        let response = self.http_client
-            .get(format!("{}/list", get_field!(self.ws_url)))
-            .headers(HEADERS.clone())
-            .query(&params)
-            .send()
-            .await?
-            .text()
-            .await?;
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn with_http_version(mut self, http_version: HttpVersion) -> Result<Self> {
+        self.client_config.http_version = http_version;
+        self.rebuild_client()?;
 
-        let json_string = Self::parse_response(response);
-        let json: models::WinJson =
-            serde_json::from_str(json_string.as_str())?;
+        Ok(self)
+    }
 
-        if json.completion.as_str() == "OK" {
-            let elements = json.parameters
-                .ok_or(UpdateInfoError::MissingData)?
-                .elements;
+    /// builder method to toggle adaptive flow control on HTTP/2 connections,
+    /// which sizes the connection windows from the measured bandwidth
+    ///
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn with_http2_adaptive_window(mut self, enabled: bool) -> Result<Self> {
+        self.client_config.http2_adaptive_window = enabled;
+        self.rebuild_client()?;
 
-            self.guesses = elements
-                .into_iter()
-                .map(|e| e.element)
-                .collect::<Vec<models::Guess>>();
+        Ok(self)
+    }
 
-            self.first_guess = self.guesses
-                .first()
-                .cloned();
+    /// builder method to send every request of the game through a [`tower::Service`],
+    /// so the transport can be wrapped in tower layers (timeouts, retries, tracing, rate limits...)
+    ///
+    /// requests are built with the game's own [`reqwest::Client`], which is also a [`tower::Service`]
+    /// that can be wrapped, see [`Self::with_layer`]
+    #[cfg(feature = "tower")]
+    #[must_use]
+    pub fn with_service<S>(mut self, service: S) -> Self
+    where
+        S: tower::Service<reqwest::Request, Response = reqwest::Response> + Clone + Send + 'static,
+        S::Error: Into<transport::BoxError>,
+        S::Future: Send + 'static,
+    {
+        use tower::ServiceExt;
+
+        let service = tower::util::BoxCloneService::new(service.map_err(Into::into));
+        self.transport = transport::Transport::Service(Arc::new(std::sync::Mutex::new(service)));
+        self
+    }
 
-            Ok(self.first_guess.clone())
-        } else {
-            Err(Self::handle_error_response(json.completion))
-        }
+    /// builder method to wrap the game's own [`reqwest::Client`] in a tower `layer`,
+    /// ex: a [`tower::ServiceBuilder`] stacking timeouts, retries or rate limits
+    ///
+    /// should be called after the other builder methods configuring the client, like [`Self::with_cookie_jar`]
+    #[cfg(feature = "tower")]
+    #[must_use]
+    pub fn with_layer<L>(self, layer: L) -> Self
+    where
+        L: tower::Layer<Client>,
+        L::Service: tower::Service<reqwest::Request, Response = reqwest::Response> + Clone + Send + 'static,
+        <L::Service as tower::Service<reqwest::Request>>::Error: Into<transport::BoxError>,
+        <L::Service as tower::Service<reqwest::Request>>::Future: Send + 'static,
+    {
+        let service = layer.layer(self.http_client.clone());
+        self.with_service(service)
     }
 
-    /// Goes back 1 question and returns the current question
-    /// Returns an Err value with [`Error::CantGoBackAnyFurther`] if we are already on question 0
+    /// builder method to send every request of the game through `client` and its middleware chain
+    /// (ex: `reqwest-retry` and tracing middleware)
     ///
-    /// # Errors
+    /// requests are still built by the game, but sent with `client`'s own configuration,
+    /// so the game's cookie jar is not used
+    #[cfg(feature = "reqwest-middleware")]
+    #[must_use]
+    pub fn with_middleware_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.transport = transport::Transport::Middleware(client);
+        self
+    }
+
+    /// builder method to send every request of the game with a custom [`transport::HttpBackend`],
+    /// to use an HTTP client other than [`reqwest`]
+    ///
+    /// the game's cookie jar is not used, cookies are left up to the backend
+    #[must_use]
+    pub fn with_backend(mut self, backend: impl transport::HttpBackend + 'static) -> Self {
+        self.transport = transport::Transport::Backend(Arc::new(backend));
+        self
+    }
+
+    /// builder method to set a [`transport::HttpLogger`] receiving the sanitized metadata of every request sent by the game
+    /// along with a summary of its response, ex: to pipe traffic logs into an external system without enabling `tracing`
+    ///
+    /// headers, cookies and the session's credentials are never logged, see [`transport::RequestLog`]
+    #[must_use]
+    pub fn with_http_logger(mut self, logger: impl transport::HttpLogger + 'static) -> Self {
+        self.http_logger = Some(transport::Logger(Arc::new(logger)));
+        self
+    }
+
+    /// builder method to set the clock timestamps are read from, defaults to [`clock::SystemClock`]
+    ///
+    /// ex: a [`clock::FixedClock`] to pin the timestamps sent to the API and stored in saved games
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl clock::Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// builder method to set a hook called with the JSON serialized state of the game, see [`Self::save_state`],
+    /// after each successful start, answer, back and win
+    ///
+    /// the bytes can be persisted to recover from crashes without saving manually,
+    /// and restored with [`save::SavedGame::from_json`] and [`Self::from_state`]
+    #[must_use]
+    pub fn on_state_change(mut self, hook: impl Fn(&[u8]) + Send + Sync + 'static) -> Self {
+        self.state_change_hook = Some(save::StateChangeHook(Arc::new(hook)));
+        self
+    }
+
+    /// internal method returning the current POSIX timestamp according to [`Self::with_clock`]
+    fn now_timestamp(&self) -> Result<u64> {
+        Ok(self.clock.now()
+            .duration_since(UNIX_EPOCH)?
+            .as_secs())
+    }
+
+    /// returns the cookie jar persisting the game's cookies, [`None`] if cookies are disabled
+    #[must_use]
+    pub const fn cookie_jar(&self) -> Option<&Arc<CookieJar>> {
+        self.cookie_jar.as_ref()
+    }
+
+    /// returns the random id of the game, a new one being generated by [`Self::start`] and [`Self::restart`]
+    ///
+    /// included in the context of errors and, with the `tracing` feature enabled, in the span of each request,
+    /// to correlate the logs of a game when serving several users
+    #[must_use]
+    pub fn game_id(&self) -> &str {
+        &self.game_id
+    }
+
+    /// returns the random id of the last request sent to the API in the current game, if any,
+    /// included in the context of errors and, with the `tracing` feature enabled, in the span of the request
+    #[must_use]
+    pub fn last_request_id(&self) -> Option<&str> {
+        self.last_request_id.as_deref()
+    }
+
+    /// returns the time left before the game's deadline set with [`Self::with_game_deadline`] runs out,
+    /// or [`None`] if there is no deadline or the game has not been started yet
+    #[must_use]
+    pub fn deadline_remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// returns the [`CancellationToken`] set with [`Self::with_cancellation_token`], if any
+    #[must_use]
+    pub const fn cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation_token.as_ref()
+    }
+
+    /// returns the body of the last response received from the API, untouched,
+    /// including the pages scraped when starting a game
+    ///
+    /// Only will be set when [`Self.debug`] mode is on
+    #[must_use]
+    pub fn last_raw_response(&self) -> Option<&str> {
+        self.last_raw_response.as_deref()
+    }
+
+    /// returns the last successfully parsed JSON response from the API
+    ///
+    /// Only will be set when [`Self.debug`] mode is on
+    #[must_use]
+    pub const fn last_json_response(&self) -> Option<&serde_json::Value> {
+        self.last_json_response.as_ref()
+    }
+
+    /// returns the full state of the game, which can be serialized and restored later with [`Self::from_state`]
+    ///
+    /// # Errors
+    /// If retrieving the current time fails
+    pub fn save_state(&self) -> Result<save::SavedGame> {
+        Ok(save::SavedGame {
+            saved_at: self.now_timestamp()?,
+
+            language: self.language,
+            theme: self.theme,
+            child_mode: self.child_mode,
+            nsfw_filter: self.nsfw_filter,
+            guess_list_size: self.guess_list_size,
+            min_confidence: self.min_confidence,
+            max_pic_size: self.max_pic_size,
+            custom_question_filter: self.custom_question_filter.clone(),
+            partner: self.partner,
+            base_url: self.base_url.clone(),
+            custom_ws_url: self.custom_ws_url.clone(),
+
+            timestamp: self.timestamp,
+            callback: Some(self.callback.clone()),
+            uri: self.uri.clone(),
+            uid: self.uid.clone(),
+            ws_url: self.ws_url.clone(),
+            session: self.session,
+            frontaddr: self.frontaddr.clone(),
+            signature: self.signature,
+            question_filter: self.question_filter.clone(),
+
+            current_question: self.current_question.clone(),
+            progression: self.progression.value(),
+            step: self.step,
+            first_question: self.first_question.clone(),
+            history: self.history.clone(),
+            last_guess_step: self.last_guess_step,
+            progression_history: self.progression_history.clone(),
+            rejected_guesses: self.rejected_guesses.clone(),
+            first_guess: self.first_guess.clone(),
+            guesses: self.guesses.clone(),
+            state: Some(self.state),
+            game_id: Some(self.game_id.clone()),
+        })
+    }
+
+    /// restores a game from a state returned by [`Self::save_state`], with a new HTTP client
+    ///
+    /// does not check whether the game's session is still alive on the server, see [`save::SavedGame::is_expired`]
+    ///
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn from_state(state: save::SavedGame) -> Result<Self> {
+        let mut akinator = Self::new()?;
+
+        let elapsed = Duration::from_secs(state.saved_at.saturating_sub(state.timestamp));
+
+        akinator.language = state.language;
+        akinator.theme = state.theme;
+        akinator.child_mode = state.child_mode;
+        akinator.nsfw_filter = state.nsfw_filter;
+        akinator.guess_list_size = state.guess_list_size;
+        akinator.min_confidence = state.min_confidence;
+        akinator.max_pic_size = state.max_pic_size;
+        akinator.custom_question_filter = state.custom_question_filter;
+        akinator.partner = state.partner;
+        akinator.base_url = state.base_url;
+        akinator.custom_ws_url = state.custom_ws_url;
+
+        akinator.timestamp = state.timestamp;
+        akinator.callback = state.callback
+            .unwrap_or_else(|| jquery_callback(state.timestamp));
+        akinator.last_active = state.saved_at;
+        akinator.uri = state.uri;
+        akinator.uid = state.uid;
+        akinator.ws_url = state.ws_url;
+        akinator.server_for = akinator.ws_url
+            .as_ref()
+            .map(|_| (state.language, state.theme));
+        akinator.session = state.session;
+        akinator.frontaddr = state.frontaddr;
+        akinator.signature = state.signature;
+        akinator.question_filter = state.question_filter;
+
+        akinator.current_question = state.current_question;
+        akinator.progression = models::Progression::new(state.progression);
+        akinator.step = state.step;
+        akinator.started_at = akinator.session
+            .and_then(|_| Instant::now().checked_sub(elapsed));
+        akinator.first_question = state.first_question;
+        akinator.history = state.history;
+
+        for entry in &akinator.history {
+            match entry.action {
+                transcript::Action::Answer(answer) => akinator.answers.push(answer),
+                transcript::Action::Back => { akinator.answers.pop(); },
+            }
+        }
+
+        akinator.last_guess_step = state.last_guess_step;
+        akinator.progression_history = state.progression_history;
+        akinator.rejected_guesses = state.rejected_guesses;
+        akinator.first_guess = state.first_guess;
+        akinator.guesses = state.guesses;
+        akinator.state = state.state.unwrap_or_else(|| {
+            if akinator.session.is_none() {
+                GameState::NotStarted
+            } else if akinator.first_guess.is_some() && akinator.last_guess_step == akinator.step {
+                GameState::AwaitingConfirmation
+            } else {
+                GameState::InProgress
+            }
+        });
+
+        if let Some(game_id) = state.game_id {
+            akinator.game_id = game_id;
+        }
+
+        Ok(akinator)
+    }
+
+    /// saves the full state of the game to a JSON file at `path`, see [`Self::save_state`]
+    ///
+    /// # Errors
+    /// If serializing the state or writing the file fails
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = self.save_state()?.to_json()?;
+        std::fs::write(path, json)?;
+
+        Ok(())
+    }
+
+    /// restores a game saved to a JSON file at `path` with [`Self::save_to_file`]
+    ///
+    /// # Errors
+    /// If reading or deserializing the file fails,
+    /// or [`Error::SessionExpired`] if the saved game's session has likely expired on the server
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let state = save::SavedGame::from_json(&std::fs::read_to_string(path)?)?;
+
+        if state.is_expired() {
+            return Err(Error::SessionExpired);
+        }
+
+        Self::from_state(state)
+    }
+
+    /// returns the websocket url (server) used for the game, if found
+    #[must_use]
+    pub fn ws_url(&self) -> Option<&str> {
+        self.ws_url.as_deref()
+    }
+
+    /// returns the number representing the game's session, if started
+    #[must_use]
+    pub const fn session(&self) -> Option<usize> {
+        self.session
+    }
+
+    /// returns the number representing the game's signature, if started
+    #[must_use]
+    pub const fn signature(&self) -> Option<usize> {
+        self.signature
+    }
+
+    /// returns the unique identifier for the akinator session, if found
+    #[must_use]
+    pub fn uid(&self) -> Option<&str> {
+        self.uid.as_deref()
+    }
+
+    /// returns the Base64 encoded IP address used for authentication, if found
+    #[must_use]
+    pub fn frontaddr(&self) -> Option<&str> {
+        self.frontaddr.as_deref()
+    }
+
+    /// returns the strategy that extracted the [`Self::uid`] and [`Self::frontaddr`] from the game page,
+    /// useful to notice when the website changed and the preferred strategies stopped working
+    #[must_use]
+    pub const fn session_info_strategy(&self) -> Option<parse::ExtractionStrategy> {
+        self.session_info_strategy
+    }
+
+    /// returns all of the game's internal session data at once,
+    /// or [`None`] if the game has not been started yet
+    #[must_use]
+    pub fn session_info(&self) -> Option<models::SessionInfo> {
+        Some(models::SessionInfo {
+            ws_url: self.ws_url.clone()?,
+            session: self.session?,
+            signature: self.signature?,
+            uid: self.uid.clone()?,
+            frontaddr: self.frontaddr.clone()?,
+        })
+    }
+
+    /// Internal method to handle an error response from the akinator API
+    /// and return an appropriate Err value
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    fn handle_error_response(completion: String) -> Error {
+        match CompletionCode::from(completion.as_str()) {
+            CompletionCode::ServerDown => Error::ServersDown,
+            CompletionCode::TechnicalError => Error::TechnicalError,
+            CompletionCode::Timeout => Error::TimeoutError,
+            CompletionCode::ElemListIsEmpty | CompletionCode::NoQuestion => Error::NoMoreQuestions,
+            code => Error::Completion(code),
+        }
+    }
+
+    /// like [`Self::handle_error_response`], for the requests made once the session is opened,
+    /// for which timeouts and rejected credentials mean the session has expired on the server
+    fn handle_session_error_response(completion: String) -> Error {
+        match Self::handle_error_response(completion) {
+            Error::TimeoutError
+            | Error::Completion(CompletionCode::Unauthorized | CompletionCode::MissingKey) => Error::SessionExpired,
+            err => err,
+        }
+    }
+
+    /// internal method used to send a request and return the response body,
+    /// transcoded from its detected charset
+    ///
+    /// rate limited requests are retried following the [`Self::retry_policy`], if any,
+    /// and challenged requests are retried once after solving the challenge with [`Self::with_challenge_solver`]
+    async fn send(&mut self, request: RequestBuilder) -> Result<String> {
+        let request_id = random_id();
+        self.last_request_id = Some(request_id.clone());
+        self.last_response_snippet = None;
+
+        let mut retries = 0;
+        let result = self.send_traced(request, &mut retries, &request_id).await;
+
+        self.record_sent(&result, retries);
+        self.last_response_snippet = result.as_deref().ok().map(snippet);
+        result
+    }
+
+    /// internal method recording a request sent with [`Self::send_shared`],
+    /// which was retried `retries` times
+    fn record_sent<T>(&mut self, result: &Result<T>, retries: usize) {
+        self.retries += retries;
+
+        if result.is_ok() {
+            self.last_active = self.now_timestamp().unwrap_or(self.last_active);
+        }
+    }
+
+    /// like [`Self::send`], but only borrowing the game so independent requests can be sent concurrently,
+    /// counting its retries in `retries` to be recorded with [`Self::record_sent`]
+    async fn send_shared(&self, request: RequestBuilder, retries: &mut usize) -> Result<String> {
+        self.send_traced(request, retries, &random_id()).await
+    }
+
+    /// internal method sending a request with [`Self::send_attempts`],
+    /// in a span carrying the game and request ids with the `tracing` feature enabled
+    async fn send_traced(&self, request: RequestBuilder, retries: &mut usize, request_id: &str) -> Result<String> {
+        let future = self.send_attempts(request, retries, request_id);
+
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(
+            future,
+            tracing::debug_span!("akinator_request", game_id = %self.game_id, request_id),
+        );
+
+        future.await
+    }
+
+    /// internal method sending a request, retrying it as described in [`Self::send`]
+    async fn send_attempts(&self, request: RequestBuilder, retries: &mut usize, request_id: &str) -> Result<String> {
+        let mut solved = false;
+
+        loop {
+            let Some(attempt) = request.try_clone() else {
+                break self.send_once(request, request_id).await;
+            };
+
+            match self.send_once(attempt, request_id).await {
+                Err(Error::RateLimited { retry_after })
+                    if self.retry_policy.is_some_and(|policy| *retries < policy.max_retries) =>
+                {
+                    let delay = self.retry_policy
+                        .unwrap_or_default()
+                        .delay(retry_after);
+
+                    self.cancellable(async {
+                        tokio::time::sleep(delay).await;
+                        Ok(())
+                    }).await?;
+
+                    *retries += 1;
+                },
+                Err(Error::BotChallenge(mut challenge)) if !solved && self.challenge_solver.is_some() => {
+                    if let Some(url) = request.try_clone().and_then(|request| request.build().ok()) {
+                        challenge.url = url.url().clone();
+                    }
+
+                    self.solve_challenge(*challenge).await?;
+                    solved = true;
+                },
+                result => break result,
+            }
+        }
+    }
+
+    /// internal method solving `challenge` with the [`Self::with_challenge_solver`],
+    /// adding the returned cookies to the game's cookie jar
+    async fn solve_challenge(&self, challenge: challenge::Challenge) -> Result<()> {
+        let (Some(solver), Some(cookie_jar)) = (&self.challenge_solver, &self.cookie_jar) else {
+            return Err(Error::BotChallenge(Box::new(challenge)));
+        };
+
+        let cookies = self.cancellable(async {
+            solver.solve(&challenge)
+                .await
+                .map_err(|_| Error::BotChallenge(Box::new(challenge.clone())))
+        }).await?;
+
+        for cookie in cookies {
+            cookie_jar.add_cookie_str(&cookie, &challenge.url);
+        }
+
+        Ok(())
+    }
+
+    /// internal method running `future`,
+    /// aborting with [`Error::Cancelled`] if the [`Self::cancellation_token`] is cancelled,
+    /// or with [`Error::DeadlineExceeded`] if the game's deadline runs out first
+    async fn cancellable<T>(&self, future: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+        let future = async {
+            match self.deadline {
+                Some(deadline) if deadline <= Instant::now() => Err(Error::DeadlineExceeded),
+                Some(deadline) => tokio::time::timeout_at(deadline.into(), future)
+                    .await
+                    .unwrap_or(Err(Error::DeadlineExceeded)),
+                None => future.await,
+            }
+        };
+
+        match &self.cancellation_token {
+            Some(token) => tokio::select! {
+                biased;
+                () = token.cancelled() => Err(Error::Cancelled),
+                result = future => result,
+            },
+            None => future.await,
+        }
+    }
+
+    /// internal method used to send a request once and return the response body
+    ///
+    /// fails with [`Error::HttpStatus`] on non-2xx responses, before the body is parsed
+    ///
+    /// aborts with [`Error::Cancelled`] if the [`Self::cancellation_token`] is cancelled
+    ///
+    /// the request and a summary of its response are passed to the [`Self::with_http_logger`], if any
+    async fn send_once(&self, request: RequestBuilder, request_id: &str) -> Result<String> {
+        let log = self.http_logger
+            .as_ref()
+            .and_then(|_| request.try_clone()?.build().ok())
+            .map(|built| transport::RequestLog::new(&built, &self.game_id, request_id));
+
+        let started = Instant::now();
+        let log_response = |status: Option<StatusCode>, body_len: usize, error: Option<String>| {
+            if let (Some(logger), Some(request)) = (&self.http_logger, &log) {
+                logger.0.log(request, &transport::ResponseLog {
+                    status,
+                    body_len,
+                    elapsed: started.elapsed(),
+                    error,
+                });
+            }
+        };
+
+        self.cancellable(async {
+            let response = self.transport.execute(request)
+                .await
+                .inspect_err(|err| log_response(None, 0, Some(err.to_string())))?;
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                log_response(Some(response.status()), 0, None);
+
+                return Err(Error::RateLimited {
+                    retry_after: retry::parse_retry_after(response.headers()),
+                });
+            }
+
+            let status = response.status();
+            let content_type = response.headers()
+                .get(CONTENT_TYPE)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+
+            let url = response.url().clone();
+            let headers = response.headers().clone();
+            let body = response.bytes()
+                .await
+                .inspect_err(|err| log_response(Some(status), 0, Some(err.to_string())))?;
+
+            log_response(Some(status), body.len(), None);
+
+            let text = String::from_utf8_lossy(&body);
+            if challenge::is_challenge(&headers, &text) {
+                return Err(Error::BotChallenge(Box::new(challenge::Challenge {
+                    url,
+                    status,
+                    body: text.into_owned(),
+                })));
+            }
+
+            if !status.is_success() {
+                return Err(Error::HttpStatus {
+                    status,
+                    body: snippet(&text),
+                });
+            }
+
+            text::decode_body(&body, content_type.as_deref())
+        }).await
+    }
+
+    /// internal method used to parse and find the [`Self.ws_url`] for this game
+    ///
+    /// returns the server cached in [`cache`] if it is younger than [`Self::with_server_cache_ttl`],
+    /// setting `raw` to the fetched homepage in [`Self.debug`] mode, to be recorded with [`Self::record_raw`]
+    async fn find_server(&self, retries: &mut usize, raw: &mut Option<String>) -> Result<String> {
+        let cached = self.base_url.is_none();
+
+        if let Some(url) = cache::get(self.language, self.theme, self.server_cache_ttl).filter(|_| cached) {
+            return Ok(url);
+        }
+
+        let html = self.send_shared(
+            self.http_client.get(&self.uri),
+            retries,
+        ).await
+            .map_err(|err| self.with_shared_context("homepage", err, None))?;
+        *raw = self.debug.then(|| html.clone());
+
+        let url = parse::server_url(&html, &(self.theme as usize).to_string())
+            .map_err(|err| self.with_shared_context("homepage", err, Some(&html)))?;
+
+        if cached {
+            cache::insert(self.language, self.theme, url.clone());
+        }
+
+        Ok(url)
+    }
+
+    /// internal method used to parse and find the session uid and frontaddr for the akinator session
+    ///
+    /// Done by parsing the javascript of the site, extracting variable values,
+    /// setting `raw` to the fetched page in [`Self.debug`] mode, to be recorded with [`Self::record_raw`]
+    async fn find_session_info(&self, retries: &mut usize, raw: &mut Option<String>) -> Result<parse::ExtractedSessionInfo> {
+        let url = if self.base_url.is_some() {
+            format!("{}/game", self.base_uri())
+        } else {
+            "https://en.akinator.com/game".to_string()
+        };
+
+        let html = self.send_shared(
+            self.http_client.get(url),
+            retries,
+        ).await
+            .map_err(|err| self.with_shared_context("game", err, None))?;
+        *raw = self.debug.then(|| html.clone());
+
+        parse::extract_session_info(&html)
+            .map_err(|err| self.with_shared_context("game", err, Some(&html)))
+    }
+
+    /// internal method used to parse the response returned from the API into a json struct
+    ///
+    /// records the raw and parsed response when in [`Self.debug`] mode
+    fn parse_json<T: DeserializeOwned>(&mut self, html: String) -> Result<T> {
+        let json_string = parse::strip_jsonp(&html);
+
+        if !self.debug {
+            return Ok(serde_json::from_str(json_string)?);
+        }
+
+        let value = serde_json::from_str::<serde_json::Value>(json_string)
+            .and_then(|value| {
+                let json = T::deserialize(&value)?;
+                Ok((value, json))
+            });
+
+        let snippet = snippet(&html);
+        self.record_raw(Some(html));
+
+        match value {
+            Ok((value, json)) => {
+                self.last_json_response = Some(value);
+                Ok(json)
+            },
+            Err(source) => Err(Error::DebugJsonParseError { source, snippet }),
+        }
+    }
+
+    /// internal method recording `raw`, the untouched body of a response, as the last raw response
+    /// in [`Self.debug`] mode, clearing the last JSON response
+    fn record_raw(&mut self, raw: Option<String>) {
+        if self.debug && raw.is_some() {
+            self.last_raw_response = raw;
+            self.last_json_response = None;
+        }
+    }
+
+    /// internal method attaching the [`error::RequestContext`] of the last request sent with [`Self::send`],
+    /// to `endpoint`, to protocol errors
+    fn with_context(&self, endpoint: &'static str, err: Error) -> Error {
+        self.attach_context(endpoint, err, self.last_request_id.clone(), self.last_response_snippet.as_deref())
+    }
+
+    /// internal method attaching the [`error::RequestContext`] of a request sent with [`Self::send_shared`]
+    /// to `endpoint`, which responded with `body` if it succeeded, to protocol errors
+    fn with_shared_context(&self, endpoint: &'static str, err: Error, body: Option<&str>) -> Error {
+        self.attach_context(endpoint, err, None, body)
+    }
+
+    /// internal method attaching the [`error::RequestContext`] of a request to protocol errors
+    fn attach_context(&self, endpoint: &'static str, err: Error, request_id: Option<String>, body: Option<&str>) -> Error {
+        if !err.is_protocol_error() {
+            return err;
+        }
+
+        Error::WithContext {
+            context: error::RequestContext {
+                endpoint,
+                step: self.step,
+                game_id: Some(self.game_id.clone()),
+                request_id,
+                body: body.map(snippet),
+            },
+            source: Box::new(err),
+        }
+    }
+
+    /// internal method emitting the events of the current question being asked,
+    /// `previous` being the progression before it
+    fn emit_question(&self, previous: models::Progression) {
+        self.events.emit(|| events::GameEvent::QuestionAsked {
+            question: models::Question {
+                text: self.current_question.clone().unwrap_or_default(),
+                step: self.step,
+                progression: self.progression,
+            },
+        });
+
+        if self.progression != previous {
+            self.events.emit(|| events::GameEvent::ProgressChanged { from: previous, to: self.progression });
+        }
+    }
+
+    /// internal method calling the hook set with [`Self::on_state_change`] with the serialized state of the game
+    ///
+    /// the hook is skipped if the state fails to serialize, which only happens if the clock is broken
+    fn autosave(&self) {
+        if let Some(hook) = &self.state_change_hook {
+            if let Ok(json) = self.save_state().and_then(|state| state.to_json()) {
+                (hook.0)(json.as_bytes());
+            }
+        }
+    }
+
+    /// internal method emitting an [`events::GameEvent::Errored`] event if `result` failed
+    fn report<T>(&self, result: Result<T>) -> Result<T> {
+        if let Err(err) = &result {
+            self.events.emit(|| events::GameEvent::Errored { message: err.to_string() });
+        }
+
+        result
+    }
+
+    /// updates the [`Akinator`] fields after each response
+    fn update_move_info(&mut self, json: models::raw::MoveJson) -> Result<(), UpdateInfoError> {
+        let params = json.parameters
+            .ok_or(UpdateInfoError::MissingData)?;
+
+        let question = parse::step(&params)?;
+
+        self.current_question = Some(question.text);
+        self.progression = question.progression;
+        self.step = question.step;
+
+        self.progression_history.truncate(self.step);
+        self.progression_history.push(self.progression.value());
+        self.state = GameState::InProgress;
+
+        Ok(())
+    }
+
+    /// similar to [`Self::update_move_info`], but only called once when [`Self::start`] is called
+    fn update_start_info(&mut self, json: &models::raw::StartJson) -> Result<(), UpdateInfoError> {
+        let params = json.parameters
+            .as_ref()
+            .ok_or(UpdateInfoError::MissingData)?;
+
+        let (session, signature) = parse::identification(&params.identification)?;
+        let question = parse::step(&params.step_information)?;
+
+        self.session = Some(session);
+        self.signature = Some(signature);
+
+        self.current_question = Some(question.text);
+        self.progression = question.progression;
+        self.step = question.step;
+
+        self.progression_history = vec![self.progression.value()];
+        self.state = GameState::InProgress;
+
+        Ok(())
+    }
+
+    /// internal method used to record a step taken in the game for [`Self::transcript`]
+    fn record_step(
+        &mut self,
+        step: usize,
+        question: Option<String>,
+        action: transcript::Action,
+        latency: Duration,
+    ) {
+        let elapsed = self.started_at
+            .map_or(Duration::ZERO, |started_at| started_at.elapsed());
+
+        self.history.push(transcript::TranscriptStep {
+            step,
+            question,
+            action,
+            progression: self.progression.value(),
+            elapsed_ms: u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX),
+            latency_ms: u64::try_from(latency.as_millis()).unwrap_or(u64::MAX),
+        });
+    }
+
+    /// returns how long ago the game's session was started according to [`Self::with_clock`],
+    /// or [`None`] if the game has not been started yet
+    #[must_use]
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.session?;
+
+        let now = self.now_timestamp().ok()?;
+        Some(Duration::from_secs(now.saturating_sub(self.timestamp)))
+    }
+
+    /// returns the estimated time left before the game's session expires on the server,
+    /// [`save::SESSION_LIFETIME`] after the last request made in it,
+    /// or [`None`] if the game has not been started yet
+    ///
+    /// useful for warning users before their game times out, [`Duration::ZERO`] means it has likely expired
+    #[must_use]
+    pub fn expires_in(&self) -> Option<Duration> {
+        self.session?;
+
+        let now = self.now_timestamp().ok()?;
+        let idle = Duration::from_secs(now.saturating_sub(self.last_active));
+
+        Some(save::SESSION_LIFETIME.saturating_sub(idle))
+    }
+
+    /// returns a [`transcript::Transcript`] of the game so far:
+    /// its configuration, every question and answer, timings and the guesses if [`Self::win`] has been called
+    #[must_use]
+    pub fn transcript(&self) -> transcript::Transcript {
+        transcript::Transcript {
+            language: self.language,
+            theme: self.theme,
+            child_mode: self.child_mode,
+            started_at: self.timestamp,
+            first_question: self.first_question.clone(),
+            steps: self.history.clone(),
+            guesses: self.guesses.clone(),
+        }
+    }
+
+    /// attempts to parse an answer in the game's [`Self.language`] (ex: "oui" for French games),
+    /// see [`Answer::parse_localized`]
+    ///
+    /// # Errors
+    /// [`Error::InvalidAnswer`] if the answer is not recognized
+    pub fn parse_answer(&self, answer: &str) -> Result<Answer> {
+        Answer::parse_localized(answer, self.language)
+    }
+
+    /// returns the progression of the akinator at each step of the game, indexed by step,
+    /// for plotting its confidence curve
+    ///
+    /// going [`Self::back`] drops the progression of the undone steps
+    #[must_use]
+    pub fn progression_history(&self) -> &[f32] {
+        &self.progression_history
+    }
+
+    /// returns statistics about the game so far, for analytics
+    #[must_use]
+    pub fn statistics(&self) -> statistics::GameStatistics {
+        let mut stats = statistics::GameStatistics {
+            duration: self.started_at
+                .map_or(Duration::ZERO, |started_at| started_at.elapsed()),
+            retries: self.retries,
+            ..statistics::GameStatistics::default()
+        };
+
+        for step in &self.history {
+            match step.action {
+                transcript::Action::Answer(answer) => stats.answers.add(answer),
+                transcript::Action::Back => stats.backs += 1,
+            }
+        }
+
+        stats
+    }
+
+    /// returns the [`akitude::Akitude`] (mascot pose) the website would currently show,
+    /// based on [`Self.progression`] and [`Self.step`]
+    #[must_use]
+    pub fn akitude(&self) -> akitude::Akitude {
+        akitude::Akitude::from_progress(self.progression.value(), self.step)
+    }
+
+    /// returns a receiver of the events emitted as the game is played, see [`events::GameEvent`]
+    ///
+    /// each receiver gets every event emitted after it subscribed,
+    /// receivers lagging more than [`events::EVENT_CAPACITY`] events behind miss the oldest ones
+    #[cfg(feature = "events")]
+    pub fn subscribe(&mut self) -> tokio::sync::broadcast::Receiver<events::GameEvent> {
+        self.events.subscribe()
+    }
+
+    /// returns an [indicatif](https://docs.rs/indicatif) progress bar following the game's step, progression and question,
+    /// updated by a task subscribed to its events, see [`progress::track`]
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime
+    #[cfg(feature = "indicatif")]
+    pub fn progress_bar(&mut self) -> indicatif::ProgressBar {
+        let bar = progress::progress_bar();
+        progress::track(bar.clone(), self.subscribe());
+
+        bar
+    }
+
+    /// moves the game into its own task, returning a [`futures_sink::Sink`] of answers
+    /// and a [`tokio_stream::Stream`] of the game's events, see [`duplex`]
+    ///
+    /// the game is started if it has not been started yet, and makes its guesses once
+    /// [`Self.progression`] reaches [`DEFAULT_WIN_THRESHOLD`] or the akinator runs out of questions
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime
+    #[cfg(feature = "duplex")]
+    #[must_use]
+    pub fn into_duplex(self) -> (duplex::AnswerSink, duplex::EventStream) {
+        duplex::spawn(self)
+    }
+
+    /// moves the game into its own task driven by messages, returning a cheap, cloneable handle to it,
+    /// see [`actor::AkinatorHandle`]
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime
+    #[cfg(feature = "actor")]
+    #[must_use]
+    pub fn spawn(self) -> actor::AkinatorHandle {
+        actor::AkinatorHandle::spawn(self)
+    }
+
+    /// runs a self-check of every stage of starting a game: server discovery, session info extraction
+    /// and a dry-run new session, returning a report of which stage failed and why
+    ///
+    /// the check runs on a copy of the game, bypassing the server cache and without calling its state change hook
+    /// or HTTP logger, so the game and its saves are left untouched.
+    /// the session created by the last stage is left unused
+    pub async fn diagnose(&self) -> diagnose::DiagnosticReport {
+        use diagnose::DiagnosticStage;
+
+        let mut probe = self.clone();
+        probe.state_change_hook = None;
+        probe.http_logger = None;
+        probe.reset();
+        probe.uri = probe.base_uri();
+        probe.server_cache_ttl = Duration::ZERO;
+
+        let mut report = diagnose::DiagnosticReport::new(probe.language, probe.theme, probe.uri.clone());
+        let mut retries = 0;
+
+        let ws_url = report.run(
+            DiagnosticStage::ServerDiscovery,
+            async {
+                match probe.custom_ws_url.clone() {
+                    Some(url) => Ok(url),
+                    None => probe.find_server(&mut retries, &mut None).await,
+                }
+            },
+            |url| format!("using {url}"),
+        ).await;
+        let Some(ws_url) = ws_url else {
+            return report;
+        };
+
+        let info = report.run(
+            DiagnosticStage::SessionInfo,
+            probe.find_session_info(&mut retries, &mut None),
+            |info| format!("found with the {:?} strategy", info.strategy),
+        ).await;
+        let Some(info) = info else {
+            return report;
+        };
+
+        probe.ws_url = Some(ws_url);
+        probe.set_session_info(info);
+
+        report.run(
+            DiagnosticStage::NewSession,
+            probe.new_session(),
+            |question| format!("first question: {}", question.as_deref().unwrap_or_default()),
+        ).await;
+
+        report
+    }
+
+    /// returns the state of the game: not started, in progress, awaiting the confirmation of a guess or finished
+    ///
+    /// methods invalid in the current state return an [`Error::WrongState`]
+    #[must_use]
+    pub const fn state(&self) -> GameState {
+        self.state
+    }
+
+    /// returns a copy of the current question, step, progression, guesses and state of the game,
+    /// to render it without borrowing the game
+    #[must_use]
+    pub fn snapshot(&self) -> models::GameSnapshot {
+        models::GameSnapshot {
+            question: self.current_question.clone(),
+            step: self.step,
+            progression: self.progression,
+            guesses: self.guesses.clone(),
+            state: self.state,
+        }
+    }
+
+    /// internal method returning an [`Error::WrongState`] unless the game is in progress or awaiting a confirmation
+    fn ensure_playing(&self, action: &'static str) -> Result<()> {
+        match self.state {
+            GameState::InProgress | GameState::AwaitingConfirmation => Ok(()),
+            state => Err(Error::WrongState { action, state }),
+        }
+    }
+
+    /// Starts the akinator game and returns the first question
+    ///
+    /// the previous game, if any, is only replaced once the new one has started,
+    /// so it is left as it was if starting fails or is aborted part-way
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn start(&mut self) -> Result<Option<String>> {
+        let mut game = Rollback::new(self);
+        game.begin_game();
+
+        let result = game.start_game().await;
+        game.finish(result)
+    }
+
+    /// internal method generating the id of a new game and starting its time budget, see [`Self::with_game_deadline`]
+    fn begin_game(&mut self) {
+        self.game_id = random_id();
+        self.last_request_id = None;
+        self.deadline = self.game_deadline
+            .and_then(|budget| Instant::now().checked_add(budget));
+    }
+
+    /// internal method making the requests for [`Self::start`]
+    async fn start_game(&mut self) -> Result<Option<String>> {
+        self.uri = self.base_uri();
+        let reused = self.uid.is_some() && self.frontaddr.is_some();
+
+        // the server and the session info are independent, so they are discovered concurrently
+        let (mut server_retries, mut session_retries) = (0, 0);
+        let (mut homepage, mut game_page) = (None, None);
+        let (ws_url, session_info) = tokio::join!(
+            async {
+                match self.custom_ws_url.clone() {
+                    Some(url) => Ok(url),
+                    None => self.find_server(&mut server_retries, &mut homepage).await,
+                }
+            },
+            async {
+                if reused {
+                    Ok(None)
+                } else {
+                    self.find_session_info(&mut session_retries, &mut game_page).await.map(Some)
+                }
+            },
+        );
+
+        self.record_sent(&ws_url, server_retries);
+        self.record_sent(&session_info, session_retries);
+
+        self.record_raw(homepage);
+        self.ws_url = Some(ws_url?);
+        self.server_for = Some((self.language, self.theme));
+
+        self.record_raw(game_page);
+        if let Some(info) = session_info? {
+            self.set_session_info(info);
+        }
+
+        self.reset();
+        self.open_session(reused).await
+    }
+
+    /// clears the game's state in place, returning it to its pre-start state
+    ///
+    /// the configuration, HTTP client and cookies are kept,
+    /// as well as the discovered server and session info used by [`Self::restart`]
+    ///
+    /// the session is only forgotten locally: the API has no endpoint to terminate it,
+    /// so it expires on the server after [`save::SESSION_LIFETIME`], as do the sessions of dropped games
+    pub fn reset(&mut self) {
+        self.started_at = None;
+        self.first_question = None;
+        self.history.clear();
+        self.last_guess_step = 0;
+        self.retries = 0;
+        self.progression_history.clear();
+        self.rejected_guesses.clear();
+        self.answers.clear();
+        self.redo_answers.clear();
+
+        self.timestamp = 0;
+        self.callback.clear();
+        self.last_active = 0;
+        self.session = None;
+        self.signature = None;
+        self.question_filter = None;
+        self.last_raw_response = None;
+        self.last_json_response = None;
+        self.last_response_snippet = None;
+
+        self.current_question = None;
+        self.progression = models::Progression::default();
+        self.step = 0;
+
+        self.first_guess = None;
+        self.guesses = models::GuessList::default();
+        self.state = GameState::NotStarted;
+    }
+
+    /// Starts a brand-new game and returns its first question,
+    /// reusing the server and session info of the previous game to start instantly
+    ///
+    /// falls back to [`Self::start`] if no game was started before,
+    /// or if [`Self.language`] or [`Self.theme`] changed since
+    ///
+    /// as with [`Self::start`], the previous game is left as it was if starting fails or is aborted part-way
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn restart(&mut self) -> Result<Option<String>> {
+        let mut game = Rollback::new(self);
+        game.begin_game();
+
+        let result = game.restart_game().await;
+        game.finish(result)
+    }
+
+    /// internal method making the requests for [`Self::restart`]
+    async fn restart_game(&mut self) -> Result<Option<String>> {
+        if self.ws_url.is_none() || self.server_for != Some((self.language, self.theme)) {
+            return self.start_game().await;
+        }
+
+        self.reset();
+        self.start_session().await
+    }
+
+    /// internal method starting a new session on [`Self.ws_url`]
+    ///
+    /// reuses the [`Self.uid`] and [`Self.frontaddr`] of a previous game if any,
+    /// only scraping new ones if the server rejects them
+    async fn start_session(&mut self) -> Result<Option<String>> {
+        let reused = self.uid.is_some() && self.frontaddr.is_some();
+
+        if !reused {
+            self.refresh_session_info().await?;
+        }
+
+        self.open_session(reused).await
+    }
+
+    /// internal method requesting a new session, scraping a new [`Self.uid`] and [`Self.frontaddr`]
+    /// and trying again if `reused` ones from a previous game are rejected
+    async fn open_session(&mut self, reused: bool) -> Result<Option<String>> {
+        match self.new_session().await {
+            Err(Error::Completion(CompletionCode::Unauthorized | CompletionCode::MissingKey)) if reused => {
+                self.refresh_session_info().await?;
+                self.new_session().await
+            },
+            result => result,
+        }
+    }
+
+    /// internal method scraping and setting a new [`Self.uid`] and [`Self.frontaddr`]
+    async fn refresh_session_info(&mut self) -> Result<()> {
+        let mut retries = 0;
+        let mut raw = None;
+        let result = self.find_session_info(&mut retries, &mut raw).await;
+        self.record_sent(&result, retries);
+        self.record_raw(raw);
+
+        self.set_session_info(result?);
+
+        Ok(())
+    }
+
+    /// internal method setting the [`Self.uid`] and [`Self.frontaddr`] extracted from the game page
+    fn set_session_info(&mut self, info: parse::ExtractedSessionInfo) {
+        self.uid = Some(info.uid);
+        self.frontaddr = Some(info.frontaddr);
+        self.session_info_strategy = Some(info.strategy);
+    }
+
+    /// internal method requesting a new session from the API and returning the first question
+    async fn new_session(&mut self) -> Result<Option<String>> {
+        let result = self.request_session().await;
+        result.map_err(|err| self.with_context("new_session", err))
+    }
+
+    /// internal method making the request for [`Self::new_session`]
+    async fn request_session(&mut self) -> Result<Option<String>> {
+        self.timestamp = self.now_timestamp()?;
+        self.callback = jquery_callback(self.timestamp);
+
+        let soft_constraint =
+            if self.child_mode {
+                "ETAT='EN'"
+            } else {
+                ""
+            }
+            .to_string();
+
+        self.question_filter = Some(
+            self.custom_question_filter.clone().unwrap_or_else(|| {
+                if self.child_mode || self.nsfw_filter {
+                    "cat=1"
+                } else {
+                    ""
+                }
+                .to_string()
+            })
+        );
+
+        let params = [
+            ("callback", self.callback.clone()),
+            ("urlApiWs", get_field!(self.ws_url)),
+            ("partner", self.partner.to_string()),
+            ("childMod", self.child_mode.to_string()),
+            ("player", "website-desktop".to_string()),
+            ("uid_ext_session", get_field!(self.uid)),
+            ("frontaddr", get_field!(self.frontaddr)),
+            ("constraint", "ETAT<>'AV'".to_string()),
+            ("soft_constraint", soft_constraint),
+            (
+                "question_filter",
+                get_field!(self.question_filter),
+            ),
+        ];
+
+        let response = self.send(
+            self.http_client
+                .get(format!("{}/new_session", &self.uri))
+                .headers(HEADERS.clone())
+                .query(&params)
+        ).await?;
+
+        let json: models::raw::StartJson =
+            self.parse_json(response)?;
+
+        if json.completion.as_str() == "OK" {
+            let previous = self.progression;
+            self.update_start_info(&json)?;
+            self.emit_question(previous);
+
+            self.started_at = Some(Instant::now());
+            self.first_question = self.current_question.clone();
+            self.history.clear();
+            self.autosave();
+
+            Ok(self.current_question.clone())
+        } else {
+            let error = Self::handle_error_response(json.completion);
+
+            // the cached server may be the one failing, look it up again next time
+            if matches!(error, Error::ServersDown | Error::TechnicalError) {
+                cache::invalidate(self.language, self.theme);
+            }
+
+            Err(error)
+        }
+    }
+
+    /// returns whether or not the akinator is ready to propose a guess, like the website does:
+    /// once it is confident enough, every [`GUESS_READY_INTERVAL`] questions since the last guess, and at the last step
+    #[must_use]
+    pub fn is_guess_ready(&self) -> bool {
+        self.progression >= GUESS_READY_PROGRESSION
+            || self.step >= LAST_STEP
+            || self.step >= self.last_guess_step.saturating_add(GUESS_READY_INTERVAL)
+    }
+
+    /// returns whether or not the game is stalled: the akinator's progression has not improved
+    /// over the last [`Self::with_stall_window`] questions since its last guess, see [`Self::progression_history`]
+    #[must_use]
+    pub fn is_stalled(&self) -> bool {
+        let history = self.progression_history
+            .get(self.last_guess_step..)
+            .unwrap_or_default();
+
+        if self.stall_window == 0 || history.len() <= self.stall_window {
+            return false;
+        }
+
+        let (earlier, recent) = history.split_at(history.len() - self.stall_window);
+        let best = |progressions: &[f32]| progressions
+            .iter()
+            .copied()
+            .fold(f32::MIN, f32::max);
+
+        best(recent) <= best(earlier)
+    }
+
+    /// returns an estimate of the amount of questions left before the akinator is ready to propose a guess,
+    /// ex: to display "about 5 more questions", [`None`] if the game has not been started
+    ///
+    /// extrapolates the average progression gained over the last few steps up to [`GUESS_READY_PROGRESSION`],
+    /// never exceeding the questions left before the akinator guesses anyway, see [`Self::is_guess_ready`]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+    pub fn estimated_steps_remaining(&self) -> Option<usize> {
+        let history = self.progression_history
+            .get(self.last_guess_step..)
+            .filter(|history| !history.is_empty())?;
+
+        if self.is_guess_ready() {
+            return Some(0);
+        }
+
+        let scheduled = self.last_guess_step
+            .saturating_add(GUESS_READY_INTERVAL)
+            .min(LAST_STEP)
+            .saturating_sub(self.step);
+
+        let window = &history[history.len().saturating_sub(ESTIMATE_WINDOW + 1)..];
+        let (Some(first), Some(last)) = (window.first(), window.last()) else {
+            return Some(scheduled);
+        };
+
+        let rate = (last - first) / (window.len() - 1).max(1) as f32;
+        if rate <= 0.0 {
+            return Some(scheduled);
+        }
+
+        let needed = ((GUESS_READY_PROGRESSION - self.progression.value()) / rate).ceil() as usize;
+        Some(needed.clamp(1, scheduled.max(1)))
+    }
+
+    /// returns whether or not bots should stop asking questions and call [`Self::win`]:
+    /// once the akinator is ready to propose a guess (see [`Self::is_guess_ready`]),
+    /// or once the game is stalled (see [`Self::is_stalled`]) past [`STALL_WIN_PROGRESSION`],
+    /// as further answers are unlikely to make it more confident
+    #[must_use]
+    pub fn recommend_win(&self) -> bool {
+        self.is_guess_ready()
+            || (self.is_stalled() && self.progression >= STALL_WIN_PROGRESSION)
+    }
+
+    /// Answers the akinator's current question like [`Self::answer`],
+    /// but returns a [`models::StepOutcome`] signalling when the akinator is ready to propose a guess
+    ///
+    /// instead of failing with [`Error::NoMoreQuestions`] when the akinator runs out of questions,
+    /// its guesses are fetched with [`Self::win`] and returned as [`models::StepOutcome::Finished`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn answer_step(&mut self, answer: Answer) -> Result<models::StepOutcome> {
+        let question = match self.answer(answer).await {
+            Ok(question) => models::Question {
+                text: question.unwrap_or_default(),
+                step: self.step,
+                progression: self.progression,
+            },
+            Err(Error::NoMoreQuestions) => {
+                self.win().await?;
+
+                return Ok(models::StepOutcome::Finished {
+                    guesses: self.guesses.clone(),
+                });
+            },
+            Err(err) => return Err(err),
+        };
+
+        Ok(if self.is_guess_ready() {
+            models::StepOutcome::ReadyToGuess(question)
+        } else {
+            models::StepOutcome::NextQuestion(question)
+        })
+    }
+
+    /// answers the akinator's current question which can be retrieved with [`Self.current_question`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn answer(&mut self, answer: Answer) -> Result<Option<String>> {
+        self.ensure_playing("answer a question")?;
+
+        let result = self.send_answer(answer).await
+            .map_err(|err| self.with_context("answer_api", err));
+        self.report(result)
+    }
+
+    /// internal method making the request for [`Self::answer`]
+    async fn send_answer(&mut self, answer: Answer) -> Result<Option<String>> {
+        let params = [
+            ("callback", self.callback.clone()),
+            ("urlApiWs", get_field!(self.ws_url)),
+            ("childMod", self.child_mode.to_string()),
+            ("session", get_field!(self.session)),
+            ("signature", get_field!(self.signature)),
+            ("frontaddr", get_field!(self.frontaddr)),
+            ("step", self.step.to_string()),
+            ("answer", (answer as u8).to_string()),
+            (
+                "question_filter",
+                get_field!(self.question_filter),
+            ),
+        ];
+
+        let (step, question) = (self.step, self.current_question.clone());
+        let sent_at = Instant::now();
+
+        let response = self.send(
+            self.http_client
+                .get(format!("{}/answer_api", &self.uri))
+                .headers(HEADERS.clone())
+                .query(&params)
+        ).await?;
+
+        let json: models::raw::MoveJson =
+            self.parse_json(response)?;
+
+        if json.completion.as_str() == "OK" {
+            let previous = self.progression;
+            self.update_move_info(json)?;
+            self.record_step(step, question, transcript::Action::Answer(answer), sent_at.elapsed());
+
+            self.answers.push(answer);
+            self.redo_answers.clear();
+
+            self.events.emit(|| events::GameEvent::Answered { step, answer });
+            self.emit_question(previous);
+            self.autosave();
+
+            Ok(self.current_question.clone())
+        } else {
+            Err(Self::handle_session_error_response(json.completion))
+        }
+    }
+
+    /// internal method used to fetch a list of `size` guesses from the akinator
+    ///
+    /// if `size` is [`None`], the API's default list size is used,
+    /// guesses under [`Self.min_confidence`] and guesses rejected with [`Self::reject_guess`] are dropped
+    async fn fetch_guesses(&mut self, size: Option<usize>) -> Result<models::GuessList> {
+        let result = self.request_guesses(size).await
+            .map_err(|err| self.with_context("list", err));
+        let mut guesses = self.report(result)?;
+
+        if let Some(min_confidence) = self.min_confidence {
+            guesses.retain(|guess| guess.probability() >= min_confidence);
+        }
+        guesses.retain(|guess| !self.rejected_guesses.contains(&guess.id));
+
+        Ok(guesses)
+    }
+
+
+    /// internal method making the request for [`Self::fetch_guesses`]
+    async fn request_guesses(&mut self, size: Option<usize>) -> Result<models::GuessList> {
+        let mut params = vec![
+            ("callback", self.callback.clone()),
+            ("childMod", self.child_mode.to_string()),
+            ("session", get_field!(self.session)),
+            ("signature", get_field!(self.signature)),
+            ("step", self.step.to_string()),
+        ];
+
+        if let Some(size) = size {
+            params.push(("size", size.to_string()));
+        }
+
+        if let Some((width, height)) = self.max_pic_size {
+            params.push(("max_pic_width", width.to_string()));
+            params.push(("max_pic_height", height.to_string()));
+        }
+
+        let response = self.send(
+            self.http_client
+                .get(format!("{}/list", get_field!(self.ws_url)))
+                .headers(HEADERS.clone())
+                .query(&params)
+        ).await?;
+
+        let json: models::raw::WinJson =
+            self.parse_json(response)?;
+
+        if json.completion.as_str() == "OK" {
+            let params = json.parameters
+                .ok_or(UpdateInfoError::MissingData)?;
+
+            Ok(parse::guesses(params).into())
+        } else {
+            Err(Self::handle_session_error_response(json.completion))
+        }
+    }
+
+    /// tells the akinator to end the game and make it's guess
+    /// and returns its best guess, which also can be retrieved with [`Self.first_guess`]
+    ///
+    /// the amount of guesses fetched can be configured with [`Self::with_guess_list_size`],
+    /// and guesses under a confidence threshold can be dropped with [`Self::with_min_confidence`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn win(&mut self) -> Result<models::WinResult> {
+        self.ensure_playing("guess")?;
+
+        self.guesses = self.fetch_guesses(self.guess_list_size).await?;
+        self.last_guess_step = self.step;
+
+        self.first_guess = self.guesses
+            .first()
+            .cloned();
+        if self.first_guess.is_some() {
+            self.state = GameState::AwaitingConfirmation;
+        }
+        self.events.emit(|| events::GameEvent::GuessesReady { guesses: self.guesses.clone() });
+        self.autosave();
+
+        Ok(models::WinResult {
+            first: self.first_guess.clone(),
+            all: self.guesses.clone(),
+            step: self.step,
+            progression: self.progression,
+        })
+    }
+
+    /// returns the guesses made by [`Self::win`] with a [`models::Guess::probability`] of at least `confidence`
+    #[must_use]
+    pub fn guesses_above(&self, confidence: f64) -> Vec<&models::Guess> {
+        self.guesses
+            .iter()
+            .filter(|guess| guess.probability() >= confidence)
+            .collect()
+    }
+
+    /// marks the guess with the given `id` as wrong, removing it from [`Self.guesses`]
+    /// so it is not presented again by later calls to [`Self::win`] after continuing the game
+    pub fn reject_guess(&mut self, id: impl Into<String>) {
+        let id = id.into();
+
+        self.guesses.retain(|guess| guess.id != id);
+        if self.first_guess.as_ref().is_some_and(|guess| guess.id == id) {
+            self.first_guess = self.guesses
+                .first()
+                .cloned();
+        }
+        if self.first_guess.is_none() && self.state == GameState::AwaitingConfirmation {
+            self.state = GameState::InProgress;
+        }
+
+        self.rejected_guesses.insert(id);
+    }
+
+    /// confirms that the akinator's best guess, [`Self.first_guess`], is correct, finishing the game
+    ///
+    /// # Errors
+    /// [`Error::WrongState`] if the akinator is not awaiting a confirmation, see [`Self::state`]
+    pub fn confirm_guess(&mut self) -> Result<&models::Guess> {
+        if self.state != GameState::AwaitingConfirmation {
+            return Err(Error::WrongState { action: "confirm a guess", state: self.state });
+        }
+
+        self.state = GameState::Finished;
+        self.first_guess
+            .as_ref()
+            .ok_or(Error::NoDataFound)
+    }
+
+    /// returns the ids of the guesses rejected with [`Self::reject_guess`]
+    #[must_use]
+    pub const fn rejected_guesses(&self) -> &HashSet<String> {
+        &self.rejected_guesses
+    }
+
+    /// calls [`Self::win`] and returns its result only if [`Self.progression`] has reached `threshold`,
+    /// otherwise returns [`None`] to indicate that the game should keep going
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn win_if_confident(&mut self, threshold: f32) -> Result<Option<models::WinResult>> {
+        if self.progression.is_confident(threshold) {
+            self.win().await.map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// plays a full game unattended, answering every question with `strategy`
+    /// until [`Self.progression`] reaches [`DEFAULT_WIN_THRESHOLD`], the akinator runs out of questions,
+    /// or the strategy stops answering, then calls [`Self::win`] and returns its best guess
+    ///
+    /// starts the game with [`Self::start`] if it has not been started yet
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn auto_play(
+        &mut self,
+        strategy: impl strategy::AnswerStrategy,
+    ) -> Result<Option<models::Guess>> {
+        self.auto_play_until(strategy, DEFAULT_WIN_THRESHOLD).await
+    }
+
+    /// like [`Self::auto_play`], but answering until [`Self.progression`] reaches `threshold`
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn auto_play_until(
+        &mut self,
+        mut strategy: impl strategy::AnswerStrategy,
+        threshold: f32,
+    ) -> Result<Option<models::Guess>> {
+        if self.session.is_none() {
+            self.start().await?;
+        }
+
+        while self.progression < threshold {
+            let question = self.current_question
+                .clone()
+                .unwrap_or_default();
+
+            let Some(answer) = strategy.answer(&question, self) else {
+                break;
+            };
+
+            match self.answer(answer).await {
+                Ok(_) => {},
+                Err(Error::NoMoreQuestions) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(self.win().await?.first)
+    }
+
+    /// plays a full interactive game over stdin and stdout, for quick prototyping, see [`Self::run_interactive`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn run_stdin(&mut self) -> Result<Option<models::Guess>> {
+        self.run_interactive(std::io::stdin().lock(), std::io::stdout()).await
+    }
+
+    /// plays a full interactive game, printing each question to `output` and reading the answers from `input`,
+    /// one per line, until [`Self.progression`] reaches [`DEFAULT_WIN_THRESHOLD`] or the akinator runs out of questions,
+    /// then calls [`Self::win`], prints and returns its best guess
+    ///
+    /// answers are parsed with [`Answer::parse_localized`] in the game's language,
+    /// `back` goes back a question, and invalid answers are asked again.
+    /// Starts the game with [`Self::start`] if it has not been started yet,
+    /// returns [`None`] without guessing if `input` ends first
+    ///
+    /// # Errors
+    /// [`Error::IoError`] if reading or writing fails, otherwise
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn run_interactive(
+        &mut self,
+        mut input: impl std::io::BufRead,
+        mut output: impl std::io::Write,
+    ) -> Result<Option<models::Guess>> {
+        if self.session.is_none() {
+            self.start().await?;
+        }
+
+        while self.progression < DEFAULT_WIN_THRESHOLD {
+            write!(output, "{}. {}\n> ", self.step + 1, self.current_question.as_deref().unwrap_or_default())?;
+            output.flush()?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+
+            let result = if line.trim().eq_ignore_ascii_case("back") {
+                self.back().await
+            } else if let Ok(answer) = Answer::parse_localized(&line, self.language) {
+                self.answer(answer).await
+            } else {
+                writeln!(output, "invalid answer, expected yes, no, idk, probably, probably not or back")?;
+                continue;
+            };
+
+            match result {
+                Ok(_) => {},
+                Err(Error::CantGoBackAnyFurther) => writeln!(output, "cannot go back any further")?,
+                Err(Error::NoMoreQuestions) => break,
+                Err(err) => return Err(err),
+            }
+        }
+
+        let guess = self.win().await?.first;
+
+        match &guess {
+            Some(guess) => writeln!(output, "I think of {}: {}", guess.name, guess.description)?,
+            None => writeln!(output, "I have no idea")?,
+        }
+
+        Ok(guess)
+    }
+
+    /// replays a [`transcript::Transcript`], re-submitting the same actions in a fresh game
+    /// and reporting where the question path diverges from the transcript
+    ///
+    /// the game's language, theme and child mode are overwritten with the transcript's, and kept afterwards.
+    /// Replaying stops at the first diverging question, otherwise if the transcript has guesses,
+    /// [`Self::win`] is called to compare the best guesses.
+    /// A step failing, including with [`Error::NoMoreQuestions`], also stops the replay
+    /// and is reported in [`transcript::ReplayReport::failure`] along with the steps replayed so far
+    ///
+    /// # Errors
+    /// If the game fails to start, otherwise
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn replay(&mut self, transcript: &transcript::Transcript) -> Result<transcript::ReplayReport> {
+        self.language = transcript.language;
+        self.theme = transcript.theme;
+        self.child_mode = transcript.child_mode;
+
+        self.start().await?;
+
+        let mut divergence = (self.current_question != transcript.first_question)
+            .then(|| transcript::Divergence {
+                index: None,
+                expected: transcript.first_question.clone(),
+                actual: self.current_question.clone(),
+            });
+        let mut steps_replayed = 0;
+        let mut failure = None;
+
+        if divergence.is_none() {
+            for (index, step) in transcript.steps.iter().enumerate() {
+                if self.current_question != step.question {
+                    divergence = Some(transcript::Divergence {
+                        index: Some(index),
+                        expected: step.question.clone(),
+                        actual: self.current_question.clone(),
+                    });
+                    break;
+                }
+
+                let result = match step.action {
+                    transcript::Action::Answer(answer) => self.answer(answer).await,
+                    transcript::Action::Back => self.back().await,
+                };
+
+                if let Err(err) = result {
+                    failure = Some(transcript::ReplayFailure::new(Some(index), &err));
+                    break;
+                }
+                steps_replayed += 1;
+            }
+        }
+
+        let guess_matches = if divergence.is_none() && failure.is_none() && !transcript.guesses.is_empty() {
+            match self.win().await {
+                Ok(result) => Some(
+                    result.first.map(|guess| guess.id) == transcript.guesses.first().map(|guess| guess.id.clone())
+                ),
+                Err(err) => {
+                    failure = Some(transcript::ReplayFailure::new(None, &err));
+                    None
+                },
+            }
+        } else {
+            None
+        };
+
+        Ok(transcript::ReplayReport {
+            divergence,
+            steps_replayed,
+            guess_matches,
+            failure,
+            transcript: self.transcript(),
+        })
+    }
+
+    /// fetches the next page of guesses after [`Self::win`] has been called,
+    /// appending them to [`Self.guesses`] and returning only the newly fetched guesses
+    ///
+    /// the page size is [`Self.guess_list_size`], or [`DEFAULT_GUESS_PAGE_SIZE`] if not set.
+    /// returns an empty vec if the akinator has no more guesses to offer
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn more_guesses(&mut self) -> Result<models::GuessList> {
+        self.ensure_playing("fetch more guesses")?;
+
+        let size = self.guesses.len()
+            + self.guess_list_size.unwrap_or(DEFAULT_GUESS_PAGE_SIZE);
+
+        let new_guesses = self.fetch_guesses(Some(size))
+            .await?
+            .into_iter()
+            .filter(|guess| !self.guesses.iter().any(|g| g.id == guess.id))
+            .collect::<models::GuessList>();
+
+        self.guesses.extend(new_guesses.iter().cloned());
+
+        if self.first_guess.is_none() {
+            self.first_guess = self.guesses
+                .first()
+                .cloned();
+        }
+        if self.first_guess.is_some() {
+            self.state = GameState::AwaitingConfirmation;
+        }
+        if !new_guesses.is_empty() {
+            self.events.emit(|| events::GameEvent::GuessesReady { guesses: self.guesses.clone() });
+        }
+
+        Ok(new_guesses)
+    }
+
+    /// Goes back 1 question and returns the current question
+    /// Returns an Err value with [`Error::CantGoBackAnyFurther`] if we are already on question 0
+    ///
+    /// # Errors
     ///
     /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
     pub async fn back(&mut self) -> Result<Option<String>> {
+        self.ensure_playing("go back")?;
+
         if self.step == 0 {
             return Err(Error::CantGoBackAnyFurther);
         }
 
+        let result = self.send_back().await
+            .map_err(|err| self.with_context("cancel_answer", err));
+        self.report(result)
+    }
+
+    /// like [`Self::start`], but failing with [`Error::CallTimeout`] if it does not complete within `timeout`
+    ///
+    /// the timeout only bounds this call, see [`Self::with_game_deadline`] to bound the whole game.
+    /// A call that times out is aborted like a cancelled one, see [`Self::with_cancellation_token`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn start_with_timeout(&mut self, timeout: Duration) -> Result<Option<String>> {
+        within(timeout, self.start()).await
+    }
+
+    /// like [`Self::answer`], but failing with [`Error::CallTimeout`] if it does not complete within `timeout`,
+    /// see [`Self::start_with_timeout`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn answer_with_timeout(&mut self, answer: Answer, timeout: Duration) -> Result<Option<String>> {
+        within(timeout, self.answer(answer)).await
+    }
+
+    /// like [`Self::back`], but failing with [`Error::CallTimeout`] if it does not complete within `timeout`,
+    /// see [`Self::start_with_timeout`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn back_with_timeout(&mut self, timeout: Duration) -> Result<Option<String>> {
+        within(timeout, self.back()).await
+    }
+
+    /// like [`Self::win`], but failing with [`Error::CallTimeout`] if it does not complete within `timeout`,
+    /// see [`Self::start_with_timeout`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn win_with_timeout(&mut self, timeout: Duration) -> Result<models::WinResult> {
+        within(timeout, self.win()).await
+    }
+
+    /// Goes forward 1 question after going back with [`Self::back`], re-applying the answer that was undone,
+    /// and returns the current question, like a browser's forward button
+    ///
+    /// the answers undone by going back are forgotten once a question is answered
+    ///
+    /// # Errors
+    /// [`Error::CantGoForward`] if there is no answer to redo, see [`Self::can_go_forward`],
+    /// otherwise see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn forward(&mut self) -> Result<Option<String>> {
+        self.ensure_playing("go forward")?;
+
+        let Some(answer) = self.redo_answers.pop() else {
+            return Err(Error::CantGoForward);
+        };
+
+        // answering forgets the answers to redo, keep the ones after this one
+        let redo_answers = std::mem::take(&mut self.redo_answers);
+        let result = self.answer(answer).await;
+
+        self.redo_answers = redo_answers;
+        if result.is_err() {
+            self.redo_answers.push(answer);
+        }
+
+        result
+    }
+
+    /// changes the answer given at an earlier `step` to `answer`, going back to it,
+    /// then answering the following questions with the answers they were given before,
+    /// and returns the question asked at the end
+    ///
+    /// as the akinator may ask different questions after the changed answer,
+    /// the previous answers are replayed in order, whatever the questions they are now given to
+    ///
+    /// # Errors
+    /// [`Error::InvalidStep`] if no answer was given at `step`,
+    /// otherwise the error of going back or answering, leaving the game where it failed,
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn change_answer(&mut self, step: usize, answer: Answer) -> Result<Option<String>> {
+        self.ensure_playing("change an answer")?;
+
+        // the step the first of the stored answers was given at
+        let first = self.step.saturating_sub(self.answers.len());
+        let Some(index) = step.checked_sub(first).filter(|&index| index < self.answers.len()) else {
+            return Err(Error::InvalidStep(step));
+        };
+
+        let mut answers = self.answers[index..].to_vec();
+        answers[0] = answer;
+
+        while self.answers.len() > index {
+            self.back().await?;
+        }
+
+        self.replay_answers(answers).await
+    }
+
+    /// renews a game whose session has expired on the server, see [`Error::SessionExpired`],
+    /// starting a new session with [`Self::restart`] and replaying the answers given so far,
+    /// and returns the question asked at the end
+    ///
+    /// as the akinator may ask different questions in the new session,
+    /// the answers are replayed in order, whatever the questions they are now given to.
+    /// the rejected guesses are kept
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn renew(&mut self) -> Result<Option<String>> {
+        let answers = self.answers.clone();
+        let rejected_guesses = self.rejected_guesses.clone();
+
+        // the renewed session is part of the same game, so it keeps its deadline
+        let result = self.restart_game().await;
+        let result = self.report(result);
+        self.rejected_guesses.extend(rejected_guesses);
+        result?;
+
+        self.replay_answers(answers).await
+    }
+
+    /// internal method answering the questions with `answers` in order, returning the question asked at the end
+    async fn replay_answers(&mut self, answers: Vec<Answer>) -> Result<Option<String>> {
+        let mut question = self.current_question.clone();
+
+        for answer in answers {
+            question = self.answer(answer).await?;
+        }
+
+        Ok(question)
+    }
+
+    /// returns whether or not there is an answer undone by [`Self::back`] to redo with [`Self::forward`]
+    #[must_use]
+    pub fn can_go_forward(&self) -> bool {
+        !self.redo_answers.is_empty()
+    }
+
+    /// internal method making the request for [`Self::back`]
+    async fn send_back(&mut self) -> Result<Option<String>> {
         let params = [
-            (
-                "callback",
-                format!("jQuery331023608747682107778_{}", self.timestamp),
-            ),
+            ("callback", self.callback.clone()),
             ("childMod", self.child_mode.to_string()),
             ("session", get_field!(self.session)),
             ("signature", get_field!(self.signature)),
@@ -517,25 +2736,33 @@ impl Akinator {
             ),
         ];
 
-        let response = self.http_client
-            .get(format!("{}/cancel_answer", get_field!(self.ws_url)))
-            .headers(HEADERS.clone())
-            .query(&params)
-            .send()
-            .await?
-            .text()
-            .await?;
+        let (step, question) = (self.step, self.current_question.clone());
+        let sent_at = Instant::now();
+
+        let response = self.send(
+            self.http_client
+                .get(format!("{}/cancel_answer", get_field!(self.ws_url)))
+                .headers(HEADERS.clone())
+                .query(&params)
+        ).await?;
 
-        let json_string = Self::parse_response(response);
-        let json: models::MoveJson =
-            serde_json::from_str(json_string.as_str())?;
+        let json: models::raw::MoveJson =
+            self.parse_json(response)?;
 
         if json.completion.as_str() == "OK" {
+            let previous = self.progression;
             self.update_move_info(json)?;
+            self.record_step(step, question, transcript::Action::Back, sent_at.elapsed());
+
+            if let Some(answer) = self.answers.pop() {
+                self.redo_answers.push(answer);
+            }
+            self.emit_question(previous);
+            self.autosave();
 
             Ok(self.current_question.clone())
         } else {
-            Err(Self::handle_error_response(json.completion))
+            Err(Self::handle_session_error_response(json.completion))
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,241 @@
+//! UniFFI bindings for using akinator games from Swift and Kotlin
+//!
+//! Only available with the `uniffi` feature enabled. Build the library with
+//! `cargo rustc --release --lib --features uniffi --crate-type cdylib`, then generate the foreign bindings with:
+//! `cargo run --features uniffi --bin uniffi-bindgen generate --library <path to cdylib> --language swift`
+//!
+//! the types here mirror the ones in [`crate::enums`] and [`crate::models`],
+//! as not every rust type used in them (such as [`usize`]) is supported by UniFFI
+
+use std::sync::Arc;
+
+use thiserror::Error as ErrorBase;
+use tokio::sync::Mutex;
+
+use crate::{
+    enums,
+    models,
+    error::Error,
+};
+
+
+/// the errors returned from an [`AkinatorGame`]
+#[derive(Debug, ErrorBase, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum AkinatorError {
+    /// see [`Error::NoMoreQuestions`]
+    #[error("There are no more available questions")]
+    NoMoreQuestions,
+
+    /// see [`Error::CantGoBackAnyFurther`]
+    #[error("Cannot go back any further, you are already on the first question")]
+    CantGoBackAnyFurther,
+
+    /// any other error returned from the akinator
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<Error> for AkinatorError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::NoMoreQuestions => Self::NoMoreQuestions,
+            Error::CantGoBackAnyFurther => Self::CantGoBackAnyFurther,
+            err => Self::Other(err.to_string()),
+        }
+    }
+}
+
+/// mirror of [`enums::Answer`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum Answer {
+    Yes,
+    No,
+    Idk,
+    Probably,
+    ProbablyNot,
+}
+
+impl From<Answer> for enums::Answer {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Yes => Self::Yes,
+            Answer::No => Self::No,
+            Answer::Idk => Self::Idk,
+            Answer::Probably => Self::Probably,
+            Answer::ProbablyNot => Self::ProbablyNot,
+        }
+    }
+}
+
+/// mirror of [`enums::Theme`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum Theme {
+    Characters,
+    Animals,
+    Objects,
+}
+
+impl From<Theme> for enums::Theme {
+    fn from(theme: Theme) -> Self {
+        match theme {
+            Theme::Characters => Self::Characters,
+            Theme::Animals => Self::Animals,
+            Theme::Objects => Self::Objects,
+        }
+    }
+}
+
+/// mirror of [`enums::Language`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+pub enum Language {
+    English,
+    Arabic,
+    Chinese,
+    German,
+    Spanish,
+    French,
+    Hebrew,
+    Italian,
+    Japanese,
+    Korean,
+    Dutch,
+    Polish,
+    Portugese,
+    Russian,
+    Turkish,
+    Indonesian,
+}
+
+impl From<Language> for enums::Language {
+    fn from(language: Language) -> Self {
+        match language {
+            Language::English => Self::English,
+            Language::Arabic => Self::Arabic,
+            Language::Chinese => Self::Chinese,
+            Language::German => Self::German,
+            Language::Spanish => Self::Spanish,
+            Language::French => Self::French,
+            Language::Hebrew => Self::Hebrew,
+            Language::Italian => Self::Italian,
+            Language::Japanese => Self::Japanese,
+            Language::Korean => Self::Korean,
+            Language::Dutch => Self::Dutch,
+            Language::Polish => Self::Polish,
+            Language::Portugese => Self::Portugese,
+            Language::Russian => Self::Russian,
+            Language::Turkish => Self::Turkish,
+            Language::Indonesian => Self::Indonesian,
+        }
+    }
+}
+
+/// a guess that the akinator makes at the end of the game, mirror of [`models::Guess`]
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+pub struct Guess {
+    /// the unique id of the guess
+    pub id: String,
+    /// the name of the guess
+    pub name: String,
+    /// the akinator's confidence level / probability that this guess is accurate
+    pub confidence: String,
+    /// a brief desription of the guess
+    pub description: String,
+    /// the ranking place of the guess
+    pub ranking: String,
+    /// the absolute url to the image of the guess
+    pub absolute_picture_path: String,
+}
+
+impl From<models::Guess> for Guess {
+    fn from(guess: models::Guess) -> Self {
+        Self {
+            id: guess.id,
+            name: guess.name,
+            confidence: guess.confidence,
+            description: guess.description,
+            ranking: guess.ranking,
+            absolute_picture_path: guess.absolute_picture_path,
+        }
+    }
+}
+
+/// an akinator game, wrapping [`crate::Akinator`]
+#[derive(uniffi::Object)]
+pub struct AkinatorGame {
+    /// the wrapped akinator game
+    inner: Mutex<crate::Akinator>,
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl AkinatorGame {
+    /// Creates a new akinator game
+    ///
+    /// # Errors
+    /// If failed to create the HTTP client
+    #[uniffi::constructor]
+    pub fn new(language: Language, theme: Theme, child_mode: bool) -> Result<Arc<Self>, AkinatorError> {
+        let mut akinator = crate::Akinator::new()?
+            .with_language(language.into())
+            .with_theme(theme.into());
+        akinator.child_mode = child_mode;
+
+        Ok(Arc::new(Self { inner: Mutex::new(akinator) }))
+    }
+
+    /// Starts the akinator game and returns the first question
+    ///
+    /// # Errors
+    /// see [`crate::Akinator::start`]
+    pub async fn start(&self) -> Result<Option<String>, AkinatorError> {
+        Ok(self.inner.lock().await.start().await?)
+    }
+
+    /// answers the akinator's current question and returns the next question
+    ///
+    /// # Errors
+    /// see [`crate::Akinator::answer`]
+    pub async fn answer(&self, answer: Answer) -> Result<Option<String>, AkinatorError> {
+        Ok(self.inner.lock().await.answer(answer.into()).await?)
+    }
+
+    /// Goes back 1 question and returns the current question
+    ///
+    /// # Errors
+    /// see [`crate::Akinator::back`]
+    pub async fn back(&self) -> Result<Option<String>, AkinatorError> {
+        Ok(self.inner.lock().await.back().await?)
+    }
+
+    /// tells the akinator to end the game and make it's guess, returning its best guess
+    ///
+    /// # Errors
+    /// see [`crate::Akinator::win`]
+    pub async fn win(&self) -> Result<Option<Guess>, AkinatorError> {
+        Ok(self.inner.lock().await.win().await?.first.map(Guess::from))
+    }
+
+    /// returns the current question to answer
+    pub async fn current_question(&self) -> Option<String> {
+        self.inner.lock().await.current_question.clone()
+    }
+
+    /// returns the progress of the akinator, a float out of 100.0
+    pub async fn progression(&self) -> f32 {
+        self.inner.lock().await.progression.value()
+    }
+
+    /// returns the a counter of questions asked and answered
+    pub async fn step(&self) -> u64 {
+        self.inner.lock().await.step as u64
+    }
+
+    /// returns all the possible guesses by the akinator, only set once [`Self::win`] has been called
+    pub async fn guesses(&self) -> Vec<Guess> {
+        self.inner.lock().await.guesses
+            .iter()
+            .cloned()
+            .map(Guess::from)
+            .collect()
+    }
+}
@@ -1,54 +1,15 @@
-use serde::{Serialize, Deserialize};
-
-
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct StepInfo {
-    pub step: String,
-    pub question: String,
-    pub progression: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct IdentJson {
-    pub session: String,
-    pub signature: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct ParametersJson {
-    pub identification: IdentJson,
-    pub(crate) step_information: StepInfo,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct StartJson {
-    pub completion: String,
-    pub(crate) parameters: Option<ParametersJson>,
-}
-
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::Deref,
+};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct MoveJson {
-    pub completion: String,
-    pub(crate) parameters: Option<StepInfo>,
-}
+use reqwest::Url;
+use serde::{Serialize, Deserialize};
 
+use crate::enums::ImageSize;
 
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct WinElement {
-    pub element: Guess,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct WinParams {
-    pub(crate) elements: Vec<WinElement>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub(crate) struct WinJson {
-    pub completion: String,
-    pub(crate) parameters: Option<WinParams>,
-}
+pub mod raw;
 
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -60,6 +21,8 @@ pub(crate) struct ServerData {
 
 /// represents a guess that the akinator makes at the end of the game
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, module = "akinator_rs"))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Guess {
     /// the unique id of the guess
     pub id: String,
@@ -78,4 +41,341 @@ pub struct Guess {
     pub picture_path: String,
     /// the absolute url to the image of the guess
     pub absolute_picture_path: String,
-}
\ No newline at end of file
+}
+
+impl Guess {
+    /// returns the akinator's confidence that this guess is accurate, from `0.0` to `1.0`,
+    /// parsed from [`Self.confidence`] (`0.0` if it is malformed)
+    #[must_use]
+    pub fn probability(&self) -> f64 {
+        self.confidence
+            .trim()
+            .parse()
+            .unwrap_or(0.0)
+    }
+
+    /// returns the URL of the picture of the guess at the given `size`,
+    /// by rewriting the width segment of [`Self.absolute_picture_path`] (ex: `.../BL_25_en/600/partenaire/...`)
+    ///
+    /// the URL is returned as it is for [`ImageSize::Full`], or if it has no width segment to rewrite
+    #[must_use]
+    pub fn picture_url(&self, size: ImageSize) -> String {
+        let Some(width) = size.width() else {
+            return self.absolute_picture_path.clone();
+        };
+
+        let Ok(mut url) = Url::parse(&self.absolute_picture_path) else {
+            return self.absolute_picture_path.clone();
+        };
+
+        let mut segments = url.path_segments()
+            .map(|segments| segments.map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let directories = segments.len().saturating_sub(1);
+
+        match segments[..directories]
+            .iter_mut()
+            .find(|segment| !segment.is_empty() && segment.bytes().all(|byte| byte.is_ascii_digit()))
+        {
+            Some(segment) => {
+                *segment = width.to_string();
+                url.set_path(&segments.join("/"));
+                url.into()
+            },
+            None => self.absolute_picture_path.clone(),
+        }
+    }
+}
+
+/// a list of guesses made by the akinator, dereferencing to a slice of [`Guess`]es and serialized as a plain list
+///
+/// the probabilities of the guesses (see [`Guess::probability`]) are independent of each other
+/// and do not sum to anything meaningful, see [`Self::normalized`] to compare them
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GuessList(Vec<Guess>);
+
+impl GuessList {
+    /// Creates a new [`GuessList`] out of `guesses`
+    #[must_use]
+    pub const fn new(guesses: Vec<Guess>) -> Self {
+        Self(guesses)
+    }
+
+    /// returns the guesses as a [`Vec`]
+    #[must_use]
+    pub fn into_vec(self) -> Vec<Guess> {
+        self.0
+    }
+
+    /// returns the first guess named `name`, ignoring case
+    #[must_use]
+    pub fn find_by_name(&self, name: &str) -> Option<&Guess> {
+        let name = name.to_lowercase();
+
+        self.0
+            .iter()
+            .find(|guess| guess.name.to_lowercase() == name)
+    }
+
+    /// returns the guesses sorted by [`Guess::probability`], most likely first
+    #[must_use]
+    pub fn sorted_by_confidence(&self) -> Self {
+        let mut guesses = self.0.clone();
+        guesses.sort_by(|a, b| b.probability().total_cmp(&a.probability()));
+
+        Self(guesses)
+    }
+
+    /// returns the `n` most likely guesses, see [`Self::sorted_by_confidence`]
+    #[must_use]
+    pub fn top(&self, n: usize) -> Self {
+        let mut guesses = self.sorted_by_confidence();
+        guesses.0.truncate(n);
+
+        guesses
+    }
+
+    /// retains only the guesses for which `predicate` returns `true`
+    pub fn retain(&mut self, predicate: impl FnMut(&Guess) -> bool) {
+        self.0.retain(predicate);
+    }
+
+    /// returns the guesses with their probabilities renormalized to sum up to `1.0`,
+    /// rewriting [`Guess.confidence`]
+    ///
+    /// the list is returned as it is if none of its guesses has a positive probability
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        let total: f64 = self.0
+            .iter()
+            .map(Guess::probability)
+            .sum();
+
+        if total <= 0.0 {
+            return self.clone();
+        }
+
+        self.0
+            .iter()
+            .map(|guess| Guess {
+                confidence: (guess.probability() / total).to_string(),
+                ..guess.clone()
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// returns the fewest guesses whose normalized probabilities (see [`Self::normalized`])
+    /// add up to at least `coverage`, from `0.0` to `1.0`, best first
+    ///
+    /// ex: `guesses.covering(0.95)` for the top guesses covering 95% of the akinator's confidence
+    #[must_use]
+    pub fn covering(&self, coverage: f64) -> Self {
+        let mut guesses = self.normalized()
+            .sorted_by_confidence()
+            .into_vec();
+
+        let mut cumulative = 0.0;
+        let count = guesses
+            .iter()
+            .take_while(|guess| {
+                let covered = cumulative >= coverage;
+                cumulative += guess.probability();
+                !covered
+            })
+            .count();
+
+        guesses.truncate(count);
+        guesses.into()
+    }
+}
+
+impl Deref for GuessList {
+    type Target = [Guess];
+
+    fn deref(&self) -> &[Guess] {
+        &self.0
+    }
+}
+
+impl From<Vec<Guess>> for GuessList {
+    fn from(guesses: Vec<Guess>) -> Self {
+        Self(guesses)
+    }
+}
+
+impl From<GuessList> for Vec<Guess> {
+    fn from(guesses: GuessList) -> Self {
+        guesses.0
+    }
+}
+
+impl FromIterator<Guess> for GuessList {
+    fn from_iter<I: IntoIterator<Item = Guess>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Guess> for GuessList {
+    fn extend<I: IntoIterator<Item = Guess>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for GuessList {
+    type Item = Guess;
+    type IntoIter = std::vec::IntoIter<Guess>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GuessList {
+    type Item = &'a Guess;
+    type IntoIter = std::slice::Iter<'a, Guess>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+/// the outcome of the akinator making its guesses with [`crate::Akinator::win`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WinResult {
+    /// the akinator's best guess, [`None`] if it has no guess to offer
+    pub first: Option<Guess>,
+    /// every guess made, best first
+    pub all: GuessList,
+    /// the step the guesses were made at
+    pub step: usize,
+    /// the progression of the akinator when making the guesses
+    pub progression: Progression,
+}
+
+/// a question asked by the akinator
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Question {
+    /// the question to answer
+    pub text: String,
+    /// the step the question is asked at, starting at 0
+    pub step: usize,
+    /// the progression of the akinator when asking the question
+    pub progression: Progression,
+}
+
+/// the outcome of answering a question with [`crate::Akinator::answer_step`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum StepOutcome {
+    /// the akinator asked another question
+    NextQuestion(Question),
+    /// the akinator is ready to propose a guess with [`crate::Akinator::win`],
+    /// the game can still continue by answering the contained question if the guess is declined
+    ReadyToGuess(Question),
+    /// the akinator ran out of questions, so its guesses were fetched with [`crate::Akinator::win`]
+    Finished {
+        /// the akinator's guesses, best first
+        guesses: GuessList,
+    },
+}
+
+/// the internal session data of a started game, see [`crate::Akinator::session_info`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionInfo {
+    /// the websocket url (server) used for the game
+    pub ws_url: String,
+    /// a (0 - 100) number representing the game's session
+    pub session: usize,
+    /// a 9 - 10ish digit number that represents the game's signature
+    pub signature: usize,
+    /// the unique identifier for the akinator session
+    pub uid: String,
+    /// an IP address encoded in Base64, for authentication purposes
+    pub frontaddr: String,
+}
+
+/// an immutable copy of the state of a game, see [`crate::Akinator::snapshot`]
+///
+/// cheap to clone and independent of the game, so it can be rendered without borrowing the game across awaits
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    /// the question currently asked, [`None`] if the game has not been started
+    pub question: Option<String>,
+    /// the step of the current question, starting at 0
+    pub step: usize,
+    /// the progression of the akinator
+    pub progression: Progression,
+    /// the guesses made by the akinator, empty until [`crate::Akinator::win`] is called
+    pub guesses: GuessList,
+    /// the state of the game
+    pub state: crate::enums::GameState,
+}
+
+/// the progression of the akinator, a percentage clamped between 0.0 and 100.0
+///
+/// compares directly against percentages (ex: `progression >= 80.0`), never fractions like `0.8`,
+/// use [`Self::as_fraction`] for those
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(from = "f32", into = "f32")]
+pub struct Progression(f32);
+
+impl Progression {
+    /// Creates a new [`Progression`] from a percentage, clamped between 0.0 and 100.0
+    #[must_use]
+    pub fn new(percentage: f32) -> Self {
+        Self(if percentage.is_nan() { 0.0 } else { percentage.clamp(0.0, 100.0) })
+    }
+
+    /// returns the progression as a percentage, between 0.0 and 100.0
+    #[must_use]
+    pub const fn value(self) -> f32 {
+        self.0
+    }
+
+    /// returns the progression as a fraction, between 0.0 and 1.0
+    #[must_use]
+    pub fn as_fraction(self) -> f32 {
+        self.0 / 100.0
+    }
+
+    /// returns whether or not the progression has reached `threshold`, a percentage
+    #[must_use]
+    pub fn is_confident(self, threshold: f32) -> bool {
+        self.0 >= threshold
+    }
+}
+
+impl From<f32> for Progression {
+    fn from(percentage: f32) -> Self {
+        Self::new(percentage)
+    }
+}
+
+impl From<Progression> for f32 {
+    fn from(progression: Progression) -> Self {
+        progression.0
+    }
+}
+
+impl PartialEq<f32> for Progression {
+    fn eq(&self, other: &f32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd<f32> for Progression {
+    fn partial_cmp(&self, other: &f32) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl fmt::Display for Progression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}%", precision, self.0),
+            None => write!(f, "{}%", self.0),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! The raw JSON response models of the akinator API, for building your own request layer
+//!
+//! numeric fields are kept as strings, exactly as sent by the API,
+//! and the models are `#[non_exhaustive]` as the API may add fields at any time
+
+use serde::{Serialize, Deserialize};
+
+use super::Guess;
+
+
+/// the information about the current step of the game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StepInfo {
+    /// the step of the game, starting at 0
+    pub step: String,
+    /// the question to answer
+    pub question: String,
+    /// the progression of the akinator, a float out of 100.0
+    pub progression: String,
+}
+
+/// the identification of a game's session
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct IdentJson {
+    /// a (0 - 100) number representing the game's session
+    pub session: String,
+    /// a 9 - 10ish digit number that represents the game's signature
+    pub signature: String,
+}
+
+/// the parameters of a [`StartJson`] response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ParametersJson {
+    /// the identification of the new session
+    pub identification: IdentJson,
+    /// the first step of the game
+    pub step_information: StepInfo,
+}
+
+/// the response of the `new_session` endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StartJson {
+    /// the completion code, `OK` on success
+    pub completion: String,
+    /// the parameters of the new session, only present on success
+    pub parameters: Option<ParametersJson>,
+}
+
+/// the response of the `answer_api` and `cancel_answer` endpoints
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MoveJson {
+    /// the completion code, `OK` on success
+    pub completion: String,
+    /// the next step of the game, only present on success
+    pub parameters: Option<StepInfo>,
+}
+
+/// a single guess of a [`WinJson`] response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WinElement {
+    /// the guess
+    pub element: Guess,
+}
+
+/// the parameters of a [`WinJson`] response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WinParams {
+    /// the guesses, best first
+    pub elements: Vec<WinElement>,
+}
+
+/// the response of the `list` endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WinJson {
+    /// the completion code, `OK` on success
+    pub completion: String,
+    /// the guesses, only present on success
+    pub parameters: Option<WinParams>,
+}
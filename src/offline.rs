@@ -0,0 +1,399 @@
+//! A simple offline 20-questions engine, playing over a user-supplied knowledge base
+//! with the same `start` / `answer` / `back` / `win` interface as [`crate::Akinator`]
+//!
+//! Only available with the `offline` feature enabled. Useful as a fallback when the akinator servers are down,
+//! and as a deterministic engine for demos and tests.
+//!
+//! the engine asks the question splitting the remaining candidates the most evenly (highest entropy),
+//! weighting each candidate by how well it matches the answers given so far
+
+use std::collections::HashMap;
+
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    enums::Answer,
+    error::{Error, Result},
+    models::{Guess, GuessList, Progression, WinResult},
+};
+
+
+/// how much a fully contradicting answer scales down a candidate's weight,
+/// kept below 1 so a single wrong answer does not rule out the right candidate
+const MISMATCH_PENALTY: f64 = 0.9;
+
+
+/// a question of a [`KnowledgeBase`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnowledgeQuestion {
+    /// the unique id of the question, referenced by [`Entity::attributes`]
+    pub id: String,
+    /// the text of the question asked
+    pub text: String,
+}
+
+/// an entity (character, animal, object...) of a [`KnowledgeBase`] that can be guessed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entity {
+    /// the name of the entity
+    pub name: String,
+    /// a brief description of the entity
+    #[serde(default)]
+    pub description: String,
+    /// the absolute url to an image of the entity
+    #[serde(default)]
+    pub picture: Option<String>,
+    /// the probability (from `0.0` to `1.0`) of the answer being "yes" for each question id,
+    /// questions missing here are treated as unknown
+    #[serde(default)]
+    pub attributes: HashMap<String, f32>,
+}
+
+/// a knowledge base of questions and entities for the [`OfflineAkinator`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeBase {
+    /// the questions that can be asked
+    pub questions: Vec<KnowledgeQuestion>,
+    /// the entities that can be guessed
+    pub entities: Vec<Entity>,
+}
+
+impl KnowledgeBase {
+    /// parses a knowledge base from JSON, in the form of:
+    /// `{"questions": [{"id": "...", "text": "..."}], "entities": [{"name": "...", "attributes": {"<question id>": 1.0}}]}`
+    ///
+    /// # Errors
+    /// If the JSON is malformed
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// parses a knowledge base from CSV, with one row per entity
+    ///
+    /// the header row holds `name`, an optional `description` column, then one column per question's text,
+    /// which is also used as the question's id;
+    /// cells hold either a probability from `0.0` to `1.0` or an answer (ex: `yes`, `probably not`),
+    /// empty cells and `idk` are treated as unknown
+    ///
+    /// # Errors
+    /// [`Error::InvalidKnowledgeBase`] if the CSV is malformed
+    pub fn from_csv(csv: &str) -> Result<Self> {
+        let mut lines = csv.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let (_, header) = lines.next()
+            .ok_or_else(|| Error::InvalidKnowledgeBase("missing header row".to_string()))?;
+        let header = split_csv_line(header);
+
+        let description_column = header.iter()
+            .position(|column| column.eq_ignore_ascii_case("description"));
+
+        let question_columns = (1..header.len())
+            .filter(|&column| Some(column) != description_column)
+            .collect::<Vec<_>>();
+
+        let questions = question_columns.iter()
+            .map(|&column| KnowledgeQuestion {
+                id: header[column].clone(),
+                text: header[column].clone(),
+            })
+            .collect();
+
+        let entities = lines
+            .map(|(index, line)| {
+                let row = split_csv_line(line);
+
+                if row.len() != header.len() {
+                    return Err(Error::InvalidKnowledgeBase(format!(
+                        "line {} has {} columns, expected {}", index + 1, row.len(), header.len(),
+                    )));
+                }
+
+                let mut attributes = HashMap::new();
+                for &column in &question_columns {
+                    if let Some(value) = parse_cell(&row[column]).ok_or_else(|| Error::InvalidKnowledgeBase(format!(
+                        "invalid value {:?} on line {}", row[column], index + 1,
+                    )))? {
+                        attributes.insert(header[column].clone(), value);
+                    }
+                }
+
+                Ok(Entity {
+                    name: row[0].clone(),
+                    description: description_column
+                        .map(|column| row[column].clone())
+                        .unwrap_or_default(),
+                    picture: None,
+                    attributes,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { questions, entities })
+    }
+}
+
+/// internal function splitting a CSV line into its fields, handling double quoted fields
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(ch),
+        }
+    }
+    fields.push(field.trim().to_string());
+
+    fields
+}
+
+/// internal function parsing a CSV cell into the probability of the answer being "yes"
+///
+/// returns `Some(None)` for unknown values and [`None`] for invalid ones
+fn parse_cell(cell: &str) -> Option<Option<f32>> {
+    if cell.is_empty() {
+        return Some(None);
+    }
+
+    if let Ok(value) = cell.parse::<f32>() {
+        return (0.0..=1.0).contains(&value).then_some(Some(value));
+    }
+
+    cell.parse::<Answer>()
+        .ok()
+        .map(answer_target)
+}
+
+/// internal function returning the probability of "yes" an answer stands for, [`None`] for [`Answer::Idk`]
+const fn answer_target(answer: Answer) -> Option<f32> {
+    match answer {
+        Answer::Yes => Some(1.0),
+        Answer::Probably => Some(0.75),
+        Answer::Idk => None,
+        Answer::ProbablyNot => Some(0.25),
+        Answer::No => Some(0.0),
+    }
+}
+
+/// internal function returning the binary entropy of a probability
+fn entropy(p: f64) -> f64 {
+    if p <= 0.0 || p >= 1.0 {
+        0.0
+    } else {
+        -(p * p.log2() + (1.0 - p) * (1.0 - p).log2())
+    }
+}
+
+
+/// an offline akinator game, playing over a [`KnowledgeBase`]
+#[derive(Debug, Clone)]
+pub struct OfflineAkinator {
+    /// the current question to answer
+    pub current_question: Option<String>,
+    /// the confidence of the engine in its best candidate
+    pub progression: Progression,
+    /// the amount of questions answered
+    pub step: usize,
+    /// the engine's best guess, set after calling [`Self::win`]
+    pub first_guess: Option<Guess>,
+    /// every candidate ranked by likelihood, set after calling [`Self::win`]
+    pub guesses: GuessList,
+
+    /// the knowledge base the game is played over
+    knowledge_base: KnowledgeBase,
+    /// the index of the current question in [`KnowledgeBase::questions`]
+    current: Option<usize>,
+    /// the index of every question answered so far, with its answer
+    history: Vec<(usize, Answer)>,
+    /// the current weight of each entity
+    weights: Vec<f64>,
+}
+
+impl OfflineAkinator {
+    /// Creates a new offline game over `knowledge_base`
+    #[must_use]
+    pub fn new(knowledge_base: KnowledgeBase) -> Self {
+        Self {
+            current_question: None,
+            progression: Progression::default(),
+            step: 0,
+            first_guess: None,
+            guesses: GuessList::default(),
+            weights: vec![1.0; knowledge_base.entities.len()],
+            knowledge_base,
+            current: None,
+            history: Vec::new(),
+        }
+    }
+
+    /// returns the knowledge base the game is played over
+    #[must_use]
+    pub const fn knowledge_base(&self) -> &KnowledgeBase {
+        &self.knowledge_base
+    }
+
+    /// Starts the game and returns the first question
+    ///
+    /// # Errors
+    /// [`Error::NoDataFound`] if the knowledge base has no entities,
+    /// [`Error::NoMoreQuestions`] if none of its questions tell the entities apart
+    pub async fn start(&mut self) -> Result<Option<String>> {
+        if self.knowledge_base.entities.is_empty() {
+            return Err(Error::NoDataFound);
+        }
+
+        self.history.clear();
+        self.step = 0;
+        self.first_guess = None;
+        self.guesses = GuessList::default();
+        self.update();
+
+        self.next_question()
+    }
+
+    /// answers the current question, which can be retrieved with [`Self.current_question`],
+    /// and returns the next question
+    ///
+    /// # Errors
+    /// [`Error::NoMoreQuestions`] if there is no question left worth asking, [`Self::win`] should then be called
+    pub async fn answer(&mut self, answer: Answer) -> Result<Option<String>> {
+        let question = self.current.ok_or(Error::NoMoreQuestions)?;
+
+        self.history.push((question, answer));
+        self.step += 1;
+        self.update();
+
+        self.next_question()
+    }
+
+    /// Goes back 1 question and returns the current question
+    ///
+    /// # Errors
+    /// [`Error::CantGoBackAnyFurther`] if we are already on question 0
+    pub async fn back(&mut self) -> Result<Option<String>> {
+        let (question, _) = self.history.pop()
+            .ok_or(Error::CantGoBackAnyFurther)?;
+
+        self.step -= 1;
+        self.update();
+
+        self.current = Some(question);
+        self.current_question = Some(self.knowledge_base.questions[question].text.clone());
+
+        Ok(self.current_question.clone())
+    }
+
+    /// ends the game and returns the engine's guesses, which also can be retrieved with [`Self.first_guess`] and [`Self.guesses`]
+    ///
+    /// # Errors
+    /// [`Error::NoDataFound`] if the knowledge base has no entities
+    pub async fn win(&mut self) -> Result<WinResult> {
+        let total = self.weights.iter().sum::<f64>();
+
+        let mut ranked = self.weights.iter()
+            .copied()
+            .enumerate()
+            .collect::<Vec<_>>();
+        ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        self.guesses = ranked.into_iter()
+            .enumerate()
+            .map(|(ranking, (index, weight))| {
+                let entity = &self.knowledge_base.entities[index];
+
+                Guess {
+                    id: index.to_string(),
+                    name: entity.name.clone(),
+                    award_id: String::new(),
+                    flag_photo: 0,
+                    confidence: format!("{:.5}", weight / total),
+                    description: entity.description.clone(),
+                    ranking: (ranking + 1).to_string(),
+                    picture_path: String::new(),
+                    absolute_picture_path: entity.picture.clone().unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        self.first_guess = self.guesses.first().cloned();
+        if self.first_guess.is_none() {
+            return Err(Error::NoDataFound);
+        }
+
+        Ok(WinResult {
+            first: self.first_guess.clone(),
+            all: self.guesses.clone(),
+            step: self.step,
+            progression: self.progression,
+        })
+    }
+
+    /// internal method recomputing the weight of every entity and the progression from the answers given so far
+    fn update(&mut self) {
+        let questions = &self.knowledge_base.questions;
+
+        self.weights = self.knowledge_base.entities.iter()
+            .map(|entity| {
+                self.history.iter()
+                    .filter_map(|&(question, answer)| Some((question, answer_target(answer)?)))
+                    .map(|(question, target)| {
+                        let value = entity.attributes
+                            .get(&questions[question].id)
+                            .map_or(0.5, |&value| value.clamp(0.0, 1.0));
+
+                        1.0 - MISMATCH_PENALTY * f64::from((target - value).abs())
+                    })
+                    .product()
+            })
+            .collect();
+
+        let total = self.weights.iter().sum::<f64>();
+        let best = self.weights.iter().copied().fold(0.0, f64::max);
+
+        #[allow(clippy::cast_possible_truncation)]
+        let progression = if total > 0.0 { (best / total * 100.0) as f32 } else { 0.0 };
+        self.progression = Progression::new(progression);
+    }
+
+    /// internal method picking the unasked question splitting the weighted candidates the most evenly
+    fn next_question(&mut self) -> Result<Option<String>> {
+        let total = self.weights.iter().sum::<f64>();
+
+        let best = self.knowledge_base.questions.iter()
+            .enumerate()
+            .filter(|(index, _)| !self.history.iter().any(|(question, _)| question == index))
+            .map(|(index, question)| {
+                let (known, yes) = self.knowledge_base.entities.iter()
+                    .zip(&self.weights)
+                    .filter_map(|(entity, weight)| Some((weight, entity.attributes.get(&question.id)?)))
+                    .fold((0.0, 0.0), |(known, yes), (weight, &value)| {
+                        (known + weight, yes + weight * f64::from(value.clamp(0.0, 1.0)))
+                    });
+
+                let score = if known > 0.0 { entropy(yes / known) * known / total } else { 0.0 };
+                (index, score)
+            })
+            .filter(|&(_, score)| score > 0.0)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        self.current = best.map(|(index, _)| index);
+        self.current_question = self.current
+            .map(|index| self.knowledge_base.questions[index].text.clone());
+
+        if self.current_question.is_none() {
+            return Err(Error::NoMoreQuestions);
+        }
+
+        Ok(self.current_question.clone())
+    }
+}
@@ -0,0 +1,287 @@
+//! The parsing of the pages and API responses served by akinator, independent of any game
+//!
+//! every function here takes untrusted server output and must never panic on it,
+//! they are fuzzed by the targets in the `fuzz` directory
+
+use lazy_static::lazy_static;
+use regex::{Regex, RegexBuilder};
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    error::{Error, Result, UpdateInfoError},
+    models::{
+        Guess,
+        Progression,
+        Question,
+        ServerData,
+        raw::{IdentJson, StepInfo, WinParams},
+    },
+    text,
+};
+
+mod js;
+
+
+/// strips the JSONP callback call wrapped around an API response, whatever the callback's name,
+/// returning the json string
+///
+/// responses without a callback are returned untouched
+#[must_use]
+pub fn strip_jsonp(body: &str) -> &str {
+    lazy_static! {
+        static ref CALLBACK_REGEX: Regex =
+            RegexBuilder::new(r"^\s*[a-z_$][\w$.]*\s*\(")
+                .case_insensitive(true)
+                .multi_line(true)
+                .build()
+                .unwrap();
+    }
+
+    let Some(callback) = CALLBACK_REGEX.find(body) else {
+        return body;
+    };
+
+    body[callback.end()..]
+        .trim_end()
+        .trim_end_matches(';')
+        .strip_suffix(')')
+        .unwrap_or(body)
+}
+
+/// extracts the URL of the API server for the theme with the given `subject_id` from the homepage's `html`
+///
+/// # Errors
+/// [`Error::NoDataFound`] if the server list or the theme is missing,
+/// [`Error::JsonParseError`] if the server list is malformed
+pub fn server_url(html: &str, subject_id: &str) -> Result<String> {
+    lazy_static! {
+        static ref DATA_REGEX: Regex = RegexBuilder::new(
+            r#"\[\{"translated_theme_name":".*","urlWs":"https:\\/\\/srv[0-9]+\.akinator\.com:[0-9]+\\/ws","subject_id":"[0-9]+"\}\]"#
+        )
+            .case_insensitive(true)
+            .multi_line(true)
+            .build()
+            .unwrap();
+    }
+
+    let mat = DATA_REGEX.find(html)
+        .ok_or(Error::NoDataFound)?;
+
+    let servers: Vec<ServerData> =
+        serde_json::from_str(mat.as_str())?;
+
+    servers
+        .into_iter()
+        .find(|server| server.subject_id == subject_id)
+        .map(|server| server.url_ws)
+        .ok_or(Error::NoDataFound)
+}
+
+/// a strategy extracting the session info from the game page, see [`extract_session_info`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtractionStrategy {
+    /// tokenizes the inline scripts of the page, finding the variables whatever their formatting
+    Tokenizer,
+    /// the original regex, matching `var uid_ext_session = '...';` directly followed by `var frontaddr = '...';`
+    VarsRegex,
+    /// looser regexes, matching each value on its own, including `data-uid-ext-session="..."` attributes
+    AlternateRegex,
+    /// parses the JSON config blobs embedded in the page's scripts, looking for the values at any depth
+    JsonConfig,
+}
+
+impl ExtractionStrategy {
+    /// every strategy, in the order they are tried in
+    pub const ALL: [Self; 4] = [Self::Tokenizer, Self::VarsRegex, Self::AlternateRegex, Self::JsonConfig];
+
+    /// extracts the `uid_ext_session` and `frontaddr` from `html` with this strategy
+    #[must_use]
+    pub fn extract(self, html: &str) -> Option<(String, String)> {
+        match self {
+            Self::Tokenizer => tokenizer_strategy(html),
+            Self::VarsRegex => vars_regex_strategy(html),
+            Self::AlternateRegex => alternate_regex_strategy(html),
+            Self::JsonConfig => json_config_strategy(html),
+        }
+    }
+}
+
+/// the session info extracted from the game page, see [`extract_session_info`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedSessionInfo {
+    /// the unique identifier of the session
+    pub uid: String,
+    /// an IP address encoded in Base64, for authentication purposes
+    pub frontaddr: String,
+    /// the strategy that found the session info
+    pub strategy: ExtractionStrategy,
+}
+
+/// extracts the session's `uid_ext_session` and `frontaddr` from the game page's `html`,
+/// trying every [`ExtractionStrategy`] in order until one finds both
+///
+/// # Errors
+/// [`Error::NoDataFound`] if no strategy finds them
+pub fn extract_session_info(html: &str) -> Result<ExtractedSessionInfo> {
+    ExtractionStrategy::ALL
+        .into_iter()
+        .find_map(|strategy| {
+            strategy.extract(html)
+                .map(|(uid, frontaddr)| ExtractedSessionInfo { uid, frontaddr, strategy })
+        })
+        .ok_or(Error::NoDataFound)
+}
+
+/// extracts the session's `uid_ext_session` and `frontaddr` from the game page's `html`,
+/// see [`extract_session_info`]
+///
+/// # Errors
+/// [`Error::NoDataFound`] if either is missing
+pub fn session_info(html: &str) -> Result<(String, String)> {
+    extract_session_info(html)
+        .map(|info| (info.uid, info.frontaddr))
+}
+
+/// [`ExtractionStrategy::Tokenizer`]
+fn tokenizer_strategy(html: &str) -> Option<(String, String)> {
+    let tokens = scripts(html)
+        .flat_map(js::tokenize)
+        .collect::<Vec<_>>();
+
+    let find = |name| js::find_string(&tokens, name)
+        .map(str::to_string);
+
+    Some((find("uid_ext_session")?, find("frontaddr")?))
+}
+
+/// [`ExtractionStrategy::VarsRegex`]
+fn vars_regex_strategy(html: &str) -> Option<(String, String)> {
+    lazy_static! {
+        static ref VARS_REGEX: Regex =
+            RegexBuilder::new(r"var uid_ext_session = '(.*)';\n.*var frontaddr = '(.*)';")
+                .case_insensitive(true)
+                .multi_line(true)
+                .build()
+                .unwrap();
+    }
+
+    let captures = VARS_REGEX.captures(html)?;
+
+    Some((
+        captures.get(1)?.as_str().to_string(),
+        captures.get(2)?.as_str().to_string(),
+    ))
+}
+
+/// [`ExtractionStrategy::AlternateRegex`]
+fn alternate_regex_strategy(html: &str) -> Option<(String, String)> {
+    lazy_static! {
+        static ref UID_REGEXES: [Regex; 2] = [
+            Regex::new(r#"(?i)uid_ext_session["']?\s*[:=]\s*["'`]([^"'`]+)"#).unwrap(),
+            Regex::new(r#"(?i)data-uid-ext-session\s*=\s*["']([^"']+)"#).unwrap(),
+        ];
+        static ref FRONTADDR_REGEXES: [Regex; 2] = [
+            Regex::new(r#"(?i)frontaddr["']?\s*[:=]\s*["'`]([^"'`]+)"#).unwrap(),
+            Regex::new(r#"(?i)data-frontaddr\s*=\s*["']([^"']+)"#).unwrap(),
+        ];
+    }
+
+    let find = |regexes: &[Regex]| regexes
+        .iter()
+        .find_map(|regex| regex.captures(html)?.get(1))
+        .map(|value| value.as_str().to_string());
+
+    Some((find(&*UID_REGEXES)?, find(&*FRONTADDR_REGEXES)?))
+}
+
+/// [`ExtractionStrategy::JsonConfig`]
+fn json_config_strategy(html: &str) -> Option<(String, String)> {
+    /// internal function returning the first string value of any of the `keys` in `value`, at any depth
+    fn find(value: &serde_json::Value, keys: &[&str]) -> Option<String> {
+        match value {
+            serde_json::Value::Object(map) => keys
+                .iter()
+                .find_map(|key| map.get(*key)?.as_str().map(str::to_string))
+                .or_else(|| map.values().find_map(|value| find(value, keys))),
+            serde_json::Value::Array(values) => values
+                .iter()
+                .find_map(|value| find(value, keys)),
+            _ => None,
+        }
+    }
+
+    scripts(html).find_map(|script| {
+        // the blob may be assigned to a variable, ex: `window.config = {...};`
+        let start = script.find('{')?;
+        let end = script.rfind('}')?;
+
+        let config = serde_json::from_str::<serde_json::Value>(script.get(start..=end)?).ok()?;
+
+        Some((
+            find(&config, &["uid_ext_session", "uidExtSession"])?,
+            find(&config, &["frontaddr", "frontAddr"])?,
+        ))
+    })
+}
+
+/// internal function returning the contents of the `<script>` elements of `html`,
+/// or the whole of `html` if it has none, as when a bare script is served
+fn scripts(html: &str) -> impl Iterator<Item = &str> {
+    let lowercase = html.to_ascii_lowercase();
+    let mut scripts = Vec::new();
+    let mut pos = 0;
+
+    while let Some(open) = lowercase[pos..].find("<script").map(|i| pos + i) {
+        let Some(start) = lowercase[open..].find('>').map(|i| open + i + 1) else {
+            break;
+        };
+        let end = lowercase[start..].find("</script")
+            .map_or(html.len(), |i| start + i);
+
+        scripts.push(&html[start..end]);
+        pos = end;
+    }
+
+    if scripts.is_empty() {
+        scripts.push(html);
+    }
+
+    scripts.into_iter()
+}
+
+/// parses the session and signature of a new session
+///
+/// # Errors
+/// [`UpdateInfoError`] if either is not a valid number
+pub fn identification(ident: &IdentJson) -> Result<(usize, usize), UpdateInfoError> {
+    Ok((
+        ident.session.parse()?,
+        ident.signature.parse()?,
+    ))
+}
+
+/// parses a step of the game into a [`Question`], decoding its text
+///
+/// # Errors
+/// [`UpdateInfoError`] if the step or progression is not a valid number
+pub fn step(info: &StepInfo) -> Result<Question, UpdateInfoError> {
+    Ok(Question {
+        text: text::decode(&info.question),
+        step: info.step.parse()?,
+        progression: Progression::new(info.progression.parse()?),
+    })
+}
+
+/// parses the guesses of a `list` response, decoding their text
+#[must_use]
+pub fn guesses(params: WinParams) -> Vec<Guess> {
+    params.elements
+        .into_iter()
+        .map(|e| Guess {
+            name: text::decode(&e.element.name),
+            description: text::decode(&e.element.description),
+            ..e.element
+        })
+        .collect()
+}
@@ -0,0 +1,103 @@
+//! A lightweight javascript tokenizer, just enough to find the string values assigned to variables
+//! in the inline scripts of the website, whatever their formatting
+
+/// a javascript token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// an identifier, keyword or number, ex: `var` or `uid_ext_session`
+    Ident(&'a str),
+    /// a string literal, unescaped
+    Str(String),
+    /// any other single character, ex: `=` or `;`
+    Punct(char),
+}
+
+/// splits `source` into [`Token`]s, skipping whitespace and comments
+///
+/// never fails: unterminated strings and comments run to the end of `source`
+pub(crate) fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {},
+            '/' if chars.peek().is_some_and(|&(_, next)| next == '/') => {
+                while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+            },
+            '/' if chars.peek().is_some_and(|&(_, next)| next == '*') => {
+                chars.next();
+
+                let mut star = false;
+                for (_, c) in chars.by_ref() {
+                    if star && c == '/' {
+                        break;
+                    }
+                    star = c == '*';
+                }
+            },
+            '\'' | '"' | '`' => tokens.push(Token::Str(string_literal(&mut chars, c))),
+            c if is_ident_char(c) => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) = chars.next_if(|&(_, c)| is_ident_char(c)) {
+                    end = i + c.len_utf8();
+                }
+
+                tokens.push(Token::Ident(&source[start..end]));
+            },
+            c => tokens.push(Token::Punct(c)),
+        }
+    }
+
+    tokens
+}
+
+/// returns the value of the first string assigned to `name` in `tokens`,
+/// either as a variable (`name = '...'`) or as an object property (`name: '...'` or `"name": "..."`)
+pub(crate) fn find_string<'t>(tokens: &'t [Token<'_>], name: &str) -> Option<&'t str> {
+    tokens.windows(3).find_map(|window| match window {
+        [Token::Ident(key), Token::Punct('=' | ':'), Token::Str(value)] if *key == name => Some(value.as_str()),
+        [Token::Str(key), Token::Punct(':'), Token::Str(value)] if key == name => Some(value.as_str()),
+        _ => None,
+    })
+}
+
+/// returns whether or not `c` can be part of an identifier
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+/// internal function reading a string literal opened by `quote`, unescaping it
+fn string_literal(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>, quote: char) -> String {
+    let mut value = String::new();
+
+    while let Some((_, c)) = chars.next() {
+        match c {
+            c if c == quote => break,
+            '\\' => match chars.next().map(|(_, c)| c) {
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                Some('r') => value.push('\r'),
+                Some('x') => value.extend(hex_escape(chars, 2)),
+                Some('u') => value.extend(hex_escape(chars, 4)),
+                Some('\n') | None => {},
+                Some(c) => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+
+    value
+}
+
+/// internal function reading the `len` hex digits of a `\x` or `\u` escape
+fn hex_escape(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>, len: usize) -> Option<char> {
+    let mut code = 0;
+
+    for _ in 0..len {
+        let digit = chars.next_if(|(_, c)| c.is_ascii_hexdigit())?.1;
+        code = code * 16 + digit.to_digit(16)?;
+    }
+
+    char::from_u32(code)
+}
@@ -0,0 +1,131 @@
+//! A pool limiting the number of simultaneously active akinator games
+//!
+//! Only available with the `pool` feature enabled.
+//! Protects both the akinator servers and the host process when many games are started at once,
+//! by queueing new games once the limit has been reached.
+
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{
+    Akinator,
+    error::Result,
+};
+
+
+/// A pool limiting the number of simultaneously active games
+///
+/// cloning the pool is cheap and shares the same limit
+#[derive(Debug, Clone)]
+pub struct SessionPool {
+    /// the semaphore handing out a permit for each active game
+    semaphore: Arc<Semaphore>,
+    /// the max amount of simultaneously active games
+    max_active: usize,
+}
+
+/// An akinator game holding a slot of a [`SessionPool`], releasing it once dropped
+///
+/// dereferences to the underlying [`Akinator`]
+#[derive(Debug)]
+pub struct PooledGame {
+    /// the akinator game
+    akinator: Akinator,
+    /// the permit representing this game's slot in the pool
+    _permit: OwnedSemaphorePermit,
+}
+
+impl SessionPool {
+    /// Creates a new [`SessionPool`] allowing at most `max_active` simultaneously active games
+    #[must_use]
+    pub fn new(max_active: usize) -> Self {
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_active)),
+            max_active,
+        }
+    }
+
+    /// returns the max amount of simultaneously active games
+    #[must_use]
+    pub const fn max_active(&self) -> usize {
+        self.max_active
+    }
+
+    /// returns the amount of currently free slots
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+
+    /// returns the amount of currently active games
+    #[must_use]
+    pub fn active(&self) -> usize {
+        self.max_active - self.available()
+    }
+
+    /// Waits for a free slot in the pool and assigns it to `akinator`, without starting the game
+    ///
+    /// # Panics
+    /// never, as the pool's semaphore is never closed
+    pub async fn acquire(&self, akinator: Akinator) -> PooledGame {
+        let permit = Arc::clone(&self.semaphore)
+            .acquire_owned()
+            .await
+            .expect("SessionPool semaphore should never be closed");
+
+        PooledGame { akinator, _permit: permit }
+    }
+
+    /// Assigns a free slot in the pool to `akinator` without waiting,
+    /// returning the game back as an [`Err`] value if the pool is full
+    ///
+    /// # Errors
+    /// If there are no free slots in the pool
+    #[allow(clippy::result_large_err)]
+    pub fn try_acquire(&self, akinator: Akinator) -> Result<PooledGame, Akinator> {
+        match Arc::clone(&self.semaphore).try_acquire_owned() {
+            Ok(permit) => Ok(PooledGame { akinator, _permit: permit }),
+            Err(_) => Err(akinator),
+        }
+    }
+
+    /// Waits for a free slot in the pool, then starts the game with [`Akinator::start`]
+    ///
+    /// the slot is released if starting the game fails
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn start(&self, akinator: Akinator) -> Result<PooledGame> {
+        let mut game = self.acquire(akinator).await;
+        game.start().await?;
+
+        Ok(game)
+    }
+}
+
+impl PooledGame {
+    /// releases the game's slot in the pool and returns the underlying [`Akinator`]
+    #[must_use]
+    pub fn into_inner(self) -> Akinator {
+        self.akinator
+    }
+}
+
+impl Deref for PooledGame {
+    type Target = Akinator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.akinator
+    }
+}
+
+impl DerefMut for PooledGame {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.akinator
+    }
+}
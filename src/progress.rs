@@ -0,0 +1,84 @@
+//! An [indicatif](https://docs.rs/indicatif) progress bar following a game, for command line consumers
+//!
+//! Only available with the `indicatif` feature enabled.
+//! [`crate::Akinator::progress_bar`] returns a bar kept up to date from the game's events (see [`crate::events`]):
+//! its position is the akinator's progression, its prefix the current step and its message the current question.
+//!
+//! ```no_run
+//! # async fn run() -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{Akinator, enums::Answer};
+//!
+//! let mut akinator = Akinator::new()?;
+//! let bar = akinator.progress_bar();
+//!
+//! akinator.start().await?;
+//! akinator.answer(Answer::Yes).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use indicatif::{ProgressBar, ProgressStyle};
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    task::JoinHandle,
+};
+
+use crate::events::GameEvent;
+
+
+/// the template of the bar returned by [`progress_bar`]
+const TEMPLATE: &str = "{spinner} step {prefix:>2} [{bar:30}] {pos:>3}% {wide_msg}";
+
+/// returns a new progress bar out of 100, styled to show the step, progression and question of a game
+#[must_use]
+pub fn progress_bar() -> ProgressBar {
+    let style = ProgressStyle::with_template(TEMPLATE)
+        .unwrap_or_else(|_| ProgressStyle::default_bar())
+        .progress_chars("=> ");
+
+    ProgressBar::new(100).with_style(style)
+}
+
+/// updates `bar` with `event`:
+/// questions set its prefix to their step and its message to their text,
+/// progression changes set its position, and guesses finish it with the name of the best guess
+pub fn apply(bar: &ProgressBar, event: &GameEvent) {
+    match event {
+        GameEvent::QuestionAsked { question } => {
+            bar.set_prefix((question.step + 1).to_string());
+            bar.set_position(percentage(question.progression.value()));
+            bar.set_message(question.text.clone());
+        },
+        GameEvent::ProgressChanged { to, .. } => bar.set_position(percentage(to.value())),
+        GameEvent::GuessesReady { guesses } => match guesses.first() {
+            Some(guess) => bar.finish_with_message(guess.name.clone()),
+            None => bar.finish_with_message("no guess"),
+        },
+        GameEvent::Errored { message } => bar.set_message(format!("error: {message}")),
+        GameEvent::Answered { .. } => bar.tick(),
+    }
+}
+
+/// Spawns a task on the current tokio runtime applying the events received from `events` to `bar`, see [`apply`]
+///
+/// the task stops once the game is dropped, leaving the bar as it is, or once the bar is finished
+///
+/// # Panics
+/// If called outside of a tokio runtime
+pub fn track(bar: ProgressBar, mut events: broadcast::Receiver<GameEvent>) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while !bar.is_finished() {
+            match events.recv().await {
+                Ok(event) => apply(&bar, &event),
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+/// internal function converting a progression to the position of the bar
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn percentage(progression: f32) -> u64 {
+    progression.round().clamp(0.0, 100.0) as u64
+}
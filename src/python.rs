@@ -0,0 +1,186 @@
+//! Python bindings built with [`pyo3`]
+//!
+//! Only available with the `python` feature enabled, build the python module with `maturin build --features python`.
+//!
+//! [`Answer`], [`Theme`], [`Language`] and [`Guess`] are exposed as python classes as-is,
+//! while the game itself is exposed through [`PyAkinator`], whose game methods return awaitables.
+
+use std::sync::Arc;
+
+use pyo3::{
+    prelude::*,
+    create_exception,
+    exceptions::PyException,
+};
+use tokio::sync::Mutex;
+
+use crate::{
+    Akinator,
+    enums::{Answer, Language, Theme},
+    error::Error,
+    models::Guess,
+};
+
+
+create_exception!(akinator_rs, AkinatorError, PyException, "Base exception for errors returned from the akinator");
+create_exception!(akinator_rs, NoMoreQuestions, AkinatorError, "There are no more available questions");
+create_exception!(
+    akinator_rs,
+    CantGoBackAnyFurther,
+    AkinatorError,
+    "Cannot go back any further, you are already on the first question"
+);
+create_exception!(
+    akinator_rs,
+    GameBusy,
+    AkinatorError,
+    "The game is busy with a pending call, await it before reading the game's state"
+);
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::NoMoreQuestions => NoMoreQuestions::new_err(err.to_string()),
+            Error::CantGoBackAnyFurther => CantGoBackAnyFurther::new_err(err.to_string()),
+            err => AkinatorError::new_err(err.to_string()),
+        }
+    }
+}
+
+/// Represents an akinator game, exposed to python as `Akinator`
+///
+/// its attributes cannot be read while a call on the game is pending, raising `GameBusy` instead of blocking
+#[pyclass(name = "Akinator", module = "akinator_rs")]
+pub struct PyAkinator {
+    /// the wrapped akinator game, shared with the futures returned to python
+    inner: Arc<Mutex<Akinator>>,
+}
+
+impl PyAkinator {
+    /// internal method used to read a value from the wrapped akinator without blocking,
+    /// raising `GameBusy` if a call on the game is still pending
+    fn read<T>(&self, f: impl FnOnce(&Akinator) -> T) -> PyResult<T> {
+        let akinator = self.inner
+            .try_lock()
+            .map_err(|_| GameBusy::new_err("the game is busy with a pending call, await it first"))?;
+
+        Ok(f(&akinator))
+    }
+}
+
+#[pymethods]
+impl PyAkinator {
+    #[new]
+    #[pyo3(signature = (language = None, theme = None, child_mode = false))]
+    fn new(language: Option<Language>, theme: Option<Theme>, child_mode: bool) -> PyResult<Self> {
+        let mut akinator = Akinator::new()?
+            .with_language(language.unwrap_or_default())
+            .with_theme(theme.unwrap_or_default());
+        akinator.child_mode = child_mode;
+
+        Ok(Self { inner: Arc::new(Mutex::new(akinator)) })
+    }
+
+    /// Starts the akinator game and returns the first question
+    fn start<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            Ok(inner.lock().await.start().await?)
+        })
+    }
+
+    /// answers the akinator's current question and returns the next question
+    fn answer<'py>(&self, py: Python<'py>, answer: Answer) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            Ok(inner.lock().await.answer(answer).await?)
+        })
+    }
+
+    /// Goes back 1 question and returns the current question
+    fn back<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            Ok(inner.lock().await.back().await?)
+        })
+    }
+
+    /// tells the akinator to end the game and make it's guess, returning its best guess
+    fn win<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let inner = Arc::clone(&self.inner);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            Ok(inner.lock().await.win().await?.first)
+        })
+    }
+
+    /// the language for the akinator session
+    #[getter]
+    fn language(&self) -> PyResult<Language> {
+        self.read(|akinator| akinator.language)
+    }
+
+    /// the theme for the akinator session
+    #[getter]
+    fn theme(&self) -> PyResult<Theme> {
+        self.read(|akinator| akinator.theme)
+    }
+
+    /// whether or not NSFW questions and content are filtered out
+    #[getter]
+    fn child_mode(&self) -> PyResult<bool> {
+        self.read(|akinator| akinator.child_mode)
+    }
+
+    /// the current question to answer
+    #[getter]
+    fn current_question(&self) -> PyResult<Option<String>> {
+        self.read(|akinator| akinator.current_question.clone())
+    }
+
+    /// the progress of the akinator, a float out of 100.0
+    #[getter]
+    fn progression(&self) -> PyResult<f32> {
+        self.read(|akinator| akinator.progression.value())
+    }
+
+    /// a counter of questions asked and answered, starts at 0
+    #[getter]
+    fn step(&self) -> PyResult<usize> {
+        self.read(|akinator| akinator.step)
+    }
+
+    /// the akinator's best guess, only set once `win` has been called
+    #[getter]
+    fn first_guess(&self) -> PyResult<Option<Guess>> {
+        self.read(|akinator| akinator.first_guess.clone())
+    }
+
+    /// all the possible guesses by the akinator, only set once `win` has been called
+    #[getter]
+    fn guesses(&self) -> PyResult<Vec<Guess>> {
+        self.read(|akinator| akinator.guesses.to_vec())
+    }
+}
+
+/// the `akinator_rs` python module
+#[pymodule]
+fn akinator_rs(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = module.py();
+
+    module.add_class::<PyAkinator>()?;
+    module.add_class::<Answer>()?;
+    module.add_class::<Theme>()?;
+    module.add_class::<Language>()?;
+    module.add_class::<Guess>()?;
+
+    module.add("AkinatorError", py.get_type::<AkinatorError>())?;
+    module.add("NoMoreQuestions", py.get_type::<NoMoreQuestions>())?;
+    module.add("CantGoBackAnyFurther", py.get_type::<CantGoBackAnyFurther>())?;
+    module.add("GameBusy", py.get_type::<GameBusy>())?;
+
+    Ok(())
+}
@@ -0,0 +1,301 @@
+//! A registry owning many akinator games keyed by an arbitrary key, such as a user id
+//!
+//! Only available with the `registry` feature enabled.
+//! Intended for bots juggling one game per user, idle games are automatically expired after a time-to-live.
+//! Games can also be paused into a [`SessionStore`] and resumed later, see [`GameRegistry::pause`].
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    hash::Hash,
+    sync::{Arc, Mutex as StdMutex, Weak},
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    sync::Mutex,
+    task::JoinHandle,
+};
+
+use crate::{
+    Akinator,
+    error::Result,
+    save::SESSION_LIFETIME,
+    store::{MemoryStore, SessionStore},
+};
+
+
+/// an akinator game owned by a [`GameRegistry`], shareable across tasks
+pub type SharedGame = Arc<Mutex<Akinator>>;
+
+/// the hook called with the key and game of every game evicted from a [`GameRegistry`]
+type EvictionHook<K> = Box<dyn Fn(&K, SharedGame) + Send + Sync>;
+
+/// a game stored in the registry alongside when it was last accessed
+struct Entry {
+    /// the stored game
+    game: SharedGame,
+    /// when the game was last inserted or retrieved
+    last_used: Instant,
+}
+
+/// A concurrency-safe registry of akinator games keyed by `K`,
+/// expiring games that have been idle for longer than its time-to-live
+///
+/// expired games are evicted lazily when accessed, with [`Self::evict_expired`],
+/// or periodically with [`Self::spawn_eviction_task`]
+pub struct GameRegistry<K> {
+    /// the stored games, keyed by `K`
+    games: StdMutex<HashMap<K, Entry>>,
+    /// how long a game can stay idle before being expired
+    ttl: Duration,
+    /// the hooks called whenever a game is evicted
+    hooks: Vec<EvictionHook<K>>,
+    /// the store paused games are moved to, see [`Self::pause`]
+    store: Arc<dyn SessionStore>,
+}
+
+impl<K> std::fmt::Debug for GameRegistry<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GameRegistry")
+            .field("ttl", &self.ttl)
+            .field("hooks", &self.hooks.len())
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K: Eq + Hash + Clone> GameRegistry<K> {
+    /// Creates a new, empty [`GameRegistry`] expiring games that have been idle for longer than `ttl`
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            games: StdMutex::new(HashMap::new()),
+            ttl,
+            hooks: Vec::new(),
+            store: Arc::new(MemoryStore::new()),
+        }
+    }
+
+    /// builder method setting the store paused games are moved to, see [`Self::pause`],
+    /// defaults to a [`MemoryStore`]
+    #[must_use]
+    pub fn with_store(mut self, store: Arc<dyn SessionStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// returns the store paused games are moved to
+    #[must_use]
+    pub const fn store(&self) -> &Arc<dyn SessionStore> {
+        &self.store
+    }
+
+    /// builder method adding a hook called with the key and game of every evicted game,
+    /// whether it expired or was removed with [`Self::remove`]
+    #[must_use]
+    pub fn with_eviction_hook(mut self, hook: impl Fn(&K, SharedGame) + Send + Sync + 'static) -> Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// returns the time-to-live of idle games
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// internal method used to lock the stored games, recovering from a poisoned lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<K, Entry>> {
+        self.games
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// internal method calling the eviction hooks for each of the `evicted` games
+    fn run_hooks(&self, evicted: Vec<(K, SharedGame)>) {
+        for (key, game) in evicted {
+            for hook in &self.hooks {
+                hook(&key, Arc::clone(&game));
+            }
+        }
+    }
+
+    /// Inserts a game for `key`, returning the shared game
+    ///
+    /// any game previously stored for `key` is evicted
+    pub fn insert(&self, key: K, akinator: Akinator) -> SharedGame {
+        let game = Arc::new(Mutex::new(akinator));
+
+        let previous = self.lock()
+            .insert(key.clone(), Entry {
+                game: Arc::clone(&game),
+                last_used: Instant::now(),
+            });
+
+        if let Some(previous) = previous {
+            self.run_hooks(vec![(key, previous.game)]);
+        }
+        game
+    }
+
+    /// Returns the game stored for `key`, refreshing its idle time
+    ///
+    /// returns [`None`] if there is no game for `key` or if it has expired, evicting it
+    pub fn get(&self, key: &K) -> Option<SharedGame> {
+        let mut games = self.lock();
+        let entry = games.get_mut(key)?;
+
+        if entry.last_used.elapsed() > self.ttl {
+            let expired = games.remove(key)?;
+            drop(games);
+
+            self.run_hooks(vec![(key.clone(), expired.game)]);
+            return None;
+        }
+
+        entry.last_used = Instant::now();
+        Some(Arc::clone(&entry.game))
+    }
+
+    /// Returns the game stored for `key`, or inserts the game created by `create` if there is none
+    ///
+    /// # Errors
+    /// If `create` fails
+    pub fn get_or_try_insert_with<E>(
+        &self,
+        key: K,
+        create: impl FnOnce() -> Result<Akinator, E>,
+    ) -> Result<SharedGame, E> {
+        if let Some(game) = self.get(&key) {
+            return Ok(game);
+        }
+
+        Ok(self.insert(key, create()?))
+    }
+
+    /// Removes and returns the game stored for `key`, calling the eviction hooks
+    pub fn remove(&self, key: &K) -> Option<SharedGame> {
+        let entry = self.lock().remove(key)?;
+
+        self.run_hooks(vec![(key.clone(), Arc::clone(&entry.game))]);
+        Some(entry.game)
+    }
+
+    /// returns whether or not there is a game stored for `key`, regardless of whether it has expired
+    #[must_use]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.lock().contains_key(key)
+    }
+
+    /// returns the amount of stored games, including ones that have expired but not yet been evicted
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// returns whether or not there are no stored games
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Evicts every game that has been idle for longer than the time-to-live,
+    /// returning the amount of evicted games
+    pub fn evict_expired(&self) -> usize {
+        let evicted = {
+            let mut games = self.lock();
+
+            let expired = games
+                .iter()
+                .filter(|(_, entry)| entry.last_used.elapsed() > self.ttl)
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<K>>();
+
+            expired
+                .into_iter()
+                .filter_map(|key| {
+                    games.remove(&key)
+                        .map(|entry| (key, entry.game))
+                })
+                .collect::<Vec<(K, SharedGame)>>()
+        };
+
+        let count = evicted.len();
+        self.run_hooks(evicted);
+
+        count
+    }
+}
+
+impl<K: Eq + Hash + Clone + Display> GameRegistry<K> {
+    /// Pauses the game stored for `key`, moving its saved state to the registry's store
+    /// under the key's string representation until its session expires, see [`SESSION_LIFETIME`]
+    ///
+    /// the game is removed without calling the eviction hooks,
+    /// returns whether or not there was a game for `key`
+    ///
+    /// # Errors
+    /// If saving the game's state or storing it fails, in which case the game is kept in the registry
+    pub async fn pause(&self, key: &K) -> Result<bool> {
+        let Some(game) = self.lock().get(key).map(|entry| Arc::clone(&entry.game)) else {
+            return Ok(false);
+        };
+
+        let state = game.lock().await.save_state()?;
+        self.store.put(&key.to_string(), &state, SESSION_LIFETIME).await?;
+        self.lock().remove(key);
+
+        Ok(true)
+    }
+
+    /// Returns the game stored for `key`, or resumes the game paused with [`Self::pause`],
+    /// moving it from the registry's store back into the registry
+    ///
+    /// resumed games are restored with [`Akinator::from_state`],
+    /// so their HTTP client and other non-saved configuration are recreated from their defaults.
+    /// returns [`None`] if there is no running nor paused game for `key`
+    ///
+    /// # Errors
+    /// If getting the game from the store, restoring or deleting it fails
+    pub async fn resume(&self, key: &K) -> Result<Option<SharedGame>> {
+        if let Some(game) = self.get(key) {
+            return Ok(Some(game));
+        }
+
+        let store_key = key.to_string();
+        let Some(state) = self.store.get(&store_key).await? else {
+            return Ok(None);
+        };
+
+        let akinator = Akinator::from_state(state)?;
+        self.store.delete(&store_key).await?;
+
+        Ok(Some(self.insert(key.clone(), akinator)))
+    }
+}
+
+impl<K: Eq + Hash + Clone + Send + 'static> GameRegistry<K> {
+    /// Spawns a task on the current tokio runtime calling [`Self::evict_expired`] every `interval`
+    ///
+    /// the task stops on its own once the registry has been dropped
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime
+    pub fn spawn_eviction_task(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let registry: Weak<Self> = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+
+            loop {
+                ticker.tick().await;
+
+                match registry.upgrade() {
+                    Some(registry) => { registry.evict_expired(); },
+                    None => break,
+                }
+            }
+        })
+    }
+}
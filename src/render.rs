@@ -0,0 +1,100 @@
+//! Helpers rendering guesses and questions as Markdown or minimal HTML snippets
+//!
+//! every piece of text coming from the API is escaped, and only `http` and `https` picture URLs are kept,
+//! so the output can be embedded as is in chat messages or web pages.
+//!
+//! ```
+//! use akinator_rs::{models::Question, render};
+//!
+//! let question = Question { text: "Is your character <real>?".to_string(), step: 0, progression: 0.0.into() };
+//!
+//! assert_eq!(render::question_markdown(&question), "**Question 1:** Is your character \\<real\\>?");
+//! assert_eq!(render::question_html(&question), "<p><strong>Question 1:</strong> Is your character &lt;real&gt;?</p>");
+//! ```
+
+use reqwest::Url;
+
+use crate::{
+    enums::ImageSize,
+    models::{Guess, Question},
+};
+
+
+/// the characters escaped by [`escape_markdown`]
+const MARKDOWN_SPECIAL: &str = "\\`*_{}[]()<>#+-.!|~";
+
+/// escapes the characters of `text` Markdown would interpret, ex: `*` and `_`
+#[must_use]
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if MARKDOWN_SPECIAL.contains(char) {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+
+    escaped
+}
+
+/// escapes `text` to be embedded in HTML, either as text or within a double-quoted attribute
+#[must_use]
+pub fn escape_html(text: &str) -> String {
+    html_escape::encode_quoted_attribute(text).into_owned()
+}
+
+/// renders `question` as Markdown: its step in bold followed by its text
+#[must_use]
+pub fn question_markdown(question: &Question) -> String {
+    format!("**Question {}:** {}", question.step + 1, escape_markdown(&question.text))
+}
+
+/// renders `question` as an HTML paragraph: its step in bold followed by its text
+#[must_use]
+pub fn question_html(question: &Question) -> String {
+    format!("<p><strong>Question {}:</strong> {}</p>", question.step + 1, escape_html(&question.text))
+}
+
+/// renders `guess` as Markdown: its name in bold, its description, the akinator's confidence and its picture
+/// at [`ImageSize::Medium`], if it has one
+#[must_use]
+pub fn guess_markdown(guess: &Guess) -> String {
+    let mut markdown = format!(
+        "**{}**\n{}\nConfidence: {:.1}%",
+        escape_markdown(&guess.name),
+        escape_markdown(&guess.description),
+        guess.probability() * 100.0,
+    );
+
+    if let Some(url) = picture_url(guess) {
+        let url = url.replace('(', "%28").replace(')', "%29");
+        markdown.push_str(&format!("\n![{}]({url})", escape_markdown(&guess.name)));
+    }
+
+    markdown
+}
+
+/// renders `guess` as an HTML `<figure>`: its picture at [`ImageSize::Medium`] if it has one,
+/// then its name, description and the akinator's confidence
+#[must_use]
+pub fn guess_html(guess: &Guess) -> String {
+    let name = escape_html(&guess.name);
+    let image = picture_url(guess)
+        .map(|url| format!("<img src=\"{}\" alt=\"{name}\">", escape_html(&url)))
+        .unwrap_or_default();
+
+    format!(
+        "<figure>{image}<figcaption><strong>{name}</strong> <span>{}</span> <small>Confidence: {:.1}%</small></figcaption></figure>",
+        escape_html(&guess.description),
+        guess.probability() * 100.0,
+    )
+}
+
+/// internal function returning the URL of the picture of `guess`, dropping URLs which are not `http` or `https`
+fn picture_url(guess: &Guess) -> Option<String> {
+    Url::parse(&guess.picture_url(ImageSize::Medium))
+        .ok()
+        .filter(|url| matches!(url.scheme(), "http" | "https"))
+        .map(String::from)
+}
@@ -0,0 +1,55 @@
+//! The policy for automatically retrying rate limited requests, see [`crate::Akinator::with_retry_policy`]
+
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+
+/// a policy for automatically waiting and retrying requests rate limited by the akinator servers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// the max amount of times a single request is retried
+    pub max_retries: usize,
+    /// the delay to wait before retrying when the server does not send a `Retry-After` header
+    pub default_delay: Duration,
+    /// the max delay to wait before retrying, longer `Retry-After` delays are capped to this
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            default_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// returns the delay to wait before retrying, given the server's `Retry-After` delay
+    #[must_use]
+    pub fn delay(&self, retry_after: Option<Duration>) -> Duration {
+        retry_after
+            .unwrap_or(self.default_delay)
+            .min(self.max_delay)
+    }
+}
+
+/// internal function parsing the `Retry-After` header,
+/// either a number of seconds or an HTTP date
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    value.parse::<u64>().map_or_else(
+        |_| {
+            httpdate::parse_http_date(value)
+                .ok()
+                .map(|date| date.duration_since(SystemTime::now()).unwrap_or_default())
+        },
+        |seconds| Some(Duration::from_secs(seconds)),
+    )
+}
@@ -0,0 +1,147 @@
+//! Serializable snapshots of a game's full state, for pausing games and resuming them later,
+//! see [`crate::Akinator::save_state`] and [`crate::Akinator::save_to_file`]
+
+use std::{
+    collections::HashSet,
+    fmt,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    enums::{GameState, Language, Theme},
+    error::Result,
+    models::{Guess, GuessList},
+    transcript::TranscriptStep,
+};
+
+
+/// the estimated time an idle akinator session stays alive on the server
+///
+/// the API has no endpoint to terminate a session, so abandoned games are only cleaned up
+/// by the server once they have been idle for this long.
+/// saved games older than this are rejected by [`crate::Akinator::load_from_file`]
+pub const SESSION_LIFETIME: Duration = Duration::from_secs(30 * 60);
+
+
+/// the function called by a [`StateChangeHook`]
+type StateChangeFn = dyn Fn(&[u8]) + Send + Sync;
+
+/// a hook called with the JSON serialized state of a game after each of its steps,
+/// see [`crate::Akinator::on_state_change`]
+#[derive(Clone)]
+pub(crate) struct StateChangeHook(pub(crate) Arc<StateChangeFn>);
+
+impl fmt::Debug for StateChangeHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StateChangeHook")
+    }
+}
+
+/// internal function returning the partner id of saves from before it was configurable
+const fn default_partner() -> usize {
+    crate::DEFAULT_PARTNER
+}
+
+
+/// the full state of a game: its configuration, session data and progress
+///
+/// the HTTP client, cookies and cancellation token are not saved,
+/// and are recreated when restoring the game with [`crate::Akinator::from_state`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedGame {
+    /// the POSIX timestamp the state was saved at
+    pub(crate) saved_at: u64,
+
+    pub(crate) language: Language,
+    pub(crate) theme: Theme,
+    pub(crate) child_mode: bool,
+    #[serde(default)]
+    pub(crate) nsfw_filter: bool,
+    pub(crate) guess_list_size: Option<usize>,
+    #[serde(default)]
+    pub(crate) min_confidence: Option<f64>,
+    pub(crate) max_pic_size: Option<(usize, usize)>,
+    #[serde(default)]
+    pub(crate) custom_question_filter: Option<String>,
+    #[serde(default = "default_partner")]
+    pub(crate) partner: usize,
+    #[serde(default)]
+    pub(crate) base_url: Option<String>,
+    #[serde(default)]
+    pub(crate) custom_ws_url: Option<String>,
+
+    pub(crate) timestamp: u64,
+    #[serde(default)]
+    pub(crate) callback: Option<String>,
+    pub(crate) uri: String,
+    pub(crate) uid: Option<String>,
+    pub(crate) ws_url: Option<String>,
+    pub(crate) session: Option<usize>,
+    pub(crate) frontaddr: Option<String>,
+    pub(crate) signature: Option<usize>,
+    pub(crate) question_filter: Option<String>,
+
+    pub(crate) current_question: Option<String>,
+    pub(crate) progression: f32,
+    pub(crate) step: usize,
+    pub(crate) first_question: Option<String>,
+    pub(crate) history: Vec<TranscriptStep>,
+    pub(crate) last_guess_step: usize,
+    #[serde(default)]
+    pub(crate) progression_history: Vec<f32>,
+    #[serde(default)]
+    pub(crate) rejected_guesses: HashSet<String>,
+    pub(crate) first_guess: Option<Guess>,
+    pub(crate) guesses: GuessList,
+    #[serde(default)]
+    pub(crate) state: Option<GameState>,
+    #[serde(default)]
+    pub(crate) game_id: Option<String>,
+}
+
+impl SavedGame {
+    /// returns the POSIX timestamp the state was saved at
+    #[must_use]
+    pub const fn saved_at(&self) -> u64 {
+        self.saved_at
+    }
+
+    /// returns whether or not the saved game was started, and so has a session on the server
+    #[must_use]
+    pub const fn is_started(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// returns whether or not the saved game's session has likely expired on the server,
+    /// as it was saved more than [`SESSION_LIFETIME`] ago
+    ///
+    /// games that were never started never expire
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+
+        self.is_started()
+            && now.saturating_sub(self.saved_at) > SESSION_LIFETIME.as_secs()
+    }
+
+    /// serializes the saved game into a JSON string
+    ///
+    /// # Errors
+    /// If serialization fails
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    /// deserializes a saved game from a JSON string
+    ///
+    /// # Errors
+    /// If deserialization fails
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
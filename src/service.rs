@@ -0,0 +1,144 @@
+//! A [`tower::Service`] playing a game, taking typed commands and responding with typed outcomes
+//!
+//! Only available with the `tower` feature enabled.
+//! Lets a game be composed with tower layers in server deployments, ex: load shedding, buffering or timeouts.
+//! Commands are processed one at a time, callers wait their turn for the game.
+//!
+//! ```no_run
+//! # async fn run() -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{Akinator, enums::Answer, service::{AkinatorService, Command}};
+//! use tower::ServiceExt;
+//!
+//! let service = AkinatorService::new(Akinator::new()?);
+//!
+//! service.clone().oneshot(Command::Start).await?;
+//! let outcome = service.oneshot(Command::Answer(Answer::Yes)).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use tokio::sync::Mutex;
+
+use crate::{
+    Akinator,
+    enums::Answer,
+    error::{Error, Result},
+    models::{GuessList, Question, StepOutcome, WinResult},
+};
+
+
+/// the future returned by [`AkinatorService`]
+pub type ServiceFuture = Pin<Box<dyn Future<Output = Result<Outcome>> + Send>>;
+
+/// a command sent to an [`AkinatorService`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// starts the game, see [`Akinator::start`]
+    Start,
+    /// answers the current question, see [`Akinator::answer_step`]
+    Answer(Answer),
+    /// goes back to the previous question, see [`Akinator::back`]
+    Back,
+    /// ends the game and makes the akinator guess, see [`Akinator::win`]
+    Win,
+    /// fetches the akinator's next page of guesses, see [`Akinator::more_guesses`]
+    MoreGuesses,
+    /// ends the game, see [`Akinator::reset`]
+    Reset,
+}
+
+/// the outcome of a [`Command`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// the question asked after [`Command::Start`] or [`Command::Back`]
+    Question(Question),
+    /// the outcome of [`Command::Answer`]
+    Step(StepOutcome),
+    /// the guesses made after [`Command::Win`]
+    Guesses(WinResult),
+    /// the next page of guesses fetched after [`Command::MoreGuesses`]
+    MoreGuesses(GuessList),
+    /// the game was reset after [`Command::Reset`]
+    Reset,
+}
+
+/// A [`tower::Service`] playing an akinator game, see the [module](self) docs
+///
+/// cloning the service is cheap and shares the same game
+#[derive(Debug, Clone)]
+pub struct AkinatorService {
+    /// the game played by the service
+    game: Arc<Mutex<Akinator>>,
+}
+
+impl AkinatorService {
+    /// Creates a new [`AkinatorService`] playing `akinator`
+    #[must_use]
+    pub fn new(akinator: Akinator) -> Self {
+        Self { game: Arc::new(Mutex::new(akinator)) }
+    }
+
+    /// returns the game played by the service, ex: to read its fields
+    #[must_use]
+    pub const fn game(&self) -> &Arc<Mutex<Akinator>> {
+        &self.game
+    }
+}
+
+impl From<Akinator> for AkinatorService {
+    fn from(akinator: Akinator) -> Self {
+        Self::new(akinator)
+    }
+}
+
+impl tower::Service<Command> for AkinatorService {
+    type Response = Outcome;
+    type Error = Error;
+    type Future = ServiceFuture;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, command: Command) -> Self::Future {
+        let game = Arc::clone(&self.game);
+
+        Box::pin(async move {
+            let mut akinator = game.lock_owned().await;
+
+            Ok(match command {
+                Command::Start => {
+                    akinator.start().await?;
+                    Outcome::Question(question(&akinator))
+                },
+                Command::Answer(answer) => Outcome::Step(akinator.answer_step(answer).await?),
+                Command::Back => {
+                    akinator.back().await?;
+                    Outcome::Question(question(&akinator))
+                },
+                Command::Win => Outcome::Guesses(akinator.win().await?),
+                Command::MoreGuesses => Outcome::MoreGuesses(akinator.more_guesses().await?),
+                Command::Reset => {
+                    akinator.reset();
+                    Outcome::Reset
+                },
+            })
+        })
+    }
+}
+
+/// internal function returning the question currently asked in `akinator`
+fn question(akinator: &Akinator) -> Question {
+    Question {
+        text: akinator.current_question.clone().unwrap_or_default(),
+        step: akinator.step,
+        progression: akinator.progression,
+    }
+}
@@ -0,0 +1,255 @@
+//! A batch simulation runner, playing many games unattended and aggregating statistics about them
+//!
+//! Only available with the `simulate` feature enabled. Useful for load testing and validating the crate against the live API.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex as StdMutex},
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    sync::Semaphore,
+    task::JoinSet,
+};
+
+use crate::{
+    Akinator,
+    DEFAULT_WIN_THRESHOLD,
+    enums::{Language, Theme},
+    error::Result,
+    strategy::AnswerStrategy,
+    transport::{RequestLog, ResponseLog},
+};
+
+
+/// the configuration of a batch of simulated games
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    /// the language for the simulated games
+    pub language: Language,
+    /// the theme for the simulated games
+    pub theme: Theme,
+    /// whether or not to filter out NSFW questions and content
+    pub child_mode: bool,
+    /// the max amount of games played concurrently
+    pub concurrency: usize,
+    /// the minimum delay between starting two games, to avoid flooding the akinator servers
+    pub start_interval: Duration,
+    /// the progression at which a game stops answering and makes the akinator guess
+    pub win_threshold: f32,
+    /// the base URL to send the games' requests to instead of the akinator website, see [`Akinator::with_base_url`]
+    pub base_url: Option<String>,
+    /// the URL to send the games' API requests to instead of the server found on the homepage,
+    /// see [`Akinator::with_ws_url`]
+    pub ws_url: Option<String>,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            theme: Theme::default(),
+            child_mode: false,
+            concurrency: 4,
+            start_interval: Duration::from_millis(250),
+            win_threshold: DEFAULT_WIN_THRESHOLD,
+            base_url: None,
+            ws_url: None,
+        }
+    }
+}
+
+/// percentiles of the latencies of every request made during a batch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LatencyStats {
+    /// the median latency
+    pub p50: Duration,
+    /// the 90th percentile latency
+    pub p90: Duration,
+    /// the 99th percentile latency
+    pub p99: Duration,
+    /// the highest latency
+    pub max: Duration,
+}
+
+impl LatencyStats {
+    /// computes the latency percentiles from a list of latencies
+    fn from_latencies(mut latencies: Vec<Duration>) -> Self {
+        latencies.sort_unstable();
+
+        let percentile = |p: usize| {
+            latencies
+                .get((latencies.len() * p / 100).min(latencies.len().saturating_sub(1)))
+                .copied()
+                .unwrap_or_default()
+        };
+
+        Self {
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: latencies.last().copied().unwrap_or_default(),
+        }
+    }
+}
+
+/// aggregated statistics about a batch of simulated games
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BatchStats {
+    /// the amount of games played
+    pub games: usize,
+    /// the amount of games that ended with the akinator making a guess
+    pub completed: usize,
+    /// the amount of failed games, keyed by the kind of error they failed with, see [`crate::error::Error::kind`]
+    pub failures: HashMap<&'static str, usize>,
+    /// the average amount of questions answered in completed games
+    pub average_steps: f64,
+    /// the latency percentiles of every request made
+    pub latency: LatencyStats,
+    /// how long the whole batch took
+    pub duration: Duration,
+}
+
+/// the outcome of a single simulated game
+struct GameOutcome {
+    /// the amount of questions answered, or the error the game failed with
+    result: Result<usize>,
+    /// the latencies of every request made
+    latencies: Vec<Duration>,
+}
+
+/// internal function playing a single game with `strategy` using [`Akinator::auto_play_until`],
+/// recording the latency of every request made
+async fn play_game(config: &SimulationConfig, strategy: impl AnswerStrategy) -> GameOutcome {
+    let latencies = Arc::new(StdMutex::new(Vec::new()));
+    let sink = Arc::clone(&latencies);
+
+    let result = async {
+        let mut akinator = Akinator::new()?
+            .with_language(config.language)
+            .with_theme(config.theme)
+            .with_http_logger(move |_: &RequestLog, response: &ResponseLog| {
+                lock(&sink).push(response.elapsed);
+            });
+        akinator.child_mode = config.child_mode;
+
+        if let Some(url) = &config.base_url {
+            akinator = akinator.with_base_url(url.clone());
+        }
+        if let Some(url) = &config.ws_url {
+            akinator = akinator.with_ws_url(url.clone());
+        }
+
+        akinator.auto_play_until(strategy, config.win_threshold).await?;
+
+        Ok(akinator.step)
+    }.await;
+
+    let latencies = std::mem::take(&mut *lock(&latencies));
+    GameOutcome { result, latencies }
+}
+
+/// internal function locking the recorded latencies, recovering from a poisoned lock
+fn lock(latencies: &StdMutex<Vec<Duration>>) -> std::sync::MutexGuard<'_, Vec<Duration>> {
+    latencies
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Plays `n` games concurrently, answering questions with the strategies created by `strategy`,
+/// and returns aggregated statistics about them
+///
+/// `strategy` is called with the index of each game to create its strategy,
+/// allowing for example differently seeded [`crate::strategy::Random`] strategies
+///
+/// # Panics
+/// If called outside of a tokio runtime, or if a game's task panics
+pub async fn run_batch<S, F>(n: usize, config: SimulationConfig, strategy: F) -> BatchStats
+where
+    S: AnswerStrategy + Send + 'static,
+    F: Fn(usize) -> S,
+{
+    let start = Instant::now();
+    let config = Arc::new(config);
+    let semaphore = Arc::new(Semaphore::new(config.concurrency.max(1)));
+
+    let mut ticker = tokio::time::interval(config.start_interval.max(Duration::from_nanos(1)));
+    let mut tasks = JoinSet::new();
+
+    for index in 0..n {
+        let permit = Arc::clone(&semaphore)
+            .acquire_owned()
+            .await
+            .expect("simulation semaphore should never be closed");
+
+        ticker.tick().await;
+
+        let config = Arc::clone(&config);
+        let strategy = strategy(index);
+
+        tasks.spawn(async move {
+            let outcome = play_game(&config, strategy).await;
+            drop(permit);
+
+            outcome
+        });
+    }
+
+    let mut stats = BatchStats { games: n, ..BatchStats::default() };
+    let mut latencies = Vec::new();
+    let mut total_steps = 0;
+
+    while let Some(outcome) = tasks.join_next().await {
+        let outcome = outcome.expect("simulated game task panicked");
+        latencies.extend(outcome.latencies);
+
+        match outcome.result {
+            Ok(steps) => {
+                stats.completed += 1;
+                total_steps += steps;
+            },
+            Err(err) => *stats.failures.entry(err.kind()).or_default() += 1,
+        }
+    }
+
+    if stats.completed > 0 {
+        stats.average_steps = total_steps as f64 / stats.completed as f64;
+    }
+    stats.latency = LatencyStats::from_latencies(latencies);
+    stats.duration = start.elapsed();
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::LatencyStats;
+
+    #[test]
+    /// the percentiles are picked from the sorted latencies, and default to zero without any latency
+    fn test_latency_percentiles() {
+        let latencies = (1..=100)
+            .rev()
+            .map(Duration::from_millis)
+            .collect();
+
+        assert_eq!(
+            LatencyStats::from_latencies(latencies),
+            LatencyStats {
+                p50: Duration::from_millis(51),
+                p90: Duration::from_millis(91),
+                p99: Duration::from_millis(100),
+                max: Duration::from_millis(100),
+            },
+        );
+
+        let single = LatencyStats::from_latencies(vec![Duration::from_millis(7)]);
+        assert_eq!(single.p50, Duration::from_millis(7));
+        assert_eq!(single.p99, Duration::from_millis(7));
+
+        assert_eq!(LatencyStats::from_latencies(Vec::new()), LatencyStats::default());
+    }
+}
@@ -0,0 +1,67 @@
+//! Per-game statistics for analytics dashboards, see [`crate::Akinator::statistics`]
+
+use std::time::Duration;
+
+use serde::{Serialize, Deserialize};
+
+use crate::enums::Answer;
+
+
+/// the amount of times each [`Answer`] was given during a game
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerCounts {
+    /// the amount of [`Answer::Yes`]
+    pub yes: usize,
+    /// the amount of [`Answer::No`]
+    pub no: usize,
+    /// the amount of [`Answer::Idk`]
+    pub idk: usize,
+    /// the amount of [`Answer::Probably`]
+    pub probably: usize,
+    /// the amount of [`Answer::ProbablyNot`]
+    pub probably_not: usize,
+}
+
+impl AnswerCounts {
+    /// returns the amount of times `answer` was given
+    #[must_use]
+    pub const fn get(&self, answer: Answer) -> usize {
+        match answer {
+            Answer::Yes => self.yes,
+            Answer::No => self.no,
+            Answer::Idk => self.idk,
+            Answer::Probably => self.probably,
+            Answer::ProbablyNot => self.probably_not,
+        }
+    }
+
+    /// returns the total amount of answers given
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.yes + self.no + self.idk + self.probably + self.probably_not
+    }
+
+    /// internal method counting one more `answer`
+    pub(crate) fn add(&mut self, answer: Answer) {
+        *match answer {
+            Answer::Yes => &mut self.yes,
+            Answer::No => &mut self.no,
+            Answer::Idk => &mut self.idk,
+            Answer::Probably => &mut self.probably,
+            Answer::ProbablyNot => &mut self.probably_not,
+        } += 1;
+    }
+}
+
+/// statistics about a single game
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameStatistics {
+    /// the wall-clock time elapsed since the game was started
+    pub duration: Duration,
+    /// the amount of times each answer was given, including answers later undone with [`crate::Akinator::back`]
+    pub answers: AnswerCounts,
+    /// the amount of times the game went back a question
+    pub backs: usize,
+    /// the amount of rate limited requests that were retried, see [`crate::Akinator::with_retry_policy`]
+    pub retries: usize,
+}
@@ -0,0 +1,137 @@
+//! Pluggable persistence backends for paused games, see [`SessionStore`]
+//!
+//! games are stored as [`SavedGame`]s under a string key, along with a time-to-live
+//! after which they are dropped, as their session expires on the server anyway, see [`crate::save::SESSION_LIFETIME`].
+//! used by the `GameRegistry` of the `registry` feature to pause and resume games, and by `akinator-server`
+//!
+//! [`MemoryStore`] keeps the games in memory, `RedisStore` (behind the `redis` feature) in Redis,
+//! `SqliteStore` (behind the `sqlite` feature) in an SQLite database,
+//! `FileStore` (behind the `file-store` feature) in a directory of JSON files, other backends can be plugged in by implementing [`SessionStore`]
+
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::Result,
+    save::SavedGame,
+};
+
+#[cfg(feature = "file-store")]
+mod file;
+#[cfg(feature = "file-store")]
+pub use self::file::FileStore;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "redis")]
+pub use self::redis::RedisStore;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+#[cfg(feature = "sqlite")]
+pub use self::sqlite::SqliteStore;
+
+
+/// the future returned by the methods of [`SessionStore`]
+pub type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+/// a persistence backend for paused games, storing them by key with a time-to-live
+///
+/// errors specific to the backend should be returned as [`crate::error::Error::StoreError`]
+pub trait SessionStore: fmt::Debug + Send + Sync {
+    /// returns the game stored under `key`, or [`None`] if there is none or it has expired
+    fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<SavedGame>>;
+
+    /// stores `game` under `key` for `ttl`, replacing any game already stored under it
+    fn put<'a>(&'a self, key: &'a str, game: &'a SavedGame, ttl: Duration) -> StoreFuture<'a, ()>;
+
+    /// deletes the game stored under `key`, if any
+    fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()>;
+}
+
+/// a game stored in a [`MemoryStore`], along with when it expires, [`None`] if never
+type StoredGame = (SavedGame, Option<Instant>);
+
+/// a [`SessionStore`] keeping the games in memory, lost once the process exits
+///
+/// expired games are dropped lazily when accessed, or with [`Self::evict_expired`]
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    /// the stored games, keyed by their key
+    games: Mutex<HashMap<String, StoredGame>>,
+}
+
+impl MemoryStore {
+    /// Creates a new, empty [`MemoryStore`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// internal method used to lock the stored games, recovering from a poisoned lock
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, StoredGame>> {
+        self.games
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// returns the amount of stored games, including ones that have expired but not yet been dropped
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// returns whether or not there are no stored games
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// drops every expired game, returning the amount of dropped games
+    pub fn evict_expired(&self) -> usize {
+        let mut games = self.lock();
+        let len = games.len();
+
+        let now = Instant::now();
+        games.retain(|_, (_, expires_at)| expires_at.is_none_or(|expires_at| expires_at > now));
+
+        len - games.len()
+    }
+}
+
+impl SessionStore for MemoryStore {
+    fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<SavedGame>> {
+        Box::pin(async move {
+            let mut games = self.lock();
+
+            match games.get(key) {
+                Some((_, Some(expires_at))) if *expires_at <= Instant::now() => {
+                    games.remove(key);
+                    Ok(None)
+                },
+                entry => Ok(entry.map(|(game, _)| game.clone())),
+            }
+        })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, game: &'a SavedGame, ttl: Duration) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            let expires_at = Instant::now().checked_add(ttl);
+
+            self.lock()
+                .insert(key.to_string(), (game.clone(), expires_at));
+            Ok(())
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            self.lock().remove(key);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,215 @@
+//! A [`SessionStore`] keeping each game in its own JSON file
+
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        Weak,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use serde::{Serialize, Deserialize};
+use tokio::{fs, task::JoinHandle};
+
+use crate::{
+    error::{Error, Result},
+    save::SavedGame,
+};
+use super::{SessionStore, StoreFuture};
+
+
+/// the extension of the files games are stored in
+const EXTENSION: &str = "json";
+
+/// the contents of the file a game is stored in
+#[derive(Serialize, Deserialize)]
+struct StoredFile {
+    /// the POSIX timestamp in milliseconds the game expires at, [`None`] if never
+    expires_at: Option<u64>,
+    /// the stored game
+    game: SavedGame,
+}
+
+/// a [`SessionStore`] keeping each game in its own JSON file in a directory,
+/// needing neither a database nor any extra dependency
+///
+/// Only available with the `file-store` feature enabled.
+/// Files are named after the percent-encoded key, and written to a temporary file first
+/// then renamed over the previous one, so a crash never leaves a half-written game behind.
+///
+/// expired games are dropped lazily when accessed, or with [`Self::evict_expired`]
+/// and [`Self::spawn_cleanup_task`]
+#[derive(Debug)]
+pub struct FileStore {
+    /// the directory the games are stored in
+    dir: PathBuf,
+    /// incremented to give every temporary file a unique name
+    writes: AtomicU64,
+}
+
+impl FileStore {
+    /// Creates a new [`FileStore`] storing games in `dir`, which is created on the first write if it does not exist
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            writes: AtomicU64::new(0),
+        }
+    }
+
+    /// returns the directory the games are stored in
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// internal method returning the path of the file the game stored under `key` is kept in
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.{EXTENSION}", utf8_percent_encode(key, NON_ALPHANUMERIC)))
+    }
+
+    /// internal method reading the file at `path`, returning [`None`] if it does not exist
+    async fn read(path: &Path) -> Result<Option<StoredFile>> {
+        match fs::read_to_string(path).await {
+            Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(store_error(err)),
+        }
+    }
+
+    /// internal method deleting the file at `path`, ignoring it if it was already deleted
+    async fn remove(path: &Path) -> Result<()> {
+        match fs::remove_file(path).await {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(store_error(err)),
+            _ => Ok(()),
+        }
+    }
+
+    /// drops every expired game, returning the amount of dropped games
+    ///
+    /// files that are not valid stored games are left untouched
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the directory cannot be read or a file cannot be deleted
+    pub async fn evict_expired(&self) -> Result<usize> {
+        let mut entries = match fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(store_error(err)),
+        };
+
+        let now = now_millis();
+        let mut evicted = 0;
+
+        while let Some(entry) = entries.next_entry().await.map_err(store_error)? {
+            let path = entry.path();
+
+            if path.extension().is_none_or(|extension| extension != EXTENSION) {
+                continue;
+            }
+
+            if let Ok(Some(StoredFile { expires_at: Some(expires_at), .. })) = Self::read(&path).await {
+                if expires_at <= now {
+                    Self::remove(&path).await?;
+                    evicted += 1;
+                }
+            }
+        }
+
+        Ok(evicted)
+    }
+
+    /// Spawns a task on the current tokio runtime calling [`Self::evict_expired`] every `interval`
+    ///
+    /// the task stops on its own once the store has been dropped, errors are ignored
+    ///
+    /// # Panics
+    /// If called outside of a tokio runtime
+    pub fn spawn_cleanup_task(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let store: Weak<Self> = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+
+            loop {
+                ticker.tick().await;
+
+                match store.upgrade() {
+                    Some(store) => { let _ = store.evict_expired().await; },
+                    None => break,
+                }
+            }
+        })
+    }
+}
+
+impl SessionStore for FileStore {
+    fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<SavedGame>> {
+        Box::pin(async move {
+            let path = self.path(key);
+
+            match Self::read(&path).await? {
+                Some(StoredFile { expires_at: Some(expires_at), .. }) if expires_at <= now_millis() => {
+                    Self::remove(&path).await?;
+                    Ok(None)
+                },
+                file => Ok(file.map(|file| file.game)),
+            }
+        })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, game: &'a SavedGame, ttl: Duration) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            let expires_at = u64::try_from(ttl.as_millis())
+                .ok()
+                .and_then(|ttl| now_millis().checked_add(ttl));
+
+            let json = serde_json::to_vec(&StoredFile { expires_at, game: game.clone() })?;
+
+            fs::create_dir_all(&self.dir)
+                .await
+                .map_err(store_error)?;
+
+            let path = self.path(key);
+            let temp_path = path.with_extension(format!(
+                "{EXTENSION}.{}-{}.tmp",
+                std::process::id(),
+                self.writes.fetch_add(1, Ordering::Relaxed),
+            ));
+
+            fs::write(&temp_path, json)
+                .await
+                .map_err(store_error)?;
+
+            if let Err(err) = fs::rename(&temp_path, &path).await {
+                let _ = fs::remove_file(&temp_path).await;
+                return Err(store_error(err));
+            }
+
+            Ok(())
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            Self::remove(&self.path(key)).await
+        })
+    }
+}
+
+/// internal function returning the current POSIX timestamp in milliseconds,
+/// as expiry times outlive the process unlike [`std::time::Instant`]s
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// internal function wrapping an [`std::io::Error`] into an [`Error::StoreError`]
+fn store_error(err: std::io::Error) -> Error {
+    Error::StoreError(Box::new(err))
+}
@@ -0,0 +1,122 @@
+//! A [`SessionStore`] backed by Redis
+
+use std::{fmt, time::Duration};
+
+use ::redis::{AsyncCommands, Client, RedisError, aio::ConnectionManager};
+
+use crate::{
+    error::{Error, Result},
+    save::SavedGame,
+};
+use super::{SessionStore, StoreFuture};
+
+
+/// a [`SessionStore`] storing games as JSON strings in Redis, expiring with Redis' own TTLs
+///
+/// Only available with the `redis` feature enabled.
+/// Lets several processes share paused games, ex: bot shards or servers behind a load balancer.
+/// cloning the store is cheap and shares the same connection
+#[derive(Clone)]
+pub struct RedisStore {
+    /// the multiplexed connection, reconnecting automatically
+    connection: ConnectionManager,
+    /// the prefix prepended to every key
+    prefix: String,
+}
+
+impl fmt::Debug for RedisStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisStore")
+            .field("prefix", &self.prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RedisStore {
+    /// the prefix of the keys games are stored under when not set with [`Self::with_prefix`]
+    pub const DEFAULT_PREFIX: &'static str = "akinator:";
+
+    /// Connects to the Redis server at `url`, ex: `redis://127.0.0.1:6379`
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the URL is invalid or connecting fails
+    pub async fn connect(url: &str) -> Result<Self> {
+        let client = Client::open(url).map_err(store_error)?;
+        let connection = client.get_connection_manager()
+            .await
+            .map_err(store_error)?;
+
+        Ok(Self::new(connection))
+    }
+
+    /// Creates a new [`RedisStore`] from an existing connection, storing games under [`Self::DEFAULT_PREFIX`]
+    #[must_use]
+    pub fn new(connection: ConnectionManager) -> Self {
+        Self {
+            connection,
+            prefix: Self::DEFAULT_PREFIX.to_string(),
+        }
+    }
+
+    /// builder method setting the prefix prepended to every key,
+    /// ex: to share a Redis database between several deployments
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// returns the prefix prepended to every key
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// internal method returning the Redis key of `key`
+    fn key(&self, key: &str) -> String {
+        format!("{}{key}", self.prefix)
+    }
+}
+
+impl SessionStore for RedisStore {
+    fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<SavedGame>> {
+        Box::pin(async move {
+            let json: Option<String> = self.connection.clone()
+                .get(self.key(key))
+                .await
+                .map_err(store_error)?;
+
+            json.as_deref()
+                .map(SavedGame::from_json)
+                .transpose()
+        })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, game: &'a SavedGame, ttl: Duration) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            // redis rejects a ttl of 0, and would keep the game forever without one
+            let milliseconds = u64::try_from(ttl.as_millis())
+                .unwrap_or(u64::MAX)
+                .max(1);
+
+            self.connection.clone()
+                .pset_ex(self.key(key), game.to_json()?, milliseconds)
+                .await
+                .map_err(store_error)
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()> {
+        Box::pin(async move {
+            self.connection.clone()
+                .del(self.key(key))
+                .await
+                .map_err(store_error)
+        })
+    }
+}
+
+/// internal function wrapping a [`RedisError`] into an [`Error::StoreError`]
+fn store_error(err: RedisError) -> Error {
+    Error::StoreError(Box::new(err))
+}
@@ -0,0 +1,235 @@
+//! A [`SessionStore`] backed by an SQLite database, along with an archive of transcripts
+
+use std::{
+    fmt,
+    path::Path,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use rusqlite::{Connection, OptionalExtension, params};
+
+use crate::{
+    error::{Error, Result},
+    save::SavedGame,
+    transcript::Transcript,
+};
+use super::{SessionStore, StoreFuture};
+
+
+/// the tables created when opening the database, if they do not exist yet
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS games (
+        key TEXT PRIMARY KEY NOT NULL,
+        game TEXT NOT NULL,
+        expires_at INTEGER
+    );
+    CREATE TABLE IF NOT EXISTS transcripts (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        key TEXT NOT NULL,
+        started_at INTEGER NOT NULL,
+        transcript TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS transcripts_key ON transcripts (key);
+";
+
+/// a [`SessionStore`] storing games as JSON in an SQLite database,
+/// for durable games on a single node without any external infrastructure
+///
+/// Only available with the `sqlite` feature enabled.
+/// Games are stored in the `games` table, and finished games can be archived in the `transcripts` table
+/// with [`Self::archive`]. Queries are run on tokio's blocking thread pool.
+/// cloning the store is cheap and shares the same connection
+///
+/// expired games are dropped lazily when accessed, or with [`Self::evict_expired`]
+#[derive(Clone)]
+pub struct SqliteStore {
+    /// the connection to the database
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl fmt::Debug for SqliteStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SqliteStore")
+            .finish_non_exhaustive()
+    }
+}
+
+impl SqliteStore {
+    /// Opens the SQLite database at `path`, creating it and its tables if they do not exist
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the database cannot be opened or its tables created
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::new(Connection::open(path).map_err(store_error)?)
+    }
+
+    /// Opens a new in-memory SQLite database, lost once the store is dropped, ex: for tests
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the database cannot be opened
+    pub fn open_in_memory() -> Result<Self> {
+        Self::new(Connection::open_in_memory().map_err(store_error)?)
+    }
+
+    /// Creates a new [`SqliteStore`] from an existing connection, creating its tables if they do not exist
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the tables cannot be created
+    pub fn new(connection: Connection) -> Result<Self> {
+        connection.execute_batch(SCHEMA)
+            .map_err(store_error)?;
+
+        Ok(Self {
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// internal method running `f` with the connection on the blocking thread pool
+    async fn run<T, F>(&self, f: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> Result<T> + Send + 'static,
+    {
+        let connection = Arc::clone(&self.connection);
+
+        tokio::task::spawn_blocking(move || {
+            let connection = connection
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            f(&connection)
+        })
+            .await
+            .map_err(|err| Error::StoreError(Box::new(err)))?
+    }
+
+    /// archives the `transcript` of a game under `key`, ex: the game's id, returning its id in the `transcripts` table
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the query fails
+    pub async fn archive(&self, key: &str, transcript: &Transcript) -> Result<i64> {
+        let key = key.to_string();
+        let started_at = i64::try_from(transcript.started_at).unwrap_or(i64::MAX);
+        let json = transcript.to_json()?;
+
+        self.run(move |connection| {
+            connection.execute(
+                "INSERT INTO transcripts (key, started_at, transcript) VALUES (?1, ?2, ?3)",
+                params![key, started_at, json],
+            )
+                .map_err(store_error)?;
+
+            Ok(connection.last_insert_rowid())
+        }).await
+    }
+
+    /// returns the transcripts archived under `key`, from the oldest to the newest
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the query fails, or a deserialization error if a transcript is malformed
+    pub async fn transcripts(&self, key: &str) -> Result<Vec<Transcript>> {
+        let key = key.to_string();
+
+        self.run(move |connection| {
+            let mut statement = connection
+                .prepare("SELECT transcript FROM transcripts WHERE key = ?1 ORDER BY started_at, id")
+                .map_err(store_error)?;
+
+            let rows = statement
+                .query_map(params![key], |row| row.get::<_, String>(0))
+                .map_err(store_error)?;
+
+            rows.map(|json| Transcript::from_json(&json.map_err(store_error)?))
+                .collect()
+        }).await
+    }
+
+    /// drops every expired game, returning the amount of dropped games
+    ///
+    /// # Errors
+    /// [`Error::StoreError`] if the query fails
+    pub async fn evict_expired(&self) -> Result<usize> {
+        self.run(|connection| {
+            connection.execute(
+                "DELETE FROM games WHERE expires_at IS NOT NULL AND expires_at <= ?1",
+                params![now_millis()],
+            )
+                .map_err(store_error)
+        }).await
+    }
+}
+
+impl SessionStore for SqliteStore {
+    fn get<'a>(&'a self, key: &'a str) -> StoreFuture<'a, Option<SavedGame>> {
+        let key = key.to_string();
+
+        Box::pin(self.run(move |connection| {
+            let row: Option<(String, Option<i64>)> = connection
+                .query_row(
+                    "SELECT game, expires_at FROM games WHERE key = ?1",
+                    params![key],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .optional()
+                .map_err(store_error)?;
+
+            match row {
+                Some((_, Some(expires_at))) if expires_at <= now_millis() => {
+                    connection.execute("DELETE FROM games WHERE key = ?1", params![key])
+                        .map_err(store_error)?;
+                    Ok(None)
+                },
+                row => row
+                    .map(|(json, _)| SavedGame::from_json(&json))
+                    .transpose(),
+            }
+        }))
+    }
+
+    fn put<'a>(&'a self, key: &'a str, game: &'a SavedGame, ttl: Duration) -> StoreFuture<'a, ()> {
+        let key = key.to_string();
+        let json = game.to_json();
+        let expires_at = i64::try_from(ttl.as_millis())
+            .ok()
+            .and_then(|ttl| now_millis().checked_add(ttl));
+
+        Box::pin(async move {
+            let json = json?;
+
+            self.run(move |connection| {
+                connection.execute(
+                    "INSERT OR REPLACE INTO games (key, game, expires_at) VALUES (?1, ?2, ?3)",
+                    params![key, json, expires_at],
+                )
+                    .map_err(store_error)?;
+
+                Ok(())
+            }).await
+        })
+    }
+
+    fn delete<'a>(&'a self, key: &'a str) -> StoreFuture<'a, ()> {
+        let key = key.to_string();
+
+        Box::pin(self.run(move |connection| {
+            connection.execute("DELETE FROM games WHERE key = ?1", params![key])
+                .map_err(store_error)?;
+
+            Ok(())
+        }))
+    }
+}
+
+/// internal function returning the current POSIX timestamp in milliseconds,
+/// as expiry times outlive the process unlike [`std::time::Instant`]s
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| i64::try_from(elapsed.as_millis()).unwrap_or(i64::MAX))
+}
+
+/// internal function wrapping a [`rusqlite::Error`] into an [`Error::StoreError`]
+fn store_error(err: rusqlite::Error) -> Error {
+    Error::StoreError(Box::new(err))
+}
@@ -0,0 +1,124 @@
+//! Automated answerers for playing akinator games unattended, see [`crate::Akinator::auto_play`]
+
+use std::{
+    collections::VecDeque,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    Akinator,
+    enums::Answer,
+};
+
+
+/// A trait for automated answerers of the akinator's questions
+pub trait AnswerStrategy {
+    /// returns the answer to `question`, or [`None`] to stop answering and let the akinator guess
+    fn answer(&mut self, question: &str, akinator: &Akinator) -> Option<Answer>;
+}
+
+impl<S: AnswerStrategy + ?Sized> AnswerStrategy for &mut S {
+    fn answer(&mut self, question: &str, akinator: &Akinator) -> Option<Answer> {
+        (**self).answer(question, akinator)
+    }
+}
+
+impl<S: AnswerStrategy + ?Sized> AnswerStrategy for Box<S> {
+    fn answer(&mut self, question: &str, akinator: &Akinator) -> Option<Answer> {
+        (**self).answer(question, akinator)
+    }
+}
+
+/// A strategy always giving the same answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Always(pub Answer);
+
+impl AnswerStrategy for Always {
+    fn answer(&mut self, _question: &str, _akinator: &Akinator) -> Option<Answer> {
+        Some(self.0)
+    }
+}
+
+/// A strategy replaying a predetermined sequence of answers, stopping once it runs out
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scripted {
+    /// the remaining answers to give
+    answers: VecDeque<Answer>,
+}
+
+impl Scripted {
+    /// Creates a new [`Scripted`] strategy from a sequence of answers
+    pub fn new(answers: impl IntoIterator<Item = Answer>) -> Self {
+        Self { answers: answers.into_iter().collect() }
+    }
+
+    /// returns the amount of answers left to give
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.answers.len()
+    }
+}
+
+impl AnswerStrategy for Scripted {
+    fn answer(&mut self, _question: &str, _akinator: &Akinator) -> Option<Answer> {
+        self.answers.pop_front()
+    }
+}
+
+/// A strategy giving random answers
+///
+/// uses a small xorshift generator, which is plenty for load testing and demos
+/// but should not be relied on for anything requiring quality randomness
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Random {
+    /// the state of the xorshift generator, never 0
+    state: u64,
+}
+
+impl Random {
+    /// Creates a new [`Random`] strategy seeded with `seed`, for reproducible sequences of answers
+    #[must_use]
+    pub const fn with_seed(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed },
+        }
+    }
+
+    /// Creates a new [`Random`] strategy seeded from the current time
+    #[must_use]
+    pub fn new() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+
+        Self::with_seed(seed)
+    }
+
+    /// internal method returning the next pseudo-random number
+    fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+impl Default for Random {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnswerStrategy for Random {
+    fn answer(&mut self, _question: &str, _akinator: &Akinator) -> Option<Answer> {
+        const ANSWERS: [Answer; 5] = [
+            Answer::Yes,
+            Answer::No,
+            Answer::Idk,
+            Answer::Probably,
+            Answer::ProbablyNot,
+        ];
+
+        Some(ANSWERS[(self.next_u64() % ANSWERS.len() as u64) as usize])
+    }
+}
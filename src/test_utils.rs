@@ -0,0 +1,283 @@
+//! A [`wiremock`] harness mocking every endpoint of the akinator API,
+//! to run full integration tests hermetically, without reaching the real servers
+//!
+//! [`MockAkinator::start`] spins up a mock server answering like the API would,
+//! and [`MockAkinator::akinator`] returns a game sending its requests to it.
+//! The `mount_*` functions and the JSON helpers can be used to build custom scenarios on a bare [`MockServer`],
+//! ex: an endpoint returning an error completion
+//!
+//! ```no_run
+//! # async fn run() -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{enums::Answer, test_utils::MockAkinator};
+//!
+//! let mock = MockAkinator::start().await;
+//! let mut akinator = mock.akinator()?;
+//!
+//! akinator.start().await?;
+//! akinator.answer(Answer::Yes).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Serialize;
+use wiremock::{
+    Mock,
+    MockServer,
+    Request,
+    Respond,
+    ResponseTemplate,
+    matchers::{method, path},
+};
+
+use crate::{
+    Akinator,
+    error::Result,
+    models::{
+        Guess,
+        GuessList,
+        raw::{
+            IdentJson,
+            MoveJson,
+            ParametersJson,
+            StartJson,
+            StepInfo,
+            WinElement,
+            WinJson,
+            WinParams,
+        },
+    },
+};
+
+
+/// the JSONP callback mocked responses are wrapped in
+pub const CALLBACK: &str = "jQuery331000000000000000000_0";
+
+/// the path the API endpoints are mounted under, relative to the mock server's URI
+pub const WS_PATH: &str = "/ws";
+
+/// the progression gained by the akinator with each answer in the mocked game
+pub const PROGRESSION_PER_STEP: f32 = 10.0;
+
+/// a mock server answering like the akinator website and API
+#[derive(Debug)]
+pub struct MockAkinator {
+    /// the underlying mock server
+    server: MockServer,
+}
+
+impl MockAkinator {
+    /// starts a mock server with every endpoint mounted:
+    /// the game page, `new_session`, `answer_api`, `cancel_answer` and `list` returning [`default_guesses`]
+    ///
+    /// the mocked game starts at step 0 with the question `Question 0`,
+    /// each answer moves to the next step, gaining [`PROGRESSION_PER_STEP`] progression
+    pub async fn start() -> Self {
+        let server = MockServer::start().await;
+
+        mount_game_page(&server).await;
+        mount_new_session(&server, "Question 0").await;
+        mount_answer(&server).await;
+        mount_cancel_answer(&server).await;
+        mount_list(&server, &default_guesses()).await;
+
+        Self { server }
+    }
+
+    /// returns the underlying mock server, to mount more mocks or inspect the received requests
+    #[must_use]
+    pub const fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// returns the base URI of the mock server, ex: `http://127.0.0.1:1234`
+    #[must_use]
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// returns the URL of the mocked API, ex: `http://127.0.0.1:1234/ws`
+    #[must_use]
+    pub fn ws_url(&self) -> String {
+        format!("{}{WS_PATH}", self.server.uri())
+    }
+
+    /// creates a new [`Akinator`] sending all of its requests to the mock server
+    ///
+    /// # Errors
+    /// If failed to create HTTP [`reqwest`] client
+    pub fn akinator(&self) -> Result<Akinator> {
+        Ok(Akinator::new()?
+            .with_base_url(self.uri())
+            .with_ws_url(self.ws_url()))
+    }
+}
+
+/// wraps `json` in the JSONP callback the API responds with
+#[must_use]
+pub fn jsonp(json: &impl Serialize) -> String {
+    format!(
+        "{CALLBACK}({})",
+        serde_json::to_string(json).unwrap_or_default(),
+    )
+}
+
+/// returns the game page, declaring the session's uid and frontaddr
+#[must_use]
+pub fn game_page() -> String {
+    "<script>\nvar uid_ext_session = 'mock-uid';\nvar frontaddr = 'mock-frontaddr';\n</script>".to_string()
+}
+
+/// returns a successful `new_session` response, starting the game with `question`
+#[must_use]
+pub fn start_json(question: &str) -> StartJson {
+    StartJson {
+        completion: "OK".to_string(),
+        parameters: Some(ParametersJson {
+            identification: IdentJson {
+                session: "1".to_string(),
+                signature: "123456789".to_string(),
+            },
+            step_information: step_info(0, question, 0.0),
+        }),
+    }
+}
+
+/// returns a successful `answer_api` or `cancel_answer` response, moving the game to `step`
+#[must_use]
+pub fn move_json(step: usize, question: &str, progression: f32) -> MoveJson {
+    MoveJson {
+        completion: "OK".to_string(),
+        parameters: Some(step_info(step, question, progression)),
+    }
+}
+
+/// returns a successful `list` response with `guesses`
+#[must_use]
+pub fn list_json(guesses: &[Guess]) -> WinJson {
+    WinJson {
+        completion: "OK".to_string(),
+        parameters: Some(WinParams {
+            elements: guesses
+                .iter()
+                .cloned()
+                .map(|element| WinElement { element })
+                .collect(),
+        }),
+    }
+}
+
+/// returns a failed `answer_api` or `cancel_answer` response with the given `completion` code,
+/// ex: `KO - TIMEOUT` or `KO - SERVER DOWN`
+#[must_use]
+pub fn error_json(completion: &str) -> MoveJson {
+    MoveJson {
+        completion: completion.to_string(),
+        parameters: None,
+    }
+}
+
+/// returns a single guess named `name`, with the given `probability`
+#[must_use]
+pub fn guess(id: &str, name: &str, probability: f64) -> Guess {
+    Guess {
+        id: id.to_string(),
+        name: name.to_string(),
+        award_id: "-1".to_string(),
+        flag_photo: 0,
+        confidence: probability.to_string(),
+        description: format!("{name}'s description"),
+        ranking: "1".to_string(),
+        picture_path: format!("partenaire/{id}.jpg"),
+        absolute_picture_path: format!("https://photos.clarinea.fr/BL_25_en/600/partenaire/{id}.jpg"),
+    }
+}
+
+/// returns the guesses [`MockAkinator::start`] mounts the `list` endpoint with
+#[must_use]
+pub fn default_guesses() -> GuessList {
+    GuessList::new(vec![
+        guess("1", "Mock Character", 0.9),
+        guess("2", "Other Character", 0.05),
+    ])
+}
+
+/// mounts the game page, see [`game_page`]
+pub async fn mount_game_page(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/game"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(game_page()))
+        .mount(server)
+        .await;
+}
+
+/// mounts the `new_session` endpoint, starting the game with `question`
+pub async fn mount_new_session(server: &MockServer, question: &str) {
+    Mock::given(method("GET"))
+        .and(path("/new_session"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(jsonp(&start_json(question))))
+        .mount(server)
+        .await;
+}
+
+/// mounts the `answer_api` endpoint, moving to the step after the one sent
+pub async fn mount_answer(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/answer_api"))
+        .respond_with(StepResponder { offset: 1 })
+        .mount(server)
+        .await;
+}
+
+/// mounts the `cancel_answer` endpoint, moving back to the step before the one sent
+pub async fn mount_cancel_answer(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!("{WS_PATH}/cancel_answer")))
+        .respond_with(StepResponder { offset: -1 })
+        .mount(server)
+        .await;
+}
+
+/// mounts the `list` endpoint, returning `guesses`
+pub async fn mount_list(server: &MockServer, guesses: &[Guess]) {
+    Mock::given(method("GET"))
+        .and(path(format!("{WS_PATH}/list")))
+        .respond_with(ResponseTemplate::new(200).set_body_string(jsonp(&list_json(guesses))))
+        .mount(server)
+        .await;
+}
+
+/// internal function building the [`StepInfo`] of `step`
+fn step_info(step: usize, question: &str, progression: f32) -> StepInfo {
+    StepInfo {
+        step: step.to_string(),
+        question: question.to_string(),
+        progression: progression.to_string(),
+    }
+}
+
+/// responds to `answer_api` and `cancel_answer` requests,
+/// moving the game `offset` steps from the `step` query parameter
+struct StepResponder {
+    /// the amount of steps to move by
+    offset: isize,
+}
+
+impl Respond for StepResponder {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let step = request.url
+            .query_pairs()
+            .find(|(key, _)| key == "step")
+            .and_then(|(_, value)| value.parse::<usize>().ok());
+
+        let Some(step) = step.and_then(|step| step.checked_add_signed(self.offset)) else {
+            return ResponseTemplate::new(200)
+                .set_body_string(jsonp(&error_json("KO - TECHNICAL ERROR")));
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let progression = (step as f32 * PROGRESSION_PER_STEP).min(100.0);
+
+        ResponseTemplate::new(200)
+            .set_body_string(jsonp(&move_json(step, &format!("Question {step}"), progression)))
+    }
+}
@@ -0,0 +1,132 @@
+//! internal helpers for cleaning up text fields returned from the API
+
+use encoding_rs::{Encoding, UTF_8};
+use lazy_static::lazy_static;
+use regex::{Regex, RegexBuilder};
+use percent_encoding::percent_decode_str;
+
+use crate::error::{Error, Result};
+
+
+/// decodes HTML entities (ex: `&amp;`, `&#39;`) and stray percent-encoding (ex: `%C3%A9`) in `text`,
+/// returning a clean display-ready string
+///
+/// percent-encoding is only decoded when the result is valid UTF-8,
+/// so strings that merely contain a `%` are left untouched
+pub fn decode(text: &str) -> String {
+    lazy_static! {
+        static ref PERCENT_REGEX: Regex = Regex::new(r"%[0-9A-Fa-f]{2}").unwrap();
+    }
+
+    let text = if PERCENT_REGEX.is_match(text) {
+        percent_decode_str(text)
+            .decode_utf8()
+            .map_or_else(|_| text.to_string(), |decoded| decoded.into_owned())
+    } else {
+        text.to_string()
+    };
+
+    html_escape::decode_html_entities(&text).into_owned()
+}
+
+/// internal function detecting the charset of a response body
+///
+/// checks for a byte order mark, then the `charset` parameter of the `Content-Type` header,
+/// then a `<meta charset>` tag in the body, and defaults to UTF-8
+fn detect_encoding(body: &[u8], content_type: Option<&str>) -> (&'static Encoding, usize) {
+    lazy_static! {
+        static ref HEADER_CHARSET_REGEX: Regex =
+            RegexBuilder::new(r#"charset\s*=\s*["']?([\w.:-]+)"#)
+                .case_insensitive(true)
+                .build()
+                .unwrap();
+        static ref META_CHARSET_REGEX: Regex =
+            RegexBuilder::new(r#"<meta[^>]+charset\s*=\s*["']?([\w.:-]+)"#)
+                .case_insensitive(true)
+                .build()
+                .unwrap();
+    }
+
+    if let Some((encoding, bom_length)) = Encoding::for_bom(body) {
+        return (encoding, bom_length);
+    }
+
+    let from_label = |regex: &Regex, haystack: &str| {
+        regex.captures(haystack)
+            .and_then(|captures| captures.get(1))
+            .and_then(|label| Encoding::for_label(label.as_str().as_bytes()))
+    };
+
+    let head = String::from_utf8_lossy(&body[..body.len().min(1024)]);
+
+    let encoding = content_type
+        .and_then(|content_type| from_label(&HEADER_CHARSET_REGEX, content_type))
+        .or_else(|| from_label(&META_CHARSET_REGEX, &head))
+        .unwrap_or(UTF_8);
+
+    (encoding, 0)
+}
+
+/// decodes a response body into a string, transcoding it from its detected charset
+///
+/// # Errors
+/// [`Error::DecodeError`] if the body is malformed in its detected charset
+pub fn decode_body(body: &[u8], content_type: Option<&str>) -> Result<String> {
+    let (encoding, bom_length) = detect_encoding(body, content_type);
+
+    encoding
+        .decode_without_bom_handling_and_without_replacement(&body[bom_length..])
+        .map(std::borrow::Cow::into_owned)
+        .ok_or_else(|| Error::DecodeError(encoding.name().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use encoding_rs::{UTF_8, UTF_16LE, WINDOWS_1252};
+
+    use super::{decode, decode_body, detect_encoding};
+    use crate::error::Error;
+
+    #[test]
+    /// HTML entities and percent-encoding are decoded, a `%` not forming valid UTF-8 is left untouched
+    fn test_decode() {
+        assert_eq!(decode("Tom &amp; Jerry"), "Tom & Jerry");
+        assert_eq!(decode("Is your character&#39;s name real?"), "Is your character's name real?");
+        assert_eq!(decode("Pok%C3%A9mon"), "Pok\u{e9}mon");
+        assert_eq!(decode("100%AB"), "100%AB");
+        assert_eq!(decode("100% sure"), "100% sure");
+    }
+
+    #[test]
+    /// a byte order mark takes precedence over any declared charset, and is stripped from the body
+    fn test_decode_body_bom() {
+        assert_eq!(detect_encoding(b"\xFF\xFEh\0i\0", Some("text/html; charset=utf-8")), (UTF_16LE, 2));
+        assert_eq!(decode_body(b"\xFF\xFEh\0i\0", Some("text/html; charset=utf-8")).unwrap(), "hi");
+        assert_eq!(decode_body(b"\xEF\xBB\xBFcaf\xC3\xA9", None).unwrap(), "caf\u{e9}");
+    }
+
+    #[test]
+    /// the charset of the `Content-Type` header is used, then the one of a `<meta charset>` tag
+    fn test_decode_body_charset() {
+        assert_eq!(detect_encoding(b"caf\xE9", Some("text/html; charset=ISO-8859-1")), (WINDOWS_1252, 0));
+        assert_eq!(decode_body(b"caf\xE9", Some("text/html; charset=\"iso-8859-1\"")).unwrap(), "caf\u{e9}");
+
+        let page = b"<html><head><meta charset=\"windows-1252\"></head>caf\xE9</html>";
+        assert_eq!(detect_encoding(page, Some("text/html")), (WINDOWS_1252, 0));
+        assert_eq!(decode_body(page, None).unwrap(), "<html><head><meta charset=\"windows-1252\"></head>caf\u{e9}</html>");
+
+        let page = b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset=utf-8\">";
+        assert_eq!(detect_encoding(page, Some("text/html; charset=windows-1252")), (WINDOWS_1252, 0));
+        assert_eq!(detect_encoding(b"{}", None), (UTF_8, 0));
+    }
+
+    #[test]
+    /// bodies which are malformed in their detected charset are rejected rather than lossily decoded
+    fn test_decode_body_malformed() {
+        assert!(matches!(decode_body(b"caf\xC3(", None), Err(Error::DecodeError(charset)) if charset == "UTF-8"));
+        assert!(matches!(
+            decode_body(b"\xFF\xFEh", Some("text/html")),
+            Err(Error::DecodeError(charset)) if charset == "UTF-16LE",
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+//! A record of a played akinator game, for archiving games as JSON, see [`crate::Akinator::transcript`]
+
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    enums::{Answer, Language, Theme},
+    error::{Error, Result},
+    models::GuessList,
+};
+
+
+/// an action taken during the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "answer")]
+pub enum Action {
+    /// the question was answered with [`crate::Akinator::answer`]
+    Answer(Answer),
+    /// the game went back a question with [`crate::Akinator::back`]
+    Back,
+}
+
+/// a single step of a played game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptStep {
+    /// the step the action was taken at, starting at 0
+    pub step: usize,
+    /// the question that was being asked when the action was taken
+    pub question: Option<String>,
+    /// the action taken
+    pub action: Action,
+    /// the progression of the akinator after the action, a float out of 100.0
+    pub progression: f32,
+    /// the amount of milliseconds elapsed since the game started, when the action was taken
+    pub elapsed_ms: u64,
+    /// the amount of milliseconds the request took
+    pub latency_ms: u64,
+}
+
+/// A complete record of a played game:
+/// its configuration, every question and answer, timings and the final guesses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Transcript {
+    /// the language of the game
+    pub language: Language,
+    /// the theme of the game
+    pub theme: Theme,
+    /// whether or not NSFW questions and content were filtered out
+    pub child_mode: bool,
+    /// the POSIX timestamp the game was started at
+    pub started_at: u64,
+    /// the first question asked when the game started
+    pub first_question: Option<String>,
+    /// every step of the game, in order
+    pub steps: Vec<TranscriptStep>,
+    /// all the guesses made by the akinator, empty if [`crate::Akinator::win`] was never called
+    pub guesses: GuessList,
+}
+
+impl Transcript {
+    /// serializes the transcript into a pretty-printed JSON string
+    ///
+    /// # Errors
+    /// If serialization fails
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// deserializes a transcript from a JSON string
+    ///
+    /// # Errors
+    /// If deserialization fails
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+/// where a replayed game's question path diverged from its transcript
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Divergence {
+    /// the index into [`Transcript::steps`] of the step that diverged,
+    /// or [`None`] if the first question already differed
+    pub index: Option<usize>,
+    /// the question asked in the transcript
+    pub expected: Option<String>,
+    /// the question asked in the replayed game
+    pub actual: Option<String>,
+}
+
+/// the request that failed while replaying a [`Transcript`], ending the replay early
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayFailure {
+    /// the index into [`Transcript::steps`] of the step that failed,
+    /// or [`None`] if the final [`crate::Akinator::win`] failed
+    pub index: Option<usize>,
+    /// the kind of the error the step failed with, see [`Error::kind`]
+    pub kind: String,
+    /// the message of the error the step failed with
+    pub message: String,
+}
+
+impl ReplayFailure {
+    /// creates the failure of the step at `index` with `err`
+    pub(crate) fn new(index: Option<usize>, err: &Error) -> Self {
+        Self {
+            index,
+            kind: err.kind().to_string(),
+            message: err.to_string(),
+        }
+    }
+}
+
+/// the result of replaying a [`Transcript`] with [`crate::Akinator::replay`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// where the question path diverged, or [`None`] if every question matched
+    pub divergence: Option<Divergence>,
+    /// the amount of steps from the transcript that were re-submitted
+    pub steps_replayed: usize,
+    /// whether or not the akinator's best guess matched the transcript's,
+    /// [`None`] if the transcript has no guesses, the question path diverged or the replay failed
+    pub guess_matches: Option<bool>,
+    /// the step that failed, or [`None`] if every request succeeded
+    pub failure: Option<ReplayFailure>,
+    /// the transcript of the replayed game
+    pub transcript: Transcript,
+}
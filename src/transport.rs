@@ -0,0 +1,434 @@
+//! The transport every request of a game is sent through
+//!
+//! by default requests are sent with the game's own [`reqwest::Client`],
+//! with the `tower` feature enabled they can be routed through any [`tower::Service`],
+//! see [`crate::Akinator::with_layer`] and [`crate::Akinator::with_service`],
+//! and with the `reqwest-middleware` feature enabled through a [`reqwest_middleware::ClientWithMiddleware`],
+//! see [`crate::Akinator::with_middleware_client`]
+//!
+//! other HTTP clients (ex: `hyper` or `ureq`) can be plugged in by implementing [`HttpBackend`],
+//! see [`crate::Akinator::with_backend`]
+//!
+//! error handling can be tested by wrapping a backend in a [`FaultyTransport`],
+//! which randomly injects timeouts, malformed JSON, non-`OK` completion codes and 5xx responses
+//!
+//! the traffic of a game can be logged with an [`HttpLogger`], see [`crate::Akinator::with_http_logger`]
+
+use std::{
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::Duration,
+};
+#[cfg(feature = "tower")]
+use std::sync::{Mutex, PoisonError};
+
+use reqwest::{
+    Client,
+    Method,
+    RequestBuilder,
+    Response,
+    StatusCode,
+    Url,
+    header::HeaderMap,
+};
+#[cfg(feature = "tower")]
+use tower::{
+    ServiceExt,
+    util::BoxCloneService,
+};
+
+use crate::error::{CompletionCode, Result};
+
+
+/// a type-erased error returned by a transport
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// the future returned by [`HttpBackend::execute`]
+pub type BackendFuture<'a> = Pin<Box<dyn Future<Output = std::result::Result<HttpResponse, BoxError>> + Send + 'a>>;
+
+/// a minimal HTTP request sent through an [`HttpBackend`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// the method of the request
+    pub method: Method,
+    /// the URL of the request, without its query string
+    pub url: Url,
+    /// the headers of the request
+    pub headers: HeaderMap,
+    /// the query parameters of the request
+    pub query: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// internal function converting a built [`reqwest::Request`] into an [`HttpRequest`]
+    fn from_reqwest(request: &reqwest::Request) -> Self {
+        let mut url = request.url().clone();
+        let query = url.query_pairs()
+            .map(|(key, value)| (key.into_owned(), value.into_owned()))
+            .collect();
+        url.set_query(None);
+
+        Self {
+            method: request.method().clone(),
+            url,
+            headers: request.headers().clone(),
+            query,
+        }
+    }
+}
+
+/// a minimal HTTP response returned by an [`HttpBackend`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// the status code of the response
+    pub status: StatusCode,
+    /// the headers of the response
+    pub headers: HeaderMap,
+    /// the raw body of the response
+    pub body: Vec<u8>,
+}
+
+impl From<HttpResponse> for Response {
+    fn from(response: HttpResponse) -> Self {
+        let mut builder = http::Response::builder()
+            .status(response.status);
+
+        if let Some(headers) = builder.headers_mut() {
+            *headers = response.headers;
+        }
+
+        builder.body(response.body)
+            .unwrap_or_default()
+            .into()
+    }
+}
+
+/// an HTTP client requests can be sent with, implemented for [`reqwest::Client`]
+///
+/// implement this to use another HTTP client, ex: `hyper` or `ureq` in blocking builds
+pub trait HttpBackend: Send + Sync {
+    /// sends `request`, returning its response with the full body
+    fn execute(&self, request: HttpRequest) -> BackendFuture<'_>;
+}
+
+impl HttpBackend for Client {
+    fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+        Box::pin(async move {
+            let response = self.request(request.method, request.url)
+                .headers(request.headers)
+                .query(&request.query)
+                .send()
+                .await?;
+
+            Ok(HttpResponse {
+                status: response.status(),
+                headers: response.headers().clone(),
+                body: response.bytes().await?.to_vec(),
+            })
+        })
+    }
+}
+
+/// a fault injected into a request by a [`FaultyTransport`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// the request fails with a [`std::io::ErrorKind::TimedOut`] error,
+    /// surfaced as [`crate::error::Error::TransportError`]
+    Timeout,
+    /// the response of an API request is replaced with malformed JSON,
+    /// surfaced as [`crate::error::Error::JsonParseError`]
+    MalformedJson,
+    /// the response of an API request is replaced with the given non-`OK` completion code,
+    /// surfaced as its [`crate::error::Error`] variant, ex: [`crate::error::Error::ServersDown`]
+    Completion(CompletionCode),
+    /// the response is replaced with an empty response with the given status code,
+    /// surfaced as [`crate::error::Error::HttpStatus`]
+    ServerError(StatusCode),
+}
+
+impl Fault {
+    /// returns whether or not the fault can be injected into `request`,
+    /// JSON faults only apply to API requests, which are the ones with a JSONP `callback`
+    fn applies_to(&self, request: &HttpRequest) -> bool {
+        match self {
+            Self::Timeout | Self::ServerError(_) => true,
+            Self::MalformedJson | Self::Completion(_) => request.query
+                .iter()
+                .any(|(key, _)| key == "callback"),
+        }
+    }
+
+    /// returns the response replacing the real one, or the error the request fails with
+    fn inject(&self) -> std::result::Result<HttpResponse, BoxError> {
+        let (status, body) = match self {
+            Self::Timeout => return Err(
+                std::io::Error::new(std::io::ErrorKind::TimedOut, "injected timeout").into()
+            ),
+            Self::MalformedJson => (StatusCode::OK, "jQuery331_0({\"completion\": \"OK\", \"parameters\": ".to_string()),
+            Self::Completion(code) => (
+                StatusCode::OK,
+                format!("jQuery331_0({})", serde_json::json!({ "completion": code.to_string() })),
+            ),
+            Self::ServerError(status) => (*status, String::new()),
+        };
+
+        Ok(HttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into_bytes(),
+        })
+    }
+}
+
+/// an [`HttpBackend`] wrapping another one, randomly injecting [`Fault`]s into its requests
+/// to test error handling against every [`crate::error::Error`] variant
+///
+/// each request rolls once against the faults, in the order they were added,
+/// so the sum of their probabilities should not exceed `1.0`. Requests not faulted are sent with the wrapped backend
+///
+/// ```no_run
+/// # fn run() -> akinator_rs::error::Result<()> {
+/// use akinator_rs::{Akinator, error::CompletionCode, transport::{Fault, FaultyTransport}};
+///
+/// let transport = FaultyTransport::new(reqwest::Client::new())
+///     .with_fault(Fault::Timeout, 0.1)
+///     .with_fault(Fault::Completion(CompletionCode::ServerDown), 0.1);
+///
+/// let akinator = Akinator::new()?.with_backend(transport);
+/// # Ok(())
+/// # }
+/// ```
+pub struct FaultyTransport<B> {
+    /// the backend requests are sent with when not faulted
+    inner: B,
+    /// the faults to inject, with their probability from `0.0` to `1.0`
+    faults: Vec<(Fault, f64)>,
+}
+
+impl<B: HttpBackend> FaultyTransport<B> {
+    /// Creates a new [`FaultyTransport`] wrapping `inner`, without any faults
+    pub const fn new(inner: B) -> Self {
+        Self { inner, faults: Vec::new() }
+    }
+
+    /// builder method to inject `fault` into requests with the given `probability`, from `0.0` to `1.0`
+    #[must_use]
+    pub fn with_fault(mut self, fault: Fault, probability: f64) -> Self {
+        self.faults.push((fault, probability.clamp(0.0, 1.0)));
+        self
+    }
+
+    /// returns the faults injected, with their probability
+    #[must_use]
+    pub fn faults(&self) -> &[(Fault, f64)] {
+        &self.faults
+    }
+
+    /// internal method rolling the fault to inject into `request`, if any
+    fn roll(&self, request: &HttpRequest) -> Option<&Fault> {
+        let roll = random_unit();
+        let mut threshold = 0.0;
+
+        self.faults
+            .iter()
+            .find(|(_, probability)| {
+                threshold += probability;
+                roll < threshold
+            })
+            .map(|(fault, _)| fault)
+            .filter(|fault| fault.applies_to(request))
+    }
+}
+
+impl<B: HttpBackend> HttpBackend for FaultyTransport<B> {
+    fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+        match self.roll(&request) {
+            Some(fault) => {
+                let result = fault.inject();
+                Box::pin(async move { result })
+            },
+            None => self.inner.execute(request),
+        }
+    }
+}
+
+impl<B> fmt::Debug for FaultyTransport<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FaultyTransport")
+            .field("faults", &self.faults)
+            .finish_non_exhaustive()
+    }
+}
+
+/// internal function returning a random number from `0.0` (inclusive) to `1.0` (exclusive)
+fn random_unit() -> f64 {
+    let random = crate::random_u64();
+
+    // the top 53 bits fit exactly in the mantissa of an `f64`
+    #[allow(clippy::cast_precision_loss)]
+    let unit = (random >> 11) as f64 / (1_u64 << 53) as f64;
+    unit
+}
+
+/// the transport requests are sent through
+#[derive(Clone, Default)]
+pub(crate) enum Transport {
+    /// sends requests with the game's own [`reqwest::Client`]
+    #[default]
+    Client,
+    /// sends requests through a [`tower::Service`], wrapped in a mutex to keep the game [`Sync`]
+    #[cfg(feature = "tower")]
+    Service(Arc<Mutex<BoxCloneService<reqwest::Request, Response, BoxError>>>),
+    /// sends requests through a [`reqwest_middleware`] middleware chain
+    #[cfg(feature = "reqwest-middleware")]
+    Middleware(reqwest_middleware::ClientWithMiddleware),
+    /// sends requests through a custom [`HttpBackend`]
+    Backend(Arc<dyn HttpBackend>),
+}
+
+impl fmt::Debug for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Client => "Client",
+            #[cfg(feature = "tower")]
+            Self::Service(_) => "Service",
+            #[cfg(feature = "reqwest-middleware")]
+            Self::Middleware(_) => "Middleware",
+            Self::Backend(_) => "Backend",
+        })
+    }
+}
+
+impl Transport {
+    /// internal method sending `request` through the transport
+    pub(crate) async fn execute(&self, request: RequestBuilder) -> Result<Response> {
+        match self {
+            Self::Client => Ok(request.send().await?),
+            #[cfg(feature = "tower")]
+            Self::Service(service) => {
+                let request = request.build()?;
+                let service = service.lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone();
+
+                service.oneshot(request)
+                    .await
+                    .map_err(from_box_error)
+            },
+            #[cfg(feature = "reqwest-middleware")]
+            Self::Middleware(client) => {
+                client.execute(request.build()?)
+                    .await
+                    .map_err(|err| match err {
+                        reqwest_middleware::Error::Reqwest(err) => err.into(),
+                        reqwest_middleware::Error::Middleware(err) => crate::error::Error::TransportError(err.into()),
+                    })
+            },
+            Self::Backend(backend) => {
+                let request = HttpRequest::from_reqwest(&request.build()?);
+
+                backend.execute(request)
+                    .await
+                    .map(Into::into)
+                    .map_err(from_box_error)
+            },
+        }
+    }
+}
+
+/// the query parameters whose values are redacted from a [`RequestLog`], as they authenticate the session
+const SENSITIVE_PARAMS: [&str; 4] = ["session", "signature", "frontaddr", "uid_ext_session"];
+
+/// the value sensitive query parameters are replaced with in a [`RequestLog`]
+pub const REDACTED: &str = "[redacted]";
+
+/// the sanitized metadata of a request sent to akinator, passed to an [`HttpLogger`]
+///
+/// headers (including cookies) are left out, and the values of the parameters authenticating the session
+/// (`session`, `signature`, `frontaddr` and `uid_ext_session`) are replaced with [`REDACTED`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestLog {
+    /// the id of the game the request was sent in, see [`crate::Akinator::game_id`]
+    pub game_id: String,
+    /// the id of the request, shared by its retries
+    pub request_id: String,
+    /// the method of the request
+    pub method: Method,
+    /// the URL of the request, without its query string
+    pub url: Url,
+    /// the query parameters of the request, with the sensitive ones redacted
+    pub query: Vec<(String, String)>,
+}
+
+impl RequestLog {
+    /// internal function building the log of `request`
+    pub(crate) fn new(request: &reqwest::Request, game_id: &str, request_id: &str) -> Self {
+        let HttpRequest { method, url, query, .. } = HttpRequest::from_reqwest(request);
+
+        Self {
+            game_id: game_id.to_string(),
+            request_id: request_id.to_string(),
+            method,
+            url,
+            query: query
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = if SENSITIVE_PARAMS.contains(&key.as_str()) { REDACTED.to_string() } else { value };
+                    (key, value)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// a summary of the response to a request, passed to an [`HttpLogger`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseLog {
+    /// the status code of the response, [`None`] if no response was received
+    pub status: Option<StatusCode>,
+    /// the length of the response body in bytes, `0` if it was not read
+    pub body_len: usize,
+    /// how long the request took
+    pub elapsed: Duration,
+    /// the message of the error the request failed with, if any,
+    /// a response with an unsuccessful status is reported with its [`Self::status`] instead
+    pub error: Option<String>,
+}
+
+/// a sink receiving a log of every request sent by a game, see [`crate::Akinator::with_http_logger`]
+///
+/// implemented for closures taking a [`RequestLog`] and a [`ResponseLog`].
+/// Called once per attempt, so retried requests are logged once per retry, sharing their request id
+pub trait HttpLogger: Send + Sync {
+    /// logs `request` and the `response` it received
+    fn log(&self, request: &RequestLog, response: &ResponseLog);
+}
+
+impl<F: Fn(&RequestLog, &ResponseLog) + Send + Sync> HttpLogger for F {
+    fn log(&self, request: &RequestLog, response: &ResponseLog) {
+        self(request, response);
+    }
+}
+
+/// an [`HttpLogger`] set on a game, see [`crate::Akinator::with_http_logger`]
+#[derive(Clone)]
+pub(crate) struct Logger(pub(crate) Arc<dyn HttpLogger>);
+
+impl fmt::Debug for Logger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HttpLogger")
+    }
+}
+
+/// internal function converting a transport's error into an [`crate::error::Error`],
+/// unwrapping [`reqwest::Error`]s into [`crate::error::Error::RequestError`]
+pub(crate) fn from_box_error(err: BoxError) -> crate::error::Error {
+    use crate::error::Error;
+
+    match err.downcast::<reqwest::Error>() {
+        Ok(err) => Error::RequestError(*err),
+        Err(err) => Error::TransportError(err),
+    }
+}
@@ -0,0 +1,251 @@
+//! A typestate wrapper around [`Akinator`], making calls that are invalid in the current state of the game
+//! (ex: answering a question before starting the game) compile errors instead of runtime errors
+//!
+//! a [`Game`] moves from [`NotStarted`] to [`InProgress`] with [`Game::start`],
+//! then to [`Finished`] with [`Game::win`]
+//!
+//! ```compile_fail
+//! # async fn run() -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{Akinator, enums::Answer, typestate::Game};
+//!
+//! let mut game = Game::new(Akinator::new()?);
+//! game.answer(Answer::Yes).await?; // the game has not been started
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ```no_run
+//! # async fn run() -> akinator_rs::error::Result<()> {
+//! use akinator_rs::{Akinator, enums::Answer, typestate::Game};
+//!
+//! let mut game = Game::new(Akinator::new()?).start().await?;
+//!
+//! while !game.progression.is_confident(80.0) {
+//!     game.answer(Answer::Yes).await?;
+//! }
+//!
+//! let game = game.win().await?;
+//! println!("{:?}", game.result().first);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt,
+    ops::Deref,
+};
+
+use crate::{
+    Akinator,
+    enums::Answer,
+    error::{Error, Result},
+    models::{GuessList, WinResult},
+};
+
+
+/// the state of a [`Game`] that has not been started yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotStarted;
+
+/// the state of a [`Game`] that has been started and is asking questions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InProgress;
+
+/// the state of a [`Game`] in which the akinator has made its guesses
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finished {
+    /// the guesses made by the akinator
+    result: WinResult,
+}
+
+/// an [`Akinator`] game in the state `S`
+///
+/// dereferences to the underlying [`Akinator`] to read its fields,
+/// but only exposes the calls valid in its current state
+#[derive(Debug)]
+pub struct Game<S> {
+    /// the underlying akinator game
+    akinator: Akinator,
+    /// the current state of the game
+    state: S,
+}
+
+/// returned when a state transition fails, giving back the game in its previous state
+/// so it can be retried
+pub struct TransitionError<S> {
+    /// the error the transition failed with
+    pub error: Error,
+    /// the game, left in its previous state
+    pub game: Game<S>,
+}
+
+impl<S> TransitionError<S> {
+    /// returns the error the transition failed with, dropping the game
+    #[must_use]
+    pub fn into_error(self) -> Error {
+        self.error
+    }
+}
+
+impl<S> fmt::Debug for TransitionError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransitionError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> fmt::Display for TransitionError<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<S> std::error::Error for TransitionError<S> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl<S> From<TransitionError<S>> for Error {
+    fn from(err: TransitionError<S>) -> Self {
+        err.error
+    }
+}
+
+impl<S> Game<S> {
+    /// returns the underlying [`Akinator`], giving up the typestate guarantees
+    #[must_use]
+    pub fn into_inner(self) -> Akinator {
+        self.akinator
+    }
+
+    /// internal method moving the game to the state `T`
+    fn transition<T>(self, state: T) -> Game<T> {
+        Game { akinator: self.akinator, state }
+    }
+}
+
+impl<S> Deref for Game<S> {
+    type Target = Akinator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.akinator
+    }
+}
+
+impl Game<NotStarted> {
+    /// Creates a new [`Game`] from a not yet started `akinator`, configured with its builder methods
+    ///
+    /// the game is [`Akinator::reset`] in case it had already been started
+    #[must_use]
+    pub fn new(mut akinator: Akinator) -> Self {
+        akinator.reset();
+        Self { akinator, state: NotStarted }
+    }
+
+    /// starts the game, reusing the server of the previous game if any, see [`Akinator::restart`]
+    ///
+    /// # Errors
+    /// the error of [`Akinator::restart`], along with the game so it can be retried
+    pub async fn start(mut self) -> Result<Game<InProgress>, TransitionError<NotStarted>> {
+        match self.akinator.restart().await {
+            Ok(_) => Ok(self.transition(InProgress)),
+            Err(error) => Err(TransitionError { error, game: self }),
+        }
+    }
+}
+
+impl From<Akinator> for Game<NotStarted> {
+    fn from(akinator: Akinator) -> Self {
+        Self::new(akinator)
+    }
+}
+
+impl Game<InProgress> {
+    /// returns the question currently asked by the akinator
+    #[must_use]
+    pub fn question(&self) -> &str {
+        self.akinator.current_question
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    /// answers the current question, see [`Akinator::answer`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn answer(&mut self, answer: Answer) -> Result<&str> {
+        self.akinator.answer(answer).await?;
+        Ok(self.question())
+    }
+
+    /// goes back to the previous question, see [`Akinator::back`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn back(&mut self) -> Result<&str> {
+        self.akinator.back().await?;
+        Ok(self.question())
+    }
+
+    /// re-applies the answer undone by going back, see [`Akinator::forward`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn forward(&mut self) -> Result<&str> {
+        self.akinator.forward().await?;
+        Ok(self.question())
+    }
+
+    /// ends the game and makes the akinator guess, see [`Akinator::win`]
+    ///
+    /// # Errors
+    /// the error of [`Akinator::win`], along with the game so it can be retried or continued
+    pub async fn win(mut self) -> Result<Game<Finished>, TransitionError<InProgress>> {
+        match self.akinator.win().await {
+            Ok(result) => Ok(self.transition(Finished { result })),
+            Err(error) => Err(TransitionError { error, game: self }),
+        }
+    }
+}
+
+impl Game<Finished> {
+    /// returns the guesses made by the akinator
+    #[must_use]
+    pub const fn result(&self) -> &WinResult {
+        &self.state.result
+    }
+
+    /// fetches the akinator's next page of guesses, see [`Akinator::more_guesses`]
+    ///
+    /// # Errors
+    ///
+    /// see [errors](https://docs.rs/akinator-rs/latest/akinator_rs/error/enum.Error.html) docs for more info
+    pub async fn more_guesses(&mut self) -> Result<GuessList> {
+        let guesses = self.akinator.more_guesses().await?;
+        self.state.result.all.extend(guesses.iter().cloned());
+
+        Ok(guesses)
+    }
+
+    /// keeps playing after the akinator guessed wrong, rejecting its guesses so they are not made again,
+    /// see [`Akinator::reject_guess`]
+    #[must_use]
+    pub fn resume(mut self) -> Game<InProgress> {
+        for guess in &self.state.result.all {
+            self.akinator.reject_guess(guess.id.clone());
+        }
+
+        self.transition(InProgress)
+    }
+
+    /// ends the game, returning a new not yet started game with the same configuration
+    #[must_use]
+    pub fn reset(self) -> Game<NotStarted> {
+        Game::new(self.akinator)
+    }
+}
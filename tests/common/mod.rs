@@ -0,0 +1,191 @@
+//! The hermetic backend shared by the integration tests, playing a game like the akinator API would
+//! without reaching the real servers
+//!
+//! each test crate only uses part of it
+#![allow(dead_code)]
+
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::Duration,
+};
+
+use akinator_rs::Akinator;
+use akinator_rs::error::Result;
+use akinator_rs::transport::{BackendFuture, HttpBackend, HttpRequest, HttpResponse};
+use reqwest::{StatusCode, header::HeaderMap};
+
+
+/// the base URL games played against a [`Server`] are sent to
+pub const BASE_URL: &str = "https://proxy.example.com";
+
+/// the API URL games played against a [`Server`] are sent to
+pub const WS_URL: &str = "https://proxy.example.com/ws";
+
+/// the game page served by a [`Server`], declaring the session's uid and frontaddr
+pub const GAME_PAGE: &str = "var uid_ext_session = 'uid';\nvar frontaddr = 'addr';";
+
+/// a backend playing a game which starts at `Question 0` and moves one step forward with each answer
+/// and one step back with each cancellation, making a single guess named `Name` with the id `1`
+///
+/// the progression at each step defaults to 50% per step, and any path can be overridden with a fixed response.
+/// Cloning the server shares its requests and session
+#[derive(Clone)]
+pub struct Server {
+    /// returns the progression reached at a step
+    progression: fn(usize) -> f32,
+    /// fixed responses served instead of the game's, by path
+    responses: Vec<(&'static str, StatusCode, String)>,
+    /// how long requests to a path take
+    delays: Vec<(&'static str, Duration)>,
+    /// whether or not the session has expired, until a new session is started
+    expired: Arc<AtomicBool>,
+    /// every request received
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self {
+            progression: |step| (step as f32 * 50.0).min(100.0),
+            responses: Vec::new(),
+            delays: Vec::new(),
+            expired: Arc::default(),
+            requests: Arc::default(),
+        }
+    }
+}
+
+impl Server {
+    /// Creates a new [`Server`] playing the default game
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// sets the progression reached at each step after the first
+    pub fn with_progression(mut self, progression: fn(usize) -> f32) -> Self {
+        self.progression = progression;
+        self
+    }
+
+    /// serves `body` with `status` to every request to `path` instead of the game's response
+    pub fn with_response(mut self, path: &'static str, status: StatusCode, body: impl Into<String>) -> Self {
+        self.responses.push((path, status, body.into()));
+        self
+    }
+
+    /// makes every request to `path` take `delay`
+    pub fn with_delay(mut self, path: &'static str, delay: Duration) -> Self {
+        self.delays.push((path, delay));
+        self
+    }
+
+    /// expires the session: requests time out until a new session is started
+    pub fn expire(&self) {
+        self.expired.store(true, Ordering::SeqCst);
+    }
+
+    /// returns every request received so far
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// returns the values of the `key` query parameter of every request received on `path`
+    pub fn query(&self, path: &str, key: &str) -> Vec<String> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.url.path() == path)
+            .filter_map(|request| query(&request, key))
+            .collect()
+    }
+
+    /// returns a new game played against the server
+    pub fn akinator(&self) -> Result<Akinator> {
+        Ok(Akinator::new()?
+            .with_base_url(BASE_URL)
+            .with_ws_url(WS_URL)
+            .with_backend(self.clone()))
+    }
+
+    /// internal method returning the status and body of the game's response to `request`
+    fn respond(&self, request: &HttpRequest) -> (StatusCode, String) {
+        let path = request.url.path();
+
+        if let Some((_, status, body)) = self.responses.iter().find(|(route, ..)| *route == path) {
+            return (*status, body.clone());
+        }
+
+        let step = query(request, "step")
+            .and_then(|step| step.parse::<usize>().ok())
+            .unwrap_or_default();
+
+        let body = match path {
+            "/" => format!(r#"[{{"translated_theme_name":"Characters","urlWs":"{WS_URL}","subject_id":"1"}}]"#),
+            "/game" => GAME_PAGE.to_string(),
+            "/new_session" => {
+                self.expired.store(false, Ordering::SeqCst);
+
+                r#"cb({"completion": "OK", "parameters": {
+                    "identification": {"session": "12", "signature": "345"},
+                    "step_information": {"step": "0", "question": "Question 0", "progression": "0.0"}
+                }})"#.to_string()
+            },
+            _ if self.expired.load(Ordering::SeqCst) => r#"cb({"completion": "KO - TIMEOUT"})"#.to_string(),
+            "/answer_api" => self.step(step + 1),
+            "/ws/cancel_answer" => match step.checked_sub(1) {
+                Some(step) => self.step(step),
+                None => r#"cb({"completion": "KO - TECHNICAL ERROR"})"#.to_string(),
+            },
+            "/ws/list" => r#"cb({"completion": "OK", "parameters": {"elements": [{"element": {
+                "id": "1", "name": "Name", "award_id": "-1", "flag_photo": 0, "proba": "0.9", "description": "Description",
+                "ranking": "1", "picture_path": "", "absolute_picture_path": ""
+            }}]}})"#.to_string(),
+            _ => return (StatusCode::NOT_FOUND, String::new()),
+        };
+
+        (StatusCode::OK, body)
+    }
+
+    /// internal method returning the response moving the game to `step`
+    fn step(&self, step: usize) -> String {
+        format!(
+            r#"cb({{"completion": "OK", "parameters": {{"step": "{step}", "question": "Question {step}", "progression": "{}"}}}})"#,
+            (self.progression)(step),
+        )
+    }
+}
+
+impl HttpBackend for Server {
+    fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+        self.requests.lock().unwrap().push(request.clone());
+
+        let (status, body) = self.respond(&request);
+        let delay = self.delays
+            .iter()
+            .find(|(path, _)| *path == request.url.path())
+            .map(|(_, delay)| *delay);
+
+        Box::pin(async move {
+            if let Some(delay) = delay {
+                tokio::time::sleep(delay).await;
+            }
+
+            Ok(HttpResponse {
+                status,
+                headers: HeaderMap::new(),
+                body: body.into_bytes(),
+            })
+        })
+    }
+}
+
+/// returns the value of the `key` query parameter of `request`
+fn query(request: &HttpRequest, key: &str) -> Option<String> {
+    request.query
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.clone())
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "actor")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::{Answer, GameState};
+    use akinator_rs::error::{Error, Result};
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// handles shared across tasks drive the same game
+    async fn test_actor() -> Result<()> {
+        let handle = Server::new().akinator()?
+            .spawn();
+
+        assert_eq!(handle.start().await?.as_deref(), Some("Question 0"));
+
+        let tasks = (0..4)
+            .map(|_| {
+                let handle = handle.clone();
+                tokio::spawn(async move { handle.answer(Answer::Yes).await })
+            })
+            .collect::<Vec<_>>();
+
+        for task in tasks {
+            task.await.expect("task should not panic")?;
+        }
+
+        assert_eq!(handle.with(|akinator| akinator.step).await?, 4);
+        assert_eq!(handle.win().await?.first.map(|guess| guess.name).as_deref(), Some("Name"));
+        assert_eq!(handle.with(Akinator::state).await?, GameState::AwaitingConfirmation);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// commands sent after the game's task stopped return a `TaskStopped` error
+    async fn test_actor_stopped() -> Result<()> {
+        let handle = Akinator::new()?.spawn();
+
+        let result = handle.with(|_| panic!("the game's task panicked")).await;
+        assert!(matches!(result, Err(Error::TaskStopped)));
+        assert!(handle.is_stopped());
+        assert!(matches!(handle.answer(Answer::Yes).await, Err(Error::TaskStopped)));
+
+        Ok(())
+    }
+}
@@ -67,7 +67,7 @@ mod tests {
 
         // akinator progression is at or over 80
         // we then tell the akinator to end the game and make its guess with `Akinator::win`
-        let first_guess = akinator.win().await?;
+        let first_guess = akinator.win().await?.first;
 
         if let Some(guess) = first_guess {
             println!("Game Over!\n");
@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::akitude::Akitude;
+
+    #[test]
+    /// progression and step are mapped to the same akitudes as the website
+    fn test_akitude_mapping() {
+        assert_eq!(Akitude::from_progress(0.0, 0), Akitude::Defi);
+        assert_eq!(Akitude::from_progress(10.0, 3), Akitude::Serein);
+        assert_eq!(Akitude::from_progress(35.0, 8), Akitude::InspirationLegere);
+        assert_eq!(Akitude::from_progress(65.0, 12), Akitude::InspirationForte);
+        assert_eq!(Akitude::from_progress(90.0, 15), Akitude::Confiant);
+        assert_eq!(Akitude::from_progress(98.0, 20), Akitude::Mobile);
+        assert_eq!(Akitude::from_progress(30.0, 35), Akitude::LegerDecouragement);
+        assert_eq!(Akitude::from_progress(30.0, 60), Akitude::VraiDecouragement);
+        assert_eq!(Akitude::from_progress(85.0, 60), Akitude::Confiant);
+    }
+
+    #[test]
+    /// akitudes point to the official image files
+    fn test_akitude_url() {
+        assert_eq!(Akitude::InspirationLegere.file_name(), "inspiration_legere.png");
+        assert_eq!(
+            Akitude::Triomphe.url(),
+            "https://en.akinator.com/assets/img/akitudes_670x1096/triomphe.png",
+        );
+    }
+}
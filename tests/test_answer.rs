@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::{Answer, Language};
+    use akinator_rs::error::Result;
+
+    #[test]
+    /// answers are parsed in the game's language, falling back to English
+    fn test_localized_answers() -> Result<()> {
+        let akinator = Akinator::new()?.with_language(Language::French);
+
+        assert_eq!(akinator.parse_answer("Oui")?, Answer::Yes);
+        assert_eq!(akinator.parse_answer("probablement pas")?, Answer::ProbablyNot);
+        assert_eq!(akinator.parse_answer("yes")?, Answer::Yes);
+        assert!(akinator.parse_answer("nein").is_err());
+
+        assert_eq!(Answer::parse_localized("Nein", Language::German)?, Answer::No);
+        assert_eq!(Answer::parse_localized("да", Language::Russian)?, Answer::Yes);
+        assert_eq!(Answer::parse_localized("不知道", Language::Chinese)?, Answer::Idk);
+
+        Ok(())
+    }
+}
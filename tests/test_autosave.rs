@@ -0,0 +1,62 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::{Answer, GameState};
+    use akinator_rs::error::Result;
+    use akinator_rs::save::SavedGame;
+
+    use reqwest::StatusCode;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// the hook is called with the serialized state after each successful step, which can be restored
+    async fn test_autosave() -> Result<()> {
+        let saves = Arc::new(Mutex::new(Vec::new()));
+        let hook_saves = Arc::clone(&saves);
+
+        let mut akinator = Server::new()
+            .with_response("/ws/cancel_answer", StatusCode::OK, r#"cb({"completion": "KO - TECHNICAL ERROR"})"#)
+            .akinator()?
+            .on_state_change(move |state| hook_saves.lock().unwrap().push(state.to_vec()));
+
+        akinator.start().await?;
+        akinator.answer(Answer::Yes).await?;
+        assert!(akinator.back().await.is_err());
+        akinator.win().await?;
+
+        let saves = saves.lock().unwrap();
+        assert_eq!(saves.len(), 3);
+
+        let json = std::str::from_utf8(&saves[1]).expect("state should be valid UTF-8");
+        let restored = Akinator::from_state(SavedGame::from_json(json)?)?;
+        assert_eq!(restored.current_question.as_deref(), Some("Question 1"));
+        assert_eq!(restored.step, 1);
+
+        let json = std::str::from_utf8(&saves[2]).expect("state should be valid UTF-8");
+        assert_eq!(Akinator::from_state(SavedGame::from_json(json)?)?.state(), GameState::AwaitingConfirmation);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// diagnosing a game never calls its hook, leaving the last save untouched
+    async fn test_diagnose_no_autosave() -> Result<()> {
+        let saves = Arc::new(Mutex::new(Vec::new()));
+        let hook_saves = Arc::clone(&saves);
+
+        let akinator = Server::new()
+            .akinator()?
+            .on_state_change(move |state| hook_saves.lock().unwrap().push(state.to_vec()));
+
+        let report = akinator.diagnose().await;
+        assert!(report.is_ok(), "{report}");
+        assert!(saves.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}};
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::Language;
+    use akinator_rs::parse::ExtractionStrategy;
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::transport::{BackendFuture, HttpBackend, HttpRequest, HttpResponse};
+    use reqwest::{StatusCode, header::HeaderMap};
+
+    #[derive(Default)]
+    struct Unavailable {
+        requests: Arc<Mutex<Vec<HttpRequest>>>,
+    }
+
+    impl HttpBackend for Unavailable {
+        fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+            self.requests.lock().unwrap().push(request);
+
+            Box::pin(async {
+                Ok(HttpResponse {
+                    status: StatusCode::SERVICE_UNAVAILABLE,
+                    headers: HeaderMap::new(),
+                    body: b"down for maintenance".to_vec(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    /// requests are sent through the custom backend, and its responses are handled like any other
+    async fn test_with_backend() -> Result<()> {
+        let backend = Unavailable::default();
+        let requests = Arc::clone(&backend.requests);
+
+        let mut akinator = Akinator::new()?
+            .with_backend(backend);
+
+        let err = akinator.start().await.unwrap_err();
+
+        assert!(
+            matches!(
+                err.root(),
+                Error::HttpStatus { status, body } if *status == StatusCode::SERVICE_UNAVAILABLE && body == "down for maintenance",
+            ),
+            "{err:?}",
+        );
+
+        let requests = requests.lock().unwrap();
+        // the homepage and the game page are requested concurrently
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url.as_str(), "https://en.akinator.com/");
+        assert_eq!(requests[1].url.as_str(), "https://en.akinator.com/game");
+        assert!(requests[0].query.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// requests target the custom base and API urls
+    async fn test_with_base_url() -> Result<()> {
+        let backend = Unavailable::default();
+        let requests = Arc::clone(&backend.requests);
+
+        let mut akinator = Akinator::new()?
+            .with_language(Language::French)
+            .with_base_url("https://proxy.example.com/{language}/")
+            .with_backend(backend);
+        akinator.start().await.unwrap_err();
+
+        let mut akinator = akinator
+            .with_ws_url("https://proxy.example.com/ws");
+        akinator.start().await.unwrap_err();
+
+        let urls = requests.lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.url.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(urls, [
+            "https://proxy.example.com/fr",
+            "https://proxy.example.com/fr/game",
+            "https://proxy.example.com/fr/game",
+        ]);
+
+        Ok(())
+    }
+
+    /// a backend recording the max amount of requests in flight at once
+    #[derive(Default)]
+    struct Concurrent {
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    impl HttpBackend for Concurrent {
+        fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+            Box::pin(async move {
+                let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                let body = if request.url.path() == "/game" {
+                    "var uid_ext_session = 'uid';\nvar frontaddr = 'addr';"
+                } else {
+                    r#"[{"translated_theme_name":"Characters","urlWs":"https:\/\/srv3.akinator.com:9331\/ws","subject_id":"1"}]"#
+                };
+
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: body.as_bytes().to_vec(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    /// the server and the session info are discovered concurrently when starting a game
+    async fn test_concurrent_discovery() -> Result<()> {
+        let backend = Concurrent::default();
+        let max_in_flight = Arc::clone(&backend.max_in_flight);
+
+        let mut akinator = Akinator::new()?
+            .with_base_url("https://proxy.example.com")
+            .with_backend(backend);
+
+        // the new session response is not a valid session, only the discovery matters here
+        akinator.start().await.unwrap_err();
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+        assert_eq!(akinator.ws_url(), Some("https://srv3.akinator.com:9331/ws"));
+        assert_eq!(akinator.uid(), Some("uid"));
+        assert_eq!(akinator.session_info_strategy(), Some(ExtractionStrategy::Tokenizer));
+
+        Ok(())
+    }
+}
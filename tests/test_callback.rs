@@ -0,0 +1,67 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+    use akinator_rs::transport::{BackendFuture, HttpBackend, HttpRequest, HttpResponse};
+    use reqwest::{StatusCode, header::HeaderMap};
+
+    /// a backend serving a game page and a new session wrapped in a custom JSONP callback
+    #[derive(Default)]
+    struct Server {
+        callbacks: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl HttpBackend for Server {
+        fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+            let body = if request.url.path().ends_with("/game") {
+                "var uid_ext_session = 'uid';\nvar frontaddr = 'addr';".to_string()
+            } else {
+                let (_, callback) = request.query
+                    .iter()
+                    .find(|(key, _)| key == "callback")
+                    .cloned()
+                    .unwrap_or_default();
+                self.callbacks.lock().unwrap().push(callback);
+
+                r#"custom_callback_1({"completion": "OK", "parameters": {
+                    "identification": {"session": "12", "signature": "345"},
+                    "step_information": {"step": "0", "question": "Is your character real?", "progression": "0.0"}
+                }});"#.to_string()
+            };
+
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: body.into_bytes(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    /// a random jQuery-like callback is generated per session, and any callback name is accepted in responses
+    async fn test_callback() -> Result<()> {
+        let server = Server::default();
+        let callbacks = Arc::clone(&server.callbacks);
+
+        let mut akinator = Akinator::new()?
+            .with_base_url("https://proxy.example.com")
+            .with_ws_url("https://proxy.example.com/ws")
+            .with_backend(server);
+
+        assert_eq!(akinator.start().await?.as_deref(), Some("Is your character real?"));
+        akinator.restart().await?;
+
+        let callbacks = callbacks.lock().unwrap();
+        let (name, timestamp) = callbacks[0].split_once('_').unwrap();
+
+        assert!(name.starts_with("jQuery331") && name[6..].chars().all(|c| c.is_ascii_digit()));
+        assert!(timestamp.parse::<u64>().is_ok());
+        assert_ne!(callbacks[0], callbacks[1]);
+
+        Ok(())
+    }
+}
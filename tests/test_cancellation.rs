@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::error::{Result, Error};
+    use tokio_util::sync::CancellationToken;
+
+    #[tokio::test]
+    /// requests are aborted once the cancellation token is cancelled, leaving the game untouched
+    async fn test_cancellation() -> Result<()> {
+        let token = CancellationToken::new();
+
+        let mut akinator = Akinator::new()?
+            .with_cancellation_token(token.clone());
+
+        token.cancel();
+
+        assert!(matches!(akinator.start().await, Err(Error::Cancelled)));
+        assert_eq!(akinator.step, 0);
+        assert!(akinator.current_question.is_none());
+
+        Ok(())
+    }
+}
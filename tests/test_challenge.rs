@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use akinator_rs::{Akinator, CookieJar};
+    use akinator_rs::challenge::{Challenge, ChallengeSolver, SolverFuture};
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::transport::{BackendFuture, HttpBackend, HttpRequest, HttpResponse};
+    use reqwest::{StatusCode, cookie::CookieStore, header::HeaderMap};
+
+    const CHALLENGE_PAGE: &str = "<html><head><title>Just a moment...</title></head>\
+        <body><script src=\"/cdn-cgi/challenge-platform/h/b/orchestrate/jsch/v1\"></script></body></html>";
+
+    /// a backend serving a challenge page, then an empty page once the clearance cookie is set in `jar`
+    #[derive(Default)]
+    struct Challenged {
+        jar: Arc<CookieJar>,
+        requests: Arc<Mutex<usize>>,
+    }
+
+    impl HttpBackend for Challenged {
+        fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+            let cleared = self.jar.cookies(&request.url)
+                .is_some_and(|cookies| cookies.to_str().is_ok_and(|cookies| cookies.contains("cf_clearance=ok")));
+            *self.requests.lock().unwrap() += 1;
+
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: if cleared { StatusCode::OK } else { StatusCode::FORBIDDEN },
+                    headers: HeaderMap::new(),
+                    body: if cleared { Vec::new() } else { CHALLENGE_PAGE.as_bytes().to_vec() },
+                })
+            })
+        }
+    }
+
+    #[derive(Debug)]
+    struct Solver;
+
+    impl ChallengeSolver for Solver {
+        fn solve<'a>(&'a self, challenge: &'a Challenge) -> SolverFuture<'a> {
+            assert_eq!(challenge.url.as_str(), "https://en.akinator.com/");
+            Box::pin(async { Ok(vec!["cf_clearance=ok".to_string()]) })
+        }
+    }
+
+    #[tokio::test]
+    /// challenge pages are surfaced as a dedicated error
+    async fn test_bot_challenge() -> Result<()> {
+        let mut akinator = Akinator::new()?
+            .with_backend(Challenged::default());
+
+        let err = akinator.start().await.unwrap_err();
+
+        assert!(
+            matches!(err.root(), Error::BotChallenge(challenge) if challenge.status == StatusCode::FORBIDDEN),
+            "{err:?}",
+        );
+        assert!(err.is_server_side());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// challenged requests are retried with the cookies from the solver
+    async fn test_challenge_solver() -> Result<()> {
+        let backend = Challenged::default();
+        let requests = Arc::clone(&backend.requests);
+
+        let mut akinator = Akinator::new()?
+            .with_cookie_jar(Arc::clone(&backend.jar))?
+            .with_challenge_solver(Solver)
+            .with_backend(backend);
+
+        let err = akinator.start().await.unwrap_err();
+
+        // the cleared homepage is empty, so no server can be found on it
+        assert!(matches!(err.root(), Error::NoDataFound), "{err:?}");
+        // the homepage is challenged then retried, and the game page requested along with it
+        // is sent after the challenge was solved
+        assert_eq!(*requests.lock().unwrap(), 3);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::{HttpVersion, IpVersion};
+    use akinator_rs::error::{Error, Result};
+
+    #[tokio::test]
+    /// resolved hosts are connected to directly, without querying DNS
+    async fn test_with_resolve() -> Result<()> {
+        let mut akinator = Akinator::new()?
+            .with_resolve("en.akinator.com", IpAddr::V4(Ipv4Addr::LOCALHOST))?;
+
+        let err = akinator.start().await.unwrap_err();
+
+        assert!(
+            matches!(err.root(), Error::RequestError(err) if err.is_connect() && !format!("{err:?}").contains("dns error")),
+            "{err:?}",
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// connections are only made over the chosen IP version
+    async fn test_with_ip_version() -> Result<()> {
+        for ip_version in [IpVersion::Any, IpVersion::V4, IpVersion::V6] {
+            let mut akinator = Akinator::new()?
+                .with_ip_version(ip_version)?
+                .with_resolve("en.akinator.com", IpAddr::V4(Ipv4Addr::LOCALHOST))?;
+
+            let err = akinator.start().await.unwrap_err();
+            assert!(matches!(err.root(), Error::RequestError(err) if err.is_connect()), "{err:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    /// the connection pool and HTTP/2 settings are applied when building the client
+    fn test_pool_and_http2_options() -> Result<()> {
+        for http_version in [HttpVersion::Auto, HttpVersion::Http1Only, HttpVersion::Http2PriorKnowledge] {
+            Akinator::new()?
+                .with_pool_max_idle_per_host(4)?
+                .with_pool_idle_timeout(Some(Duration::from_secs(30)))?
+                .with_pool_idle_timeout(None)?
+                .with_http_version(http_version)?
+                .with_http2_adaptive_window(true)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::clock::{Clock, FixedClock};
+    use akinator_rs::error::Result;
+    use akinator_rs::save::{SavedGame, SESSION_LIFETIME};
+
+    #[test]
+    /// a fixed clock only moves when told to, and is shared between its clones
+    fn test_fixed_clock() {
+        let clock = FixedClock::from_timestamp(1_700_000_000);
+        let shared = clock.clone();
+
+        let now = clock.now();
+        assert_eq!(clock.now(), now);
+
+        shared.advance(Duration::from_secs(60));
+        assert_eq!(clock.now(), now + Duration::from_secs(60));
+    }
+
+    #[test]
+    /// timestamps of the game are read from the injected clock
+    fn test_with_clock() -> Result<()> {
+        let clock = FixedClock::from_timestamp(1_700_000_000);
+        let akinator = Akinator::new()?
+            .with_clock(clock.clone());
+
+        assert_eq!(akinator.save_state()?.saved_at(), 1_700_000_000);
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(akinator.save_state()?.saved_at(), 1_700_000_090);
+
+        Ok(())
+    }
+
+    #[test]
+    /// the elapsed time and session expiry are tracked from the start and the last request of the session
+    fn test_elapsed_and_expires_in() -> Result<()> {
+        let clock = FixedClock::from_timestamp(1_700_000_120);
+
+        assert_eq!(Akinator::new()?.with_clock(clock.clone()).elapsed(), None);
+        assert_eq!(Akinator::new()?.with_clock(clock.clone()).expires_in(), None);
+
+        let mut state: serde_json::Value = serde_json::from_str(&Akinator::new()?.save_state()?.to_json()?)?;
+        state["timestamp"] = 1_700_000_000_u64.into();
+        state["saved_at"] = 1_700_000_060_u64.into();
+        state["session"] = 12.into();
+
+        let akinator = Akinator::from_state(SavedGame::from_json(&state.to_string())?)?
+            .with_clock(clock.clone());
+
+        assert_eq!(akinator.elapsed(), Some(Duration::from_secs(120)));
+        assert_eq!(akinator.expires_in(), Some(SESSION_LIFETIME - Duration::from_secs(60)));
+
+        clock.advance(SESSION_LIFETIME);
+        assert_eq!(akinator.expires_in(), Some(Duration::ZERO));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use akinator_rs::{Akinator, CookieJar};
+    use akinator_rs::error::Result;
+
+    #[test]
+    /// cookies are enabled by default, and can be shared or disabled
+    fn test_cookie_jar() -> Result<()> {
+        assert!(Akinator::new()?.cookie_jar().is_some());
+        assert!(Akinator::new()?.without_cookies()?.cookie_jar().is_none());
+
+        let jar = Arc::new(CookieJar::default());
+        let akinator = Akinator::new()?.with_cookie_jar(Arc::clone(&jar))?;
+
+        assert!(Arc::ptr_eq(akinator.cookie_jar().unwrap(), &jar));
+
+        Ok(())
+    }
+}
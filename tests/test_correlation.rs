@@ -0,0 +1,61 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Error, Result};
+
+    use reqwest::StatusCode;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// errors carry the ids of their game and request, and each game gets its own id
+    async fn test_correlation_ids() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_response("/answer_api", StatusCode::OK, "cb({malformed")
+            .akinator()?;
+
+        akinator.start().await?;
+        let game_id = akinator.game_id().to_string();
+        let session_request = akinator.last_request_id().map(str::to_string);
+        assert_eq!(game_id.len(), 16);
+        assert!(session_request.is_some());
+
+        let err = akinator.answer(Answer::Yes).await.unwrap_err();
+        let context = err.context().expect("error should have a context");
+        assert_eq!(context.game_id.as_deref(), Some(game_id.as_str()));
+        assert_eq!(context.request_id.as_deref(), akinator.last_request_id());
+        assert_ne!(context.request_id, session_request);
+        assert_eq!(context.body.as_deref(), Some("cb({malformed"));
+        assert!(err.to_string().contains(&format!("game: {game_id}")));
+
+        let restored = Akinator::from_state(akinator.save_state()?)?;
+        assert_eq!(restored.game_id(), game_id);
+
+        akinator.restart().await?;
+        assert_ne!(akinator.game_id(), game_id);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// the context of errors from the requests made concurrently when starting a game
+    /// carries the body of the response that failed, even outside of debug mode
+    async fn test_start_error_context() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_response("/game", StatusCode::OK, "<html>the website changed</html>")
+            .akinator()?;
+
+        let err = akinator.start().await.unwrap_err();
+        assert!(matches!(err.root(), Error::NoDataFound), "{err:?}");
+
+        let context = err.context().expect("error should have a context");
+        assert_eq!(context.endpoint, "game");
+        assert_eq!(context.request_id, None);
+        assert_eq!(context.body.as_deref(), Some("<html>the website changed</html>"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Result, Error};
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// requests are aborted once the game's deadline runs out, and later requests fail fast
+    async fn test_game_deadline() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_delay("/answer_api", Duration::from_secs(1))
+            .akinator()?
+            .with_game_deadline(Duration::from_millis(200));
+
+        assert!(akinator.deadline_remaining().is_none());
+        akinator.start().await?;
+        assert!(akinator.deadline_remaining().is_some_and(|remaining| remaining > Duration::ZERO));
+
+        let started = Instant::now();
+        let err = akinator.answer(Answer::Yes).await.unwrap_err();
+        assert!(matches!(err.root(), Error::DeadlineExceeded));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(akinator.step, 0);
+
+        let started = Instant::now();
+        let err = akinator.win().await.unwrap_err();
+        assert!(matches!(err.root(), Error::DeadlineExceeded));
+        assert!(started.elapsed() < Duration::from_millis(100));
+        assert_eq!(akinator.deadline_remaining(), Some(Duration::ZERO));
+
+        // restarting starts a new game, with a new budget
+        akinator.restart().await?;
+        assert_eq!(akinator.step, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// calls made with a timeout fail once it runs out, leaving the game untouched
+    async fn test_call_timeout() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_delay("/answer_api", Duration::from_secs(1))
+            .akinator()?;
+
+        akinator.start_with_timeout(Duration::from_secs(5)).await?;
+
+        let timeout = Duration::from_millis(100);
+        let err = akinator.answer_with_timeout(Answer::Yes, timeout).await.unwrap_err();
+        assert!(matches!(err, Error::CallTimeout(duration) if duration == timeout));
+        assert!(err.is_retryable());
+        assert_eq!(akinator.step, 0);
+
+        assert_eq!(akinator.answer_with_timeout(Answer::Yes, Duration::from_secs(5)).await?.as_deref(), Some("Question 1"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// a new game timing out while starting leaves the game in progress as it was
+    async fn test_start_timeout() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_delay("/new_session", Duration::from_millis(300))
+            .akinator()?;
+
+        akinator.start_with_timeout(Duration::from_secs(5)).await?;
+        akinator.answer(Answer::Yes).await?;
+
+        let err = akinator.start_with_timeout(Duration::from_millis(100)).await.unwrap_err();
+        assert!(matches!(err, Error::CallTimeout(_)), "{err:?}");
+        assert_eq!(akinator.step, 1);
+        assert_eq!(akinator.current_question.as_deref(), Some("Question 1"));
+
+        assert_eq!(akinator.answer(Answer::Yes).await?.as_deref(), Some("Question 2"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Error, Result};
+    use reqwest::StatusCode;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// the untouched body and parsed JSON of the last response are recorded in debug mode only
+    async fn test_debug_responses() -> Result<()> {
+        let mut akinator = Server::new().akinator()?;
+        akinator.start().await?;
+
+        assert_eq!(akinator.last_raw_response(), None);
+        assert_eq!(akinator.last_json_response(), None);
+
+        let mut akinator = Server::new().akinator()?.with_debug();
+        akinator.start().await?;
+        akinator.answer(Answer::Yes).await?;
+
+        let raw = akinator.last_raw_response().expect("the response should be recorded");
+        assert!(raw.starts_with("cb(") && raw.ends_with(')'), "{raw}");
+
+        let json = akinator.last_json_response().expect("the response should be recorded");
+        assert_eq!(json["completion"], "OK");
+        assert_eq!(json["parameters"]["question"], "Question 1");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// malformed responses are reported with a snippet of their body, which is recorded without any JSON
+    async fn test_debug_parse_error() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_response("/answer_api", StatusCode::OK, "cb({malformed")
+            .akinator()?
+            .with_debug();
+
+        akinator.start().await?;
+        assert!(akinator.last_json_response().is_some());
+
+        let err = akinator.answer(Answer::Yes).await.unwrap_err();
+        assert!(
+            matches!(err.root(), Error::DebugJsonParseError { snippet, .. } if snippet == "cb({malformed"),
+            "{err:?}",
+        );
+        assert_eq!(akinator.last_raw_response(), Some("cb({malformed"));
+        assert_eq!(akinator.last_json_response(), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// pages which are not JSON are recorded too
+    async fn test_debug_game_page() -> Result<()> {
+        let page = "<html>the website changed</html>";
+        let mut akinator = Server::new()
+            .with_response("/game", StatusCode::OK, page)
+            .akinator()?
+            .with_debug();
+
+        assert!(akinator.start().await.is_err());
+        assert_eq!(akinator.last_raw_response(), Some(page));
+
+        Ok(())
+    }
+}
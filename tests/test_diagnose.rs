@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::diagnose::DiagnosticStage;
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::transport::{BackendFuture, HttpBackend, HttpRequest, HttpResponse};
+    use reqwest::{StatusCode, header::HeaderMap};
+
+    /// a backend serving every page, with the game page optionally missing the session info
+    struct Server {
+        game_page: &'static str,
+    }
+
+    impl HttpBackend for Server {
+        fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+            let body = match request.url.path() {
+                "/game" => self.game_page,
+                "/new_session" => r#"cb({"completion": "OK", "parameters": {
+                    "identification": {"session": "12", "signature": "345"},
+                    "step_information": {"step": "0", "question": "Is your character real?", "progression": "0.0"}
+                }})"#,
+                _ => r#"[{"translated_theme_name":"Characters","urlWs":"https:\/\/srv3.akinator.com:9331\/ws","subject_id":"1"}]"#,
+            };
+
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: body.as_bytes().to_vec(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    /// every stage is run and reported when they all succeed, leaving the game untouched
+    async fn test_diagnose() -> Result<()> {
+        let akinator = Akinator::new()?
+            .with_base_url("https://proxy.example.com")
+            .with_backend(Server { game_page: "var uid_ext_session = 'uid';\nvar frontaddr = 'addr';" });
+
+        let report = akinator.diagnose().await;
+
+        assert!(report.is_ok(), "{report}");
+        assert_eq!(
+            report.stages.iter().map(|stage| stage.stage).collect::<Vec<_>>(),
+            [DiagnosticStage::ServerDiscovery, DiagnosticStage::SessionInfo, DiagnosticStage::NewSession],
+        );
+        assert_eq!(
+            report.stages[2].outcome.as_deref().ok(),
+            Some("first question: Is your character real?"),
+        );
+
+        assert!(akinator.session().is_none());
+        assert!(akinator.uid().is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// the report stops at the stage that failed
+    async fn test_diagnose_failure() -> Result<()> {
+        let akinator = Akinator::new()?
+            .with_base_url("https://proxy.example.com")
+            .with_backend(Server { game_page: "<html>the website changed</html>" });
+
+        let report = akinator.diagnose().await;
+
+        assert!(!report.is_ok());
+        assert_eq!(report.stages.len(), 2);
+        assert_eq!(report.failure().map(|failure| failure.stage), Some(DiagnosticStage::SessionInfo));
+        assert!(matches!(report.error().map(Error::root), Some(Error::NoDataFound)));
+
+        let text = report.to_string();
+        assert!(text.contains("server discovery: ok"), "{text}");
+        assert!(text.contains("session info: FAILED"), "{text}");
+
+        Ok(())
+    }
+}
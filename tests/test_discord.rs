@@ -0,0 +1,59 @@
+#![cfg(feature = "discord")]
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::discord::{self, ButtonAction};
+    use akinator_rs::enums::Answer;
+    use akinator_rs::models::{Guess, Progression, Question};
+
+    #[test]
+    /// every button's custom id maps back to its action
+    fn test_button_custom_ids() {
+        let rows = serde_json::to_value(discord::question_components(true)).unwrap();
+        let ids = rows
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|row| row["components"].as_array().unwrap())
+            .map(|button| button["custom_id"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids.len(), 6);
+        assert_eq!(ButtonAction::from_custom_id(&ids[4]), Some(ButtonAction::Answer(Answer::ProbablyNot)));
+        assert_eq!(ButtonAction::from_custom_id(&ids[5]), Some(ButtonAction::Back));
+        assert_eq!(ButtonAction::from_custom_id("akinator:maybe"), None);
+        assert_eq!(ButtonAction::from_custom_id("yes"), None);
+
+        assert_eq!(discord::question_components(false).len(), 1);
+    }
+
+    #[test]
+    /// questions and guesses are rendered into embeds
+    fn test_embeds() {
+        let question = Question {
+            text: "Is your character real?".to_string(),
+            step: 2,
+            progression: Progression::new(42.5),
+        };
+        let embed = serde_json::to_value(discord::question_embed(&question)).unwrap();
+        assert_eq!(embed["title"], "Question 3");
+        assert_eq!(embed["description"], "Is your character real?");
+        assert_eq!(embed["footer"]["text"], "Progression: 42.5%");
+
+        let guess: Guess = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "name": "Mario",
+            "award_id": "-1",
+            "flag_photo": 0,
+            "proba": "0.91",
+            "description": "Video game character",
+            "ranking": "1",
+            "picture_path": "mario.jpg",
+            "absolute_picture_path": "https://photos.clarinea.fr/mario.jpg",
+        })).unwrap();
+        let embed = serde_json::to_value(discord::guess_embed(&guess)).unwrap();
+        assert_eq!(embed["title"], "Mario");
+        assert_eq!(embed["image"]["url"], "https://photos.clarinea.fr/mario.jpg");
+        assert_eq!(embed["fields"][0]["value"], "91.0%");
+    }
+}
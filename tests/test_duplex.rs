@@ -0,0 +1,53 @@
+#![cfg(feature = "duplex")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::Result;
+    use akinator_rs::events::GameEvent;
+    use futures::{SinkExt, StreamExt};
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// answers sent into the sink play the game, whose events end with the guesses once it is confident
+    async fn test_duplex() -> Result<()> {
+        let (mut answers, events) = Server::new().akinator()?
+            .into_duplex();
+
+        answers.send(Answer::Yes).await?;
+        answers.send(Answer::No).await?;
+
+        let events = events.collect::<Vec<_>>().await;
+        let questions = events
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::QuestionAsked { question } => Some(question.text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(questions, ["Question 0", "Question 1", "Question 2"]);
+        assert!(matches!(events.last(), Some(GameEvent::GuessesReady { guesses }) if guesses.len() == 1));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// closing the sink stops the game without guessing, ending the stream
+    async fn test_duplex_close() -> Result<()> {
+        let (mut answers, events) = Server::new().akinator()?
+            .into_duplex();
+
+        answers.send(Answer::Yes).await?;
+        answers.close().await?;
+
+        let events = events.collect::<Vec<_>>().await;
+        assert!(!events.iter().any(|event| matches!(event, GameEvent::GuessesReady { .. })));
+        assert!(answers.send(Answer::Yes).await.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::error::{CompletionCode, Error, RequestContext};
+    use reqwest::StatusCode;
+
+    #[test]
+    /// known completion codes are parsed into their variants and displayed back as-is
+    fn test_completion_code() {
+        let code = CompletionCode::from("KO - UNAUTHORIZED");
+
+        assert_eq!(code, CompletionCode::Unauthorized);
+        assert_eq!(code.to_string(), "KO - UNAUTHORIZED");
+
+        assert_eq!(
+            CompletionCode::from("ko - missing parameters"),
+            CompletionCode::MissingParameters,
+        );
+    }
+
+    #[test]
+    /// unrecognized completion codes keep their raw value
+    fn test_unknown_completion_code() {
+        let code = CompletionCode::from("KO - SOMETHING NEW");
+
+        assert_eq!(code, CompletionCode::Unknown("KO - SOMETHING NEW".to_string()));
+        assert_eq!(code.to_string(), "KO - SOMETHING NEW");
+    }
+
+    #[test]
+    /// errors are categorized for retry logic
+    fn test_error_categories() {
+        assert!(Error::ServersDown.is_retryable());
+        assert!(Error::ServersDown.is_server_side());
+        assert!(!Error::ServersDown.is_session_dead());
+
+        assert!(Error::RateLimited { retry_after: None }.is_retryable());
+
+        let unauthorized = Error::Completion(CompletionCode::Unauthorized);
+        assert!(unauthorized.is_session_dead());
+        assert!(!unauthorized.is_retryable());
+
+        assert!(Error::TimeoutError.is_session_dead());
+
+        let unavailable = Error::HttpStatus { status: StatusCode::SERVICE_UNAVAILABLE, body: String::new() };
+        assert!(unavailable.is_retryable());
+        assert!(unavailable.is_server_side());
+
+        let forbidden = Error::HttpStatus { status: StatusCode::FORBIDDEN, body: String::new() };
+        assert!(!forbidden.is_retryable());
+
+        assert!(!Error::InvalidAnswer.is_retryable());
+        assert!(!Error::InvalidAnswer.is_server_side());
+        assert!(!Error::NoMoreQuestions.is_session_dead());
+    }
+
+    #[test]
+    /// context is displayed alongside the underlying error and can be stripped
+    fn test_error_context() {
+        let err = Error::WithContext {
+            context: RequestContext {
+                endpoint: "answer_api",
+                step: 4,
+                game_id: None,
+                request_id: None,
+                body: None,
+            },
+            source: Box::new(Error::NoDataFound),
+        };
+
+        assert!(matches!(err.root(), Error::NoDataFound));
+        assert_eq!(err.context().map(|context| context.step), Some(4));
+        assert_eq!(err.kind(), "NoDataFound");
+        assert!(err.to_string().ends_with("(endpoint: answer_api, step: 4)"));
+    }
+}
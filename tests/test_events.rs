@@ -0,0 +1,46 @@
+#![cfg(feature = "events")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::Result;
+    use akinator_rs::events::GameEvent;
+    use reqwest::StatusCode;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// every subscriber receives the events of the game, in order
+    async fn test_events() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_response("/ws/cancel_answer", StatusCode::OK, r#"cb({"completion": "KO - TECHNICAL ERROR"})"#)
+            .akinator()?;
+
+        let mut first = akinator.subscribe();
+        let mut second = akinator.subscribe();
+
+        akinator.start().await?;
+        akinator.answer(Answer::Yes).await?;
+        assert!(akinator.back().await.is_err());
+        akinator.win().await?;
+
+        let mut events = Vec::new();
+        while let Ok(event) = first.try_recv() {
+            events.push(event);
+        }
+
+        assert!(matches!(&events[0], GameEvent::QuestionAsked { question } if question.text == "Question 0"));
+        assert_eq!(events[1], GameEvent::Answered { step: 0, answer: Answer::Yes });
+        assert!(matches!(&events[2], GameEvent::QuestionAsked { question } if question.step == 1));
+        assert!(matches!(&events[3], GameEvent::ProgressChanged { to, .. } if (to.value() - 50.0).abs() < f32::EPSILON));
+        assert!(matches!(&events[4], GameEvent::Errored { message } if message.contains("technical error")));
+        assert!(matches!(&events[5], GameEvent::GuessesReady { guesses } if guesses.len() == 1));
+        assert_eq!(events.len(), 6);
+
+        assert_eq!(second.try_recv().ok(), events.first().cloned());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::error::{CompletionCode, Error, Result};
+    use akinator_rs::transport::{BackendFuture, Fault, FaultyTransport, HttpBackend, HttpRequest, HttpResponse};
+    use reqwest::{StatusCode, header::HeaderMap};
+
+    /// a backend serving a game page and a new session
+    struct Server;
+
+    impl HttpBackend for Server {
+        fn execute(&self, request: HttpRequest) -> BackendFuture<'_> {
+            let body = if request.url.path().ends_with("/game") {
+                "var uid_ext_session = 'uid';\nvar frontaddr = 'addr';"
+            } else {
+                r#"jQuery331_0({"completion": "OK", "parameters": {
+                    "identification": {"session": "12", "signature": "345"},
+                    "step_information": {"step": "0", "question": "Is your character real?", "progression": "0.0"}
+                }})"#
+            };
+
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: body.as_bytes().to_vec(),
+                })
+            })
+        }
+    }
+
+    /// starts a game through a transport always injecting `fault`
+    async fn start_with(fault: Fault) -> Result<Option<String>> {
+        let mut akinator = Akinator::new()?
+            .with_base_url("https://proxy.example.com")
+            .with_ws_url("https://proxy.example.com/ws")
+            .with_backend(FaultyTransport::new(Server).with_fault(fault, 1.0));
+
+        akinator.start().await
+    }
+
+    #[tokio::test]
+    /// each fault surfaces as its matching error
+    async fn test_faults() {
+        let err = start_with(Fault::Timeout).await.unwrap_err();
+        assert!(
+            matches!(err.root(), Error::TransportError(err) if err.downcast_ref::<std::io::Error>()
+                .is_some_and(|err| err.kind() == std::io::ErrorKind::TimedOut)),
+            "{err:?}",
+        );
+
+        let err = start_with(Fault::MalformedJson).await.unwrap_err();
+        assert!(matches!(err.root(), Error::JsonParseError(_)), "{err:?}");
+
+        let err = start_with(Fault::Completion(CompletionCode::ServerDown)).await.unwrap_err();
+        assert!(matches!(err.root(), Error::ServersDown), "{err:?}");
+
+        let err = start_with(Fault::Completion(CompletionCode::Unauthorized)).await.unwrap_err();
+        assert!(matches!(err.root(), Error::Completion(CompletionCode::Unauthorized)), "{err:?}");
+
+        let err = start_with(Fault::ServerError(StatusCode::BAD_GATEWAY)).await.unwrap_err();
+        assert!(
+            matches!(err.root(), Error::HttpStatus { status, .. } if *status == StatusCode::BAD_GATEWAY),
+            "{err:?}",
+        );
+    }
+
+    #[tokio::test]
+    /// requests are sent with the wrapped backend when not faulted
+    async fn test_no_faults() -> Result<()> {
+        let mut akinator = Akinator::new()?
+            .with_base_url("https://proxy.example.com")
+            .with_ws_url("https://proxy.example.com/ws")
+            .with_backend(FaultyTransport::new(Server).with_fault(Fault::Timeout, 0.0));
+
+        assert_eq!(akinator.start().await?.as_deref(), Some("Is your character real?"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "ffi")]
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CStr, ptr};
+
+    use akinator_rs::ffi::{
+        AkinatorStatus,
+        akinator_free,
+        akinator_last_error,
+        akinator_new,
+        akinator_start,
+        akinator_step,
+        akinator_string_free,
+    };
+
+    /// returns the last error recorded on this thread
+    fn last_error() -> Option<String> {
+        let error = akinator_last_error();
+        if error.is_null() {
+            return None;
+        }
+
+        let message = unsafe { CStr::from_ptr(error) }.to_string_lossy().into_owned();
+        unsafe { akinator_string_free(error) };
+
+        Some(message)
+    }
+
+    #[test]
+    /// unknown languages and themes are rejected instead of falling back to the defaults
+    fn test_new_invalid_arguments() {
+        let handle = unsafe { akinator_new(c"klingon".as_ptr(), ptr::null(), false) };
+        assert!(handle.is_null());
+
+        let handle = unsafe { akinator_new(c"en".as_ptr(), c"planets".as_ptr(), false) };
+        assert!(handle.is_null());
+        assert!(last_error().is_some_and(|error| error.contains("theme")));
+
+        let handle = unsafe { akinator_new(c"en".as_ptr(), c"animals".as_ptr(), false) };
+        assert!(!handle.is_null());
+        assert_eq!(unsafe { akinator_step(handle) }, 0);
+        unsafe { akinator_free(handle) };
+    }
+
+    #[test]
+    /// null handles are reported as invalid arguments
+    fn test_null_handle() {
+        assert_eq!(unsafe { akinator_start(ptr::null_mut(), ptr::null_mut()) }, AkinatorStatus::InvalidArgument);
+        assert_eq!(unsafe { akinator_step(ptr::null()) }, -1);
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "file-store")]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+    use akinator_rs::store::{FileStore, SessionStore};
+
+    #[tokio::test]
+    /// games are stored in their own file until they are deleted or expire
+    async fn test_file_store() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("akinator-rs-test-{}", std::process::id()));
+        let store = FileStore::new(&dir);
+        let mut akinator = Akinator::new()?;
+        akinator.step = 3;
+        let game = akinator.save_state()?;
+
+        assert_eq!(store.evict_expired().await?, 0);
+
+        store.put("kept/../game", &game, Duration::MAX).await?;
+        store.put("deleted", &game, Duration::from_secs(60)).await?;
+        store.put("expired", &game, Duration::ZERO).await?;
+        store.put("evicted", &game, Duration::ZERO).await?;
+
+        let kept = store.get("kept/../game").await?.expect("game should be stored");
+        assert_eq!(Akinator::from_state(kept)?.step, 3);
+        assert!(store.get("missing").await?.is_none());
+
+        store.delete("deleted").await?;
+        assert!(store.get("deleted").await?.is_none());
+
+        assert!(store.get("expired").await?.is_none());
+        assert_eq!(store.evict_expired().await?, 1);
+
+        // only the kept game is left, without any temporary file
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 1);
+        std::fs::remove_dir_all(&dir)?;
+
+        Ok(())
+    }
+}
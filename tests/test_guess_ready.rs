@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::{Akinator, GUESS_READY_INTERVAL, LAST_STEP};
+    use akinator_rs::error::Result;
+
+    #[test]
+    /// the akinator is ready to guess once confident, periodically, and at the last step
+    fn test_is_guess_ready() -> Result<()> {
+        let mut akinator = Akinator::new()?;
+        assert!(!akinator.is_guess_ready());
+
+        akinator.step = 10;
+        akinator.progression = 98.5.into();
+        assert!(akinator.is_guess_ready());
+
+        akinator.progression = 40.0.into();
+        assert!(!akinator.is_guess_ready());
+
+        akinator.step = GUESS_READY_INTERVAL;
+        assert!(akinator.is_guess_ready());
+
+        akinator.step = LAST_STEP;
+        assert!(akinator.is_guess_ready());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::ImageSize;
+    use akinator_rs::error::Result;
+    use akinator_rs::models::{Guess, GuessList};
+
+    /// internal function creating a guess with the given name and confidence
+    fn guess(name: &str, confidence: &str) -> serde_json::Result<Guess> {
+        serde_json::from_value(serde_json::json!({
+            "id": name,
+            "name": name,
+            "award_id": "-1",
+            "flag_photo": 0,
+            "proba": confidence,
+            "description": "",
+            "ranking": "1",
+            "picture_path": "",
+            "absolute_picture_path": "",
+        }))
+    }
+
+    #[test]
+    /// guesses are filtered by their parsed confidence
+    fn test_guesses_above() -> Result<()> {
+        let mut akinator = Akinator::new()?;
+        akinator.guesses = vec![
+            guess("Mario", "0.93")?,
+            guess("Luigi", "0.05")?,
+            guess("Wario", "malformed")?,
+        ].into();
+
+        assert_eq!(akinator.guesses[0].probability(), 0.93);
+        assert_eq!(akinator.guesses[2].probability(), 0.0);
+
+        let names = akinator.guesses_above(0.1)
+            .into_iter()
+            .map(|guess| guess.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["Mario"]);
+        assert_eq!(akinator.guesses_above(0.0).len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    /// the confidence threshold is kept when saving a game
+    fn test_min_confidence_saved() -> Result<()> {
+        let akinator = Akinator::new()?
+            .with_min_confidence(0.2);
+        let restored = Akinator::from_state(akinator.save_state()?)?;
+
+        assert_eq!(restored.min_confidence, Some(0.2));
+
+        Ok(())
+    }
+
+    #[test]
+    /// rejected guesses are removed from the guesses and remembered
+    fn test_reject_guess() -> Result<()> {
+        let mut akinator = Akinator::new()?;
+        akinator.guesses = vec![guess("Mario", "0.6")?, guess("Luigi", "0.3")?].into();
+        akinator.first_guess = akinator.guesses.first().cloned();
+
+        akinator.reject_guess("Mario");
+
+        assert_eq!(akinator.guesses.len(), 1);
+        assert_eq!(akinator.first_guess.as_ref().map(|guess| guess.name.as_str()), Some("Luigi"));
+        assert!(akinator.rejected_guesses().contains("Mario"));
+
+        let restored = Akinator::from_state(akinator.save_state()?)?;
+        assert_eq!(restored.rejected_guesses(), akinator.rejected_guesses());
+
+        Ok(())
+    }
+
+    #[test]
+    /// normalized probabilities sum up to one, and the top guesses cover the requested share of them
+    fn test_normalized_guesses() -> Result<()> {
+        let guesses = GuessList::new(vec![
+            guess("Luigi", "0.45")?,
+            guess("Mario", "0.9")?,
+            guess("Wario", "0.15")?,
+        ]);
+
+        let normalized = guesses.normalized();
+        let total: f64 = normalized.iter().map(Guess::probability).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!((normalized[1].probability() - 0.6).abs() < 1e-9);
+
+        let names = |guesses: &GuessList| guesses
+            .iter()
+            .map(|guess| guess.name.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(names(&guesses.covering(0.85)), ["Mario", "Luigi"]);
+        assert_eq!(names(&guesses.covering(0.5)), ["Mario"]);
+        assert_eq!(guesses.covering(1.0).len(), 3);
+
+        let unknown = GuessList::new(vec![guess("Toad", "malformed")?]);
+        assert_eq!(unknown.normalized(), unknown);
+
+        Ok(())
+    }
+
+    #[test]
+    /// picture URLs are rewritten to the requested width, when they have one
+    fn test_picture_url() -> Result<()> {
+        let mut mario = guess("Mario", "0.9")?;
+        mario.absolute_picture_path = "https://photos.clarinea.fr/BL_25_en/600/partenaire/m/mario.jpg".to_string();
+
+        assert_eq!(mario.picture_url(ImageSize::Thumbnail), "https://photos.clarinea.fr/BL_25_en/100/partenaire/m/mario.jpg");
+        assert_eq!(mario.picture_url(ImageSize::Medium), "https://photos.clarinea.fr/BL_25_en/300/partenaire/m/mario.jpg");
+        assert_eq!(mario.picture_url(ImageSize::Full), mario.absolute_picture_path);
+
+        mario.absolute_picture_path = "https://photos.clarinea.fr/123.jpg".to_string();
+        assert_eq!(mario.picture_url(ImageSize::Thumbnail), mario.absolute_picture_path);
+
+        Ok(())
+    }
+
+    #[test]
+    /// guesses can be searched by name, sorted and truncated to the most likely ones
+    fn test_guess_list() -> Result<()> {
+        let guesses = [guess("Luigi", "0.3")?, guess("Mario", "0.6")?, guess("Wario", "0.1")?]
+            .into_iter()
+            .collect::<GuessList>();
+
+        assert_eq!(guesses.find_by_name("mario").map(|guess| guess.id.as_str()), Some("Mario"));
+        assert!(guesses.find_by_name("Toad").is_none());
+
+        let names = |guesses: GuessList| guesses
+            .into_iter()
+            .map(|guess| guess.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names(guesses.sorted_by_confidence()), ["Mario", "Luigi", "Wario"]);
+        assert_eq!(names(guesses.top(2)), ["Mario", "Luigi"]);
+        assert_eq!(guesses.top(5).len(), 3);
+
+        let json = serde_json::to_value(&guesses)?;
+        assert!(json.is_array());
+
+        Ok(())
+    }
+}
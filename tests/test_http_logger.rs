@@ -0,0 +1,58 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::Result;
+    use akinator_rs::transport::{REDACTED, RequestLog, ResponseLog};
+    use reqwest::StatusCode;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// every request is logged once along with its response, without the session's credentials
+    async fn test_http_logger() -> Result<()> {
+        let logs: Arc<Mutex<Vec<(RequestLog, ResponseLog)>>> = Arc::default();
+        let sink = Arc::clone(&logs);
+
+        let mut akinator = Server::new()
+            .with_response("/answer_api", StatusCode::BAD_GATEWAY, "bad gateway")
+            .akinator()?
+            .with_http_logger(move |request: &RequestLog, response: &ResponseLog| {
+                sink.lock().unwrap().push((request.clone(), response.clone()));
+            });
+
+        akinator.start().await?;
+        assert!(akinator.answer(Answer::Yes).await.is_err());
+
+        let logs = logs.lock().unwrap();
+        let paths = logs.iter()
+            .map(|(request, _)| request.url.path())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["/game", "/new_session", "/answer_api"]);
+
+        let (request, response) = &logs[2];
+        assert_eq!(request.game_id, akinator.game_id());
+        assert_eq!(Some(request.request_id.as_str()), akinator.last_request_id());
+        assert_eq!(request.url.query(), None);
+        assert_eq!(response.status, Some(StatusCode::BAD_GATEWAY));
+        assert_eq!(response.body_len, "bad gateway".len());
+        assert_eq!(response.error, None);
+
+        for key in ["session", "signature", "frontaddr"] {
+            let value = request.query.iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.as_str());
+            assert_eq!(value, Some(REDACTED), "{key} should be redacted");
+        }
+
+        let answer = request.query.iter()
+            .find(|(name, _)| name == "answer")
+            .map(|(_, value)| value.as_str());
+        assert_eq!(answer, Some("0"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "image-cache")]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::error::Result;
+    use akinator_rs::images::ImageCache;
+
+    #[tokio::test]
+    /// pictures are stored once per content, and the least recently used ones are evicted past the maximum size
+    async fn test_image_cache() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("akinator-rs-images-{}", std::process::id()));
+        let cache = ImageCache::new(&dir, 10);
+
+        assert_eq!(cache.get("https://example.com/a.jpg").await?, None);
+        assert_eq!(cache.size().await?, 0);
+
+        cache.insert("https://example.com/a.jpg", b"aaaa").await?;
+        cache.insert("https://example.com/copy-of-a.jpg", b"aaaa").await?;
+        assert_eq!(cache.size().await?, 4);
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        cache.insert("https://example.com/b.jpg", b"bbbb").await?;
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(cache.get("https://example.com/a.jpg").await?.as_deref(), Some(&b"aaaa"[..]));
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        cache.insert("https://example.com/c.jpg", b"cccc").await?;
+
+        assert_eq!(cache.size().await?, 8);
+        assert_eq!(cache.get("https://example.com/b.jpg").await?, None);
+        assert_eq!(cache.get("https://example.com/copy-of-a.jpg").await?.as_deref(), Some(&b"aaaa"[..]));
+        assert_eq!(cache.get("https://example.com/c.jpg").await?.as_deref(), Some(&b"cccc"[..]));
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        Ok(())
+    }
+}
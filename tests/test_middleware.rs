@@ -0,0 +1,51 @@
+#![cfg(feature = "reqwest-middleware")]
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt, future::Future, pin::Pin};
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::{Error, Result};
+    use reqwest::{Client, Request, Response};
+    use reqwest_middleware::{ClientBuilder, Next};
+    use task_local_extensions::Extensions;
+
+    #[derive(Debug)]
+    struct Rejected;
+
+    impl fmt::Display for Rejected {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("rejected by middleware")
+        }
+    }
+
+    impl std::error::Error for Rejected {}
+
+    fn reject<'a>(
+        _request: Request,
+        _extensions: &'a mut Extensions,
+        _next: Next<'a>,
+    ) -> Pin<Box<dyn Future<Output = reqwest_middleware::Result<Response>> + Send + 'a>> {
+        Box::pin(async { Err(reqwest_middleware::Error::middleware(Rejected)) })
+    }
+
+    #[tokio::test]
+    /// requests go through the provided middleware chain
+    async fn test_with_middleware_client() -> Result<()> {
+        let client = ClientBuilder::new(Client::new())
+            .with(reject)
+            .build();
+
+        let mut akinator = Akinator::new()?
+            .with_middleware_client(client);
+
+        let err = akinator.start().await.unwrap_err();
+
+        assert!(
+            matches!(err.root(), Error::TransportError(err) if err.to_string() == Rejected.to_string()),
+            "{err:?}",
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "test-utils")]
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::test_utils::{self, MockAkinator};
+    use wiremock::{Mock, ResponseTemplate, matchers::{path, query_param}};
+
+    #[tokio::test]
+    /// a full game can be played against the mocked endpoints
+    async fn test_mock_game() -> Result<()> {
+        let mock = MockAkinator::start().await;
+        let mut akinator = mock.akinator()?;
+
+        assert_eq!(akinator.start().await?.as_deref(), Some("Question 0"));
+
+        while !akinator.progression.is_confident(80.0) {
+            akinator.answer(Answer::Yes).await?;
+        }
+        assert_eq!(akinator.step, 8);
+        assert_eq!(akinator.current_question.as_deref(), Some("Question 8"));
+
+        assert_eq!(akinator.back().await?.as_deref(), Some("Question 7"));
+        assert_eq!(akinator.step, 7);
+
+        let result = akinator.win().await?;
+        assert_eq!(result.first.map(|guess| guess.name).as_deref(), Some("Mock Character"));
+        assert_eq!(result.all, test_utils::default_guesses());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// custom scenarios can be mounted on top of the default mocks
+    async fn test_mock_error() -> Result<()> {
+        let mock = MockAkinator::start().await;
+        let mut akinator = mock.akinator()?;
+
+        Mock::given(path("/answer_api"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(test_utils::jsonp(&test_utils::error_json("KO - SERVER DOWN")))
+            )
+            .with_priority(1)
+            .mount(mock.server())
+            .await;
+
+        akinator.start().await?;
+        assert!(matches!(akinator.answer(Answer::Yes).await, Err(Error::ServersDown)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// guesses are fetched in pages of the configured size, each page only returning the guesses not seen yet,
+    /// until the akinator has no more guesses to offer
+    async fn test_more_guesses() -> Result<()> {
+        let mock = MockAkinator::start().await;
+        let mut akinator = mock.akinator()?
+            .with_guess_list_size(1)
+            .with_max_pic_size(200, 300);
+
+        // the first page only holds the best guess, the next ones fall back to every guess mounted with `mount_list`
+        let guesses = test_utils::default_guesses();
+        Mock::given(path(format!("{}/list", test_utils::WS_PATH)))
+            .and(query_param("size", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(test_utils::jsonp(&test_utils::list_json(&guesses[..1])))
+            )
+            .with_priority(1)
+            .mount(mock.server())
+            .await;
+
+        akinator.start().await?;
+        assert_eq!(akinator.win().await?.all.len(), 1);
+
+        let page = akinator.more_guesses().await?;
+        assert_eq!(page.iter().map(|guess| guess.id.as_str()).collect::<Vec<_>>(), ["2"]);
+        assert_eq!(akinator.guesses, guesses);
+
+        assert!(akinator.more_guesses().await?.is_empty());
+        assert_eq!(akinator.guesses, guesses);
+
+        let requests = mock.server()
+            .received_requests()
+            .await
+            .unwrap_or_default();
+        let pages = requests.iter()
+            .filter(|request| request.url.path().ends_with("/list"))
+            .map(|request| {
+                let query = |key: &str| request.url
+                    .query_pairs()
+                    .find(|(name, _)| name == key)
+                    .map(|(_, value)| value.into_owned());
+
+                (query("size"), query("max_pic_width"), query("max_pic_height"))
+            })
+            .collect::<Vec<_>>();
+
+        let page = |size: &str| (Some(size.to_string()), Some("200".to_string()), Some("300".to_string()));
+        assert_eq!(pages, [page("1"), page("2"), page("3")]);
+
+        Ok(())
+    }
+}
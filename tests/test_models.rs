@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::models::{GuessList, Question, StepOutcome, WinResult};
+    use akinator_rs::models::raw::MoveJson;
+
+    #[test]
+    /// raw responses can be deserialized, ignoring unknown fields
+    fn test_raw_move_json() -> serde_json::Result<()> {
+        let json: MoveJson = serde_json::from_str(r#"{
+            "completion": "OK",
+            "parameters": {
+                "step": "3",
+                "question": "Is your character real?",
+                "progression": "12.5",
+                "infogain": "0.6"
+            }
+        }"#)?;
+
+        let parameters = json.parameters.unwrap();
+
+        assert_eq!(json.completion, "OK");
+        assert_eq!(parameters.step, "3");
+        assert_eq!(parameters.question, "Is your character real?");
+
+        Ok(())
+    }
+
+    #[test]
+    /// step outcomes are serialized with a type tag
+    fn test_step_outcome_json() -> serde_json::Result<()> {
+        let outcome = StepOutcome::ReadyToGuess(Question {
+            text: "Is your character a youtuber?".to_string(),
+            step: 14,
+            progression: 97.5.into(),
+        });
+
+        let json = serde_json::to_value(&outcome)?;
+
+        assert_eq!(json["type"], "ready_to_guess");
+        assert_eq!(json["progression"], 97.5);
+        assert_eq!(serde_json::from_value::<StepOutcome>(json)?, outcome);
+
+        Ok(())
+    }
+
+    #[test]
+    /// win results are self-contained and can be sent across as JSON
+    fn test_win_result_json() -> serde_json::Result<()> {
+        let result = WinResult {
+            first: None,
+            all: GuessList::default(),
+            step: 21,
+            progression: 88.0.into(),
+        };
+
+        let json = serde_json::to_value(&result)?;
+
+        assert_eq!(json["step"], 21);
+        assert_eq!(json["first"], serde_json::Value::Null);
+        assert_eq!(serde_json::from_value::<WinResult>(json)?, result);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "offline")]
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::offline::{KnowledgeBase, OfflineAkinator};
+
+    const CSV: &str = "\
+name,description,Is your character real?,Is your character a plumber?,Does your character wear a hat?
+Mario,Nintendo's mascot,no,yes,yes
+Luigi,Mario's brother,no,yes,probably
+Albert Einstein,Physicist,yes,no,0.1
+Sherlock Holmes,\"Detective, from London\",no,no,yes
+";
+
+    /// internal function answering the offline engine's questions as if thinking of `name`
+    fn answer_for(name: &str, question: &str) -> Answer {
+        let yes = match question {
+            "Is your character real?" => name == "Albert Einstein",
+            "Is your character a plumber?" => name == "Mario" || name == "Luigi",
+            _ => name == "Mario" || name == "Sherlock Holmes",
+        };
+
+        if yes { Answer::Yes } else { Answer::No }
+    }
+
+    #[test]
+    /// knowledge bases are parsed from CSV, handling quoted fields and answers as values
+    fn test_from_csv() -> Result<()> {
+        let knowledge_base = KnowledgeBase::from_csv(CSV)?;
+
+        assert_eq!(knowledge_base.questions.len(), 3);
+        assert_eq!(knowledge_base.entities.len(), 4);
+        assert_eq!(knowledge_base.entities[3].description, "Detective, from London");
+        assert_eq!(knowledge_base.entities[1].attributes.get("Does your character wear a hat?"), Some(&0.75));
+
+        assert!(matches!(
+            KnowledgeBase::from_csv("name,Is it real?\nMario,maybe"),
+            Err(Error::InvalidKnowledgeBase(_)),
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// the offline engine narrows down and guesses the entity being thought of
+    async fn test_offline_game() -> Result<()> {
+        for name in ["Mario", "Luigi", "Albert Einstein", "Sherlock Holmes"] {
+            let mut akinator = OfflineAkinator::new(KnowledgeBase::from_csv(CSV)?);
+            let mut question = akinator.start().await?;
+
+            while let Some(text) = question {
+                match akinator.answer(answer_for(name, &text)).await {
+                    Ok(next) => question = next,
+                    Err(Error::NoMoreQuestions) => break,
+                    Err(err) => return Err(err),
+                }
+            }
+
+            let result = akinator.win().await?;
+            assert_eq!(result.first.unwrap().name, name);
+            assert_eq!(result.all.len(), 4);
+            assert_eq!(result.step, akinator.step);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// going back restores the previous question
+    async fn test_offline_back() -> Result<()> {
+        let mut akinator = OfflineAkinator::new(KnowledgeBase::from_csv(CSV)?);
+
+        assert!(matches!(akinator.back().await, Err(Error::CantGoBackAnyFurther)));
+
+        let first = akinator.start().await?;
+        akinator.answer(Answer::Yes).await?;
+
+        assert_eq!(akinator.back().await?, first);
+        assert_eq!(akinator.step, 0);
+        assert_eq!(akinator.progression, 25.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// an empty knowledge base can't be played
+    async fn test_offline_empty() {
+        let mut akinator = OfflineAkinator::new(KnowledgeBase::default());
+
+        assert!(matches!(akinator.start().await, Err(Error::NoDataFound)));
+    }
+}
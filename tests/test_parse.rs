@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::error::{Error, UpdateInfoError};
+    use akinator_rs::models::raw::{MoveJson, StartJson};
+    use akinator_rs::parse::{self, ExtractionStrategy};
+
+    #[test]
+    /// the JSONP callback is stripped whatever its name, and bodies without one are left untouched
+    fn test_strip_jsonp() {
+        assert_eq!(parse::strip_jsonp("jQuery331_0({\"a\": 1});"), "{\"a\": 1}");
+        assert_eq!(parse::strip_jsonp("  cb$.x ({})\n"), "{}");
+        assert_eq!(parse::strip_jsonp("{\"a\": 1}"), "{\"a\": 1}");
+        assert_eq!(parse::strip_jsonp("cb("), "cb(");
+        assert_eq!(parse::strip_jsonp(""), "");
+    }
+
+    #[test]
+    /// missing or malformed server data is reported as an error instead of panicking
+    fn test_page_extraction() {
+        assert!(matches!(parse::server_url("<html></html>", "1"), Err(Error::NoDataFound)));
+        assert!(matches!(parse::session_info("var uid_ext_session = 'a';"), Err(Error::NoDataFound)));
+
+        let (uid, frontaddr) = parse::session_info("var uid_ext_session = 'uid';\nvar frontaddr = 'addr';").unwrap();
+        assert_eq!((uid.as_str(), frontaddr.as_str()), ("uid", "addr"));
+
+        let html = r#"[{"translated_theme_name":"Characters","urlWs":"https:\/\/srv3.akinator.com:9331\/ws","subject_id":"1"}]"#;
+        assert_eq!(parse::server_url(html, "1").unwrap(), "https://srv3.akinator.com:9331/ws");
+        assert!(matches!(parse::server_url(html, "14"), Err(Error::NoDataFound)));
+    }
+
+    #[test]
+    /// the session info is found whatever the formatting of the page's scripts
+    fn test_session_info_formats() {
+        let pages = [
+            "var uid_ext_session = 'uid';\nvar frontaddr = 'addr';",
+            "<html><script>const frontaddr=\"addr\"; let uid_ext_session  =\t'uid'</script></html>",
+            "<SCRIPT type=\"text/javascript\">\n  var uid_ext_session = 'uid',\n      frontaddr = 'addr';\n</SCRIPT>",
+            "<script>// var uid_ext_session = 'commented';\n/* frontaddr = 'commented' */\nwindow.uid_ext_session = `uid`;</script>\
+             <script>var config = {\"frontaddr\": \"addr\"};</script>",
+            "<script>var uid_ext_session = 'u\\x69d'; var frontaddr = 'a\\u0064dr';</script>",
+        ];
+
+        for page in pages {
+            let (uid, frontaddr) = parse::session_info(page).unwrap();
+            assert_eq!((uid.as_str(), frontaddr.as_str()), ("uid", "addr"), "{page}");
+        }
+
+        assert_eq!(
+            ExtractionStrategy::Tokenizer.extract("<p>var uid_ext_session = 'uid';</p><script>var frontaddr = 'addr';</script>"),
+            None,
+        );
+        assert!(matches!(parse::session_info("<script>var uid_ext_session = 'unterminated"), Err(Error::NoDataFound)));
+    }
+
+    #[test]
+    /// the extraction strategies are tried in order, and the one that succeeded is reported
+    fn test_extraction_strategies() {
+        let pages = [
+            ("<script>var uid_ext_session = 'uid'; var frontaddr = 'addr';</script>", ExtractionStrategy::Tokenizer),
+            ("<div data-uid-ext-session=\"uid\" data-frontaddr=\"addr\"></div>", ExtractionStrategy::AlternateRegex),
+            (
+                "<script type=\"application/json\">{\"session\": [{\"uidExtSession\": \"uid\", \"frontAddr\": \"addr\"}]}</script>",
+                ExtractionStrategy::JsonConfig,
+            ),
+        ];
+
+        for (page, strategy) in pages {
+            let info = parse::extract_session_info(page).unwrap();
+            assert_eq!((info.uid.as_str(), info.frontaddr.as_str(), info.strategy), ("uid", "addr", strategy), "{page}");
+        }
+
+        let page = "<p>var uid_ext_session = 'uid';\nvar frontaddr = 'addr';</p><script></script>";
+        assert_eq!(ExtractionStrategy::Tokenizer.extract(page), None);
+        assert_eq!(parse::extract_session_info(page).unwrap().strategy, ExtractionStrategy::VarsRegex);
+
+        assert!(matches!(parse::extract_session_info("<html></html>"), Err(Error::NoDataFound)));
+    }
+
+    #[test]
+    /// out of range numbers in API responses are rejected or clamped
+    fn test_step_parsing() {
+        let json: MoveJson = serde_json::from_str(
+            r#"{"completion": "OK", "parameters": {"step": "99999999999999999999999", "question": "q", "progression": "1.0"}}"#
+        ).unwrap();
+        assert!(matches!(parse::step(&json.parameters.unwrap()), Err(UpdateInfoError::ParseIntError(_))));
+
+        let json: MoveJson = serde_json::from_str(
+            r#"{"completion": "OK", "parameters": {"step": "3", "question": "It&#39;s %C3%A9", "progression": "NaN"}}"#
+        ).unwrap();
+        let question = parse::step(&json.parameters.unwrap()).unwrap();
+        assert_eq!(question.text, "It's é");
+        assert_eq!(question.progression.value(), 0.0);
+
+        let json: StartJson = serde_json::from_str(
+            r#"{"completion": "OK", "parameters": {"identification": {"session": "-1", "signature": "1"},
+                "step_information": {"step": "0", "question": "q", "progression": "0"}}}"#
+        ).unwrap();
+        assert!(parse::identification(&json.parameters.unwrap().identification).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "pool")]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+    use akinator_rs::pool::SessionPool;
+
+    #[tokio::test]
+    /// games beyond the pool's limit wait until a slot is released
+    async fn test_pool() -> Result<()> {
+        let pool = SessionPool::new(1);
+
+        let game = pool.acquire(Akinator::new()?).await;
+        assert_eq!(pool.active(), 1);
+
+        assert!(pool.try_acquire(Akinator::new()?).is_err());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(10), pool.acquire(Akinator::new()?))
+                .await
+                .is_err()
+        );
+
+        drop(game);
+        assert_eq!(pool.available(), 1);
+        assert!(pool.try_acquire(Akinator::new()?).is_ok());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "indicatif")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::Result;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// the progress bar follows the game's step, progression and question, finishing with the best guess
+    async fn test_progress_bar() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_progression(|_| 42.4)
+            .akinator()?;
+
+        let bar = akinator.progress_bar();
+
+        akinator.start().await?;
+        akinator.answer(Answer::Yes).await?;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(bar.position(), 42);
+        assert_eq!(bar.prefix(), "2");
+        assert_eq!(bar.message(), "Question 1");
+        assert!(!bar.is_finished());
+
+        akinator.win().await?;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(bar.is_finished());
+        assert_eq!(bar.message(), "Name");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::models::Progression;
+
+    #[test]
+    /// progressions are clamped percentages, displayed with a percent sign
+    fn test_progression() {
+        let progression = Progression::new(85.5);
+
+        assert!(progression.is_confident(80.0));
+        assert!(!progression.is_confident(90.0));
+        assert!(progression > 80.0);
+        assert_eq!(progression.as_fraction(), 0.855);
+
+        assert_eq!(Progression::new(120.0), 100.0);
+        assert_eq!(Progression::new(-3.0), 0.0);
+
+        assert_eq!(progression.to_string(), "85.5%");
+        assert_eq!(format!("{progression:.2}"), "85.50%");
+    }
+
+    #[test]
+    /// deserialized progressions are clamped like new ones, and serialized as plain percentages
+    fn test_progression_serde() -> serde_json::Result<()> {
+        assert_eq!(serde_json::from_str::<Progression>("150.0")?, 100.0);
+        assert_eq!(serde_json::from_str::<Progression>("-5")?, 0.0);
+        assert_eq!(serde_json::from_str::<Progression>("85.5")?, 85.5);
+
+        assert_eq!(serde_json::to_string(&Progression::new(85.5))?, "85.5");
+
+        Ok(())
+    }
+}
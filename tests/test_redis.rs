@@ -0,0 +1,43 @@
+#![cfg(feature = "redis")]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+    use akinator_rs::store::{RedisStore, SessionStore};
+
+    #[tokio::test]
+    /// games are stored under the prefixed key until they are deleted or expire
+    ///
+    /// needs a Redis server, given by the `AKINATOR_TEST_REDIS_URL` environment variable, skipped otherwise
+    async fn test_redis_store() -> Result<()> {
+        let Ok(url) = std::env::var("AKINATOR_TEST_REDIS_URL") else {
+            return Ok(());
+        };
+
+        let store = RedisStore::connect(&url)
+            .await?
+            .with_prefix("akinator-rs-test:");
+        assert_eq!(store.prefix(), "akinator-rs-test:");
+
+        let mut akinator = Akinator::new()?;
+        akinator.step = 3;
+        let game = akinator.save_state()?;
+
+        store.put("kept", &game, Duration::from_secs(60)).await?;
+        store.put("expired", &game, Duration::from_millis(1)).await?;
+
+        let kept = store.get("kept").await?.expect("game should be stored");
+        assert_eq!(Akinator::from_state(kept)?.step, 3);
+
+        store.delete("kept").await?;
+        assert!(store.get("kept").await?.is_none());
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(store.get("expired").await?.is_none());
+
+        Ok(())
+    }
+}
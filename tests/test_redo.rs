@@ -0,0 +1,60 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Error, Result};
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// going forward re-applies the answers undone by going back, until a new answer is given
+    async fn test_forward() -> Result<()> {
+        let server = Server::new().with_progression(|_| 0.0);
+        let mut akinator = server.akinator()?;
+
+        akinator.start().await?;
+        assert!(matches!(akinator.forward().await, Err(Error::CantGoForward)));
+
+        akinator.answer(Answer::No).await?;
+        akinator.answer(Answer::Probably).await?;
+        akinator.back().await?;
+        akinator.back().await?;
+        assert!(akinator.can_go_forward());
+
+        akinator.forward().await?;
+        assert_eq!(akinator.forward().await?.as_deref(), Some("Question 2"));
+        assert!(!akinator.can_go_forward());
+        assert_eq!(server.query("/answer_api", "answer"), ["1", "3", "1", "3"]);
+
+        akinator.back().await?;
+        akinator.answer(Answer::Yes).await?;
+        assert!(!akinator.can_go_forward());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// changing an earlier answer goes back to it and replays the later answers
+    async fn test_change_answer() -> Result<()> {
+        let server = Server::new().with_progression(|_| 0.0);
+        let mut akinator = server.akinator()?;
+
+        akinator.start().await?;
+        for answer in [Answer::Yes, Answer::No, Answer::Idk] {
+            akinator.answer(answer).await?;
+        }
+        assert!(matches!(akinator.change_answer(3, Answer::Yes).await, Err(Error::InvalidStep(3))));
+
+        assert_eq!(akinator.change_answer(1, Answer::Probably).await?.as_deref(), Some("Question 3"));
+        assert_eq!(server.query("/answer_api", "answer"), ["0", "1", "2", "3", "2"]);
+
+        // the answers are restored from the transcript of saved games
+        let mut restored = Akinator::from_state(akinator.save_state()?)?
+            .with_backend(Server::new().with_progression(|_| 0.0));
+        assert_eq!(restored.change_answer(0, Answer::No).await?.as_deref(), Some("Question 3"));
+
+        Ok(())
+    }
+}
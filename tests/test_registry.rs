@@ -0,0 +1,85 @@
+#![cfg(feature = "registry")]
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::Theme;
+    use akinator_rs::error::Result;
+    use akinator_rs::registry::GameRegistry;
+    use akinator_rs::store::{MemoryStore, SessionStore};
+
+    #[tokio::test]
+    /// games can be inserted, retrieved and removed by key
+    async fn test_registry() -> Result<()> {
+        let registry = GameRegistry::new(Duration::from_secs(60));
+
+        registry.insert(1_u64, Akinator::new()?.with_theme(Theme::Animals));
+        assert_eq!(registry.len(), 1);
+
+        let game = registry.get(&1).expect("game should exist");
+        assert_eq!(game.lock().await.theme, Theme::Animals);
+
+        assert!(registry.get(&2).is_none());
+        assert!(registry.remove(&1).is_some());
+        assert!(registry.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// idle games are expired and passed to the eviction hooks
+    async fn test_registry_expiry() -> Result<()> {
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let hook_evicted = Arc::clone(&evicted);
+
+        let registry = GameRegistry::new(Duration::from_millis(10))
+            .with_eviction_hook(move |key: &&str, _| hook_evicted.lock().unwrap().push(*key));
+
+        registry.insert("a", Akinator::new()?);
+        registry.insert("b", Akinator::new()?);
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        assert!(registry.get(&"a").is_none());
+        assert_eq!(registry.evict_expired(), 1);
+        assert!(registry.is_empty());
+
+        let mut evicted = evicted.lock().unwrap().clone();
+        evicted.sort_unstable();
+        assert_eq!(evicted, ["a", "b"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// paused games are moved to the store and back into the registry once resumed
+    async fn test_registry_pause() -> Result<()> {
+        let store = Arc::new(MemoryStore::new());
+        let registry = GameRegistry::new(Duration::from_secs(60))
+            .with_store(Arc::clone(&store) as Arc<dyn SessionStore>);
+
+        let mut akinator = Akinator::new()?.with_theme(Theme::Objects);
+        akinator.step = 4;
+        registry.insert(1_u64, akinator);
+
+        assert!(registry.pause(&1).await?);
+        assert!(!registry.pause(&2).await?);
+        assert!(registry.is_empty());
+        assert!(store.get("1").await?.is_some());
+
+        let game = registry.resume(&1).await?.expect("game should be resumed");
+        let akinator = game.lock().await;
+        assert_eq!((akinator.theme, akinator.step), (Theme::Objects, 4));
+
+        assert!(store.is_empty());
+        assert!(registry.contains_key(&1));
+        assert!(registry.resume(&2).await?.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::models::Guess;
+    use akinator_rs::render;
+
+    /// internal function creating a guess with the given name, description and picture
+    fn guess(name: &str, description: &str, picture: &str) -> serde_json::Result<Guess> {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "name": name,
+            "award_id": "-1",
+            "flag_photo": 0,
+            "proba": "0.93",
+            "description": description,
+            "ranking": "1",
+            "picture_path": "",
+            "absolute_picture_path": picture,
+        }))
+    }
+
+    #[test]
+    /// guesses are rendered with their text escaped, at a medium picture size
+    fn test_render_guess() -> serde_json::Result<()> {
+        let mario = guess("Mario *Bros*", "<b>plumber</b> & \"hero\"", "https://photos.clarinea.fr/BL_25_en/600/partenaire/m/mario.jpg")?;
+
+        assert_eq!(
+            render::guess_markdown(&mario),
+            "**Mario \\*Bros\\***\n\\<b\\>plumber\\</b\\> & \"hero\"\nConfidence: 93.0%\n\
+            ![Mario \\*Bros\\*](https://photos.clarinea.fr/BL_25_en/300/partenaire/m/mario.jpg)",
+        );
+        assert_eq!(
+            render::guess_html(&mario),
+            "<figure><img src=\"https://photos.clarinea.fr/BL_25_en/300/partenaire/m/mario.jpg\" alt=\"Mario *Bros*\">\
+            <figcaption><strong>Mario *Bros*</strong> <span>&lt;b&gt;plumber&lt;/b&gt; &amp; &quot;hero&quot;</span> \
+            <small>Confidence: 93.0%</small></figcaption></figure>",
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    /// pictures which are not served over http are dropped
+    fn test_render_unsafe_picture() -> serde_json::Result<()> {
+        let guess = guess("Luigi", "", "javascript:alert(1)")?;
+
+        assert!(!render::guess_markdown(&guess).contains("javascript"));
+        assert!(!render::guess_html(&guess).contains("<img"));
+
+        Ok(())
+    }
+}
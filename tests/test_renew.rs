@@ -0,0 +1,36 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Error, Result};
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// expired sessions are reported distinctly, and renewed by replaying the answers in a new session
+    async fn test_renew() -> Result<()> {
+        let server = Server::new().with_progression(|_| 0.0);
+        let mut akinator = server.akinator()?;
+
+        akinator.start().await?;
+        akinator.answer(Answer::Yes).await?;
+        akinator.answer(Answer::No).await?;
+        akinator.reject_guess("1");
+
+        server.expire();
+
+        let err = akinator.answer(Answer::Yes).await.unwrap_err();
+        assert!(matches!(err, Error::SessionExpired), "{err:?}");
+        assert!(err.is_session_dead());
+
+        assert_eq!(akinator.renew().await?.as_deref(), Some("Question 2"));
+        assert_eq!(akinator.step, 2);
+        assert!(akinator.rejected_guesses().contains("1"));
+
+        akinator.answer(Answer::Yes).await?;
+        assert_eq!(akinator.step, 3);
+
+        Ok(())
+    }
+}
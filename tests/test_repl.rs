@@ -0,0 +1,49 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+
+    use crate::common::Server;
+
+    /// returns a game played against [`Server`]
+    fn akinator() -> Result<Akinator> {
+        Server::new()
+            .with_progression(|_| 90.0)
+            .akinator()
+    }
+
+    #[tokio::test]
+    /// questions are asked until the akinator is confident, handling invalid answers and going back
+    async fn test_run_interactive() -> Result<()> {
+        let mut output = Vec::new();
+        let guess = akinator()?
+            .run_interactive("maybe\nback\nYes\n".as_bytes(), &mut output)
+            .await?;
+
+        assert_eq!(guess.map(|guess| guess.name).as_deref(), Some("Name"));
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "1. Question 0\n> invalid answer, expected yes, no, idk, probably, probably not or back\n\
+             1. Question 0\n> cannot go back any further\n\
+             1. Question 0\n> I think of Name: Description\n",
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// the game stops without guessing once the input ends
+    async fn test_run_interactive_eof() -> Result<()> {
+        let mut output = Vec::new();
+        let guess = akinator()?
+            .run_interactive("".as_bytes(), &mut output)
+            .await?;
+
+        assert!(guess.is_none());
+        assert_eq!(String::from_utf8(output).unwrap(), "1. Question 0\n> ");
+
+        Ok(())
+    }
+}
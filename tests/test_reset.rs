@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::Language;
+    use akinator_rs::error::Result;
+
+    #[test]
+    /// resetting clears the game's state but keeps its configuration
+    fn test_reset() -> Result<()> {
+        let mut akinator = Akinator::new()?
+            .with_language(Language::French)
+            .with_partner(3)
+            .with_child_mode();
+
+        akinator.current_question = Some("Is your character real?".to_string());
+        akinator.progression = 42.0.into();
+        akinator.step = 7;
+
+        akinator.reset();
+
+        assert_eq!(akinator.current_question, None);
+        assert_eq!(akinator.progression, 0.0);
+        assert_eq!(akinator.step, 0);
+        assert!(akinator.guesses.is_empty());
+        assert!(akinator.transcript().steps.is_empty());
+        assert!(akinator.progression_history().is_empty());
+
+        assert_eq!(akinator.language, Language::French);
+        assert_eq!(akinator.partner(), 3);
+        assert!(akinator.child_mode);
+
+        Ok(())
+    }
+
+    #[test]
+    /// a game that has not been started has no session info
+    fn test_session_info_before_start() -> Result<()> {
+        let akinator = Akinator::new()?;
+
+        assert_eq!(akinator.session_info(), None);
+        assert_eq!(akinator.session(), None);
+        assert_eq!(akinator.ws_url(), None);
+
+        Ok(())
+    }
+}
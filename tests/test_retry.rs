@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+    use akinator_rs::retry::RetryPolicy;
+
+    #[test]
+    /// the delay before retrying follows `Retry-After` within the policy's limits
+    fn test_retry_delay() {
+        let policy = RetryPolicy {
+            max_retries: 2,
+            default_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(10),
+        };
+
+        assert_eq!(policy.delay(None), Duration::from_secs(2));
+        assert_eq!(policy.delay(Some(Duration::from_secs(5))), Duration::from_secs(5));
+        assert_eq!(policy.delay(Some(Duration::from_secs(120))), Duration::from_secs(10));
+    }
+
+    #[test]
+    /// rate limited requests are only retried when a policy is set
+    fn test_retry_policy_builder() -> Result<()> {
+        assert!(Akinator::new()?.retry_policy().is_none());
+
+        let akinator = Akinator::new()?.with_retry_policy(RetryPolicy::default());
+        assert_eq!(akinator.retry_policy(), Some(&RetryPolicy::default()));
+
+        Ok(())
+    }
+}
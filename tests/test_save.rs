@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::{Language, Theme};
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::save::SavedGame;
+
+    #[test]
+    /// a saved game is restored with the same configuration and progress
+    fn test_save_and_load_file() -> Result<()> {
+        let path = std::env::temp_dir().join("akinator-rs-test-save.json");
+
+        let mut akinator = Akinator::new()?
+            .with_language(Language::Japanese)
+            .with_theme(Theme::Animals);
+        akinator.current_question = Some("Is it a mammal?".to_string());
+        akinator.progression = 33.0.into();
+        akinator.step = 5;
+
+        akinator.save_to_file(&path)?;
+        let restored = Akinator::load_from_file(&path)?;
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(restored.language, Language::Japanese);
+        assert_eq!(restored.theme, Theme::Animals);
+        assert_eq!(restored.current_question, akinator.current_question);
+        assert_eq!(restored.progression, 33.0);
+        assert_eq!(restored.step, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    /// started games saved too long ago are rejected as expired
+    fn test_load_expired() -> Result<()> {
+        let path = std::env::temp_dir().join("akinator-rs-test-expired.json");
+
+        let mut state: serde_json::Value = serde_json::from_str(
+            &Akinator::new()?.save_state()?.to_json()?
+        )?;
+        state["saved_at"] = 0.into();
+        state["session"] = 12.into();
+
+        let saved = SavedGame::from_json(&state.to_string())?;
+        assert!(saved.is_expired());
+
+        std::fs::write(&path, saved.to_json()?)?;
+        let result = Akinator::load_from_file(&path);
+        std::fs::remove_file(&path)?;
+
+        assert!(matches!(result, Err(Error::SessionExpired)));
+
+        Ok(())
+    }
+}
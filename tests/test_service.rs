@@ -0,0 +1,46 @@
+#![cfg(feature = "tower")]
+
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::{Answer, GameState};
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::models::StepOutcome;
+    use akinator_rs::service::{AkinatorService, Command, Outcome};
+    use tower::ServiceExt;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// commands sent to the service play the game, responding with their outcomes
+    async fn test_service() -> Result<()> {
+        let service = AkinatorService::new(
+            Server::new().akinator()?,
+        );
+
+        match service.clone().oneshot(Command::Start).await? {
+            Outcome::Question(question) => assert_eq!(question.text, "Question 0"),
+            outcome => panic!("unexpected outcome: {outcome:?}"),
+        }
+
+        match service.clone().oneshot(Command::Answer(Answer::Yes)).await? {
+            Outcome::Step(StepOutcome::NextQuestion(question)) => assert_eq!(question.step, 1),
+            outcome => panic!("unexpected outcome: {outcome:?}"),
+        }
+
+        match service.clone().oneshot(Command::Win).await? {
+            Outcome::Guesses(result) => assert_eq!(result.all.len(), 1),
+            outcome => panic!("unexpected outcome: {outcome:?}"),
+        }
+        assert_eq!(service.game().lock().await.state(), GameState::AwaitingConfirmation);
+
+        assert_eq!(service.clone().oneshot(Command::Reset).await?, Outcome::Reset);
+        assert!(matches!(
+            service.oneshot(Command::Back).await,
+            Err(Error::WrongState { state: GameState::NotStarted, .. }),
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg(all(feature = "simulate", feature = "test-utils"))]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::enums::Answer;
+    use akinator_rs::simulate::{self, SimulationConfig};
+    use akinator_rs::strategy::Always;
+    use akinator_rs::test_utils::{MockAkinator, PROGRESSION_PER_STEP};
+
+    #[tokio::test]
+    /// every game of a batch is played until the win threshold, and their statistics aggregated
+    async fn test_run_batch() {
+        let mock = MockAkinator::start().await;
+        let config = SimulationConfig {
+            concurrency: 2,
+            start_interval: Duration::from_millis(1),
+            win_threshold: 50.0,
+            base_url: Some(mock.uri()),
+            ws_url: Some(mock.ws_url()),
+            ..SimulationConfig::default()
+        };
+
+        let stats = simulate::run_batch(3, config, |_| Always(Answer::Yes)).await;
+
+        assert_eq!(stats.games, 3);
+        assert_eq!(stats.completed, 3);
+        assert!(stats.failures.is_empty(), "{:?}", stats.failures);
+        assert!((stats.average_steps - f64::from(50.0 / PROGRESSION_PER_STEP)).abs() < f64::EPSILON);
+        assert!(stats.latency.max > Duration::ZERO);
+        assert!(stats.latency.p50 <= stats.latency.max);
+    }
+}
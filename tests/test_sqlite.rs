@@ -0,0 +1,54 @@
+#![cfg(feature = "sqlite")]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+    use akinator_rs::store::{SessionStore, SqliteStore};
+
+    #[tokio::test]
+    /// games are stored by key until they are deleted or expire
+    async fn test_sqlite_store() -> Result<()> {
+        let store = SqliteStore::open_in_memory()?;
+        let mut akinator = Akinator::new()?;
+        akinator.step = 3;
+        let game = akinator.save_state()?;
+
+        store.put("kept", &game, Duration::MAX).await?;
+        store.put("deleted", &game, Duration::from_secs(60)).await?;
+        store.put("expired", &game, Duration::ZERO).await?;
+        store.put("evicted", &game, Duration::ZERO).await?;
+
+        let kept = store.get("kept").await?.expect("game should be stored");
+        assert_eq!(Akinator::from_state(kept)?.step, 3);
+        assert!(store.get("missing").await?.is_none());
+
+        store.delete("deleted").await?;
+        assert!(store.get("deleted").await?.is_none());
+
+        assert!(store.get("expired").await?.is_none());
+        assert_eq!(store.evict_expired().await?, 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// transcripts are archived and listed by key
+    async fn test_sqlite_archive() -> Result<()> {
+        let store = SqliteStore::open_in_memory()?;
+        let transcript = Akinator::new()?.transcript();
+
+        let first = store.archive("game", &transcript).await?;
+        let second = store.archive("game", &transcript).await?;
+        store.archive("other", &transcript).await?;
+        assert!(second > first);
+
+        let transcripts = store.transcripts("game").await?;
+        assert_eq!(transcripts, vec![transcript.clone(), transcript]);
+        assert!(store.transcripts("missing").await?.is_empty());
+
+        Ok(())
+    }
+}
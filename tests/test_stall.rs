@@ -0,0 +1,63 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::GUESS_READY_INTERVAL;
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::Result;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// the game is stalled once the progression stops improving for a window of questions,
+    /// at which point guessing is recommended
+    async fn test_stall() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_progression(|step| (step * 20).min(60) as f32)
+            .akinator()?
+            .with_stall_window(3);
+
+        akinator.start().await?;
+
+        for _ in 0..5 {
+            akinator.answer(Answer::Yes).await?;
+            assert!(!akinator.is_stalled());
+            assert!(!akinator.recommend_win());
+        }
+
+        akinator.answer(Answer::Yes).await?;
+        assert_eq!(akinator.progression_history(), [0.0, 20.0, 40.0, 60.0, 60.0, 60.0, 60.0]);
+        assert!(akinator.is_stalled());
+        assert!(akinator.recommend_win());
+
+        akinator = akinator.with_stall_window(0);
+        assert!(!akinator.is_stalled());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// the questions left are extrapolated from the recent progression rate,
+    /// bounded by the questions left before the akinator guesses anyway
+    async fn test_estimated_steps_remaining() -> Result<()> {
+        let mut akinator = Server::new()
+            .with_progression(|step| (step * 20).min(60) as f32)
+            .akinator()?;
+
+        assert_eq!(akinator.estimated_steps_remaining(), None);
+
+        akinator.start().await?;
+        assert_eq!(akinator.estimated_steps_remaining(), Some(GUESS_READY_INTERVAL));
+
+        akinator.answer(Answer::Yes).await?;
+        akinator.answer(Answer::Yes).await?;
+        assert_eq!(akinator.estimated_steps_remaining(), Some(3));
+
+        for _ in 0..5 {
+            akinator.answer(Answer::Yes).await?;
+        }
+        assert_eq!(akinator.estimated_steps_remaining(), Some(10));
+
+        Ok(())
+    }
+}
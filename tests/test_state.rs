@@ -0,0 +1,85 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::{Answer, GameState};
+    use akinator_rs::error::{Error, Result};
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// the state follows the game, and out of order calls return a `WrongState` error
+    async fn test_state() -> Result<()> {
+        let mut akinator = Server::new().akinator()?;
+
+        assert_eq!(akinator.state(), GameState::NotStarted);
+        assert!(matches!(
+            akinator.answer(Answer::Yes).await,
+            Err(Error::WrongState { state: GameState::NotStarted, .. }),
+        ));
+        assert!(matches!(akinator.win().await, Err(Error::WrongState { .. })));
+        assert!(matches!(akinator.confirm_guess(), Err(Error::WrongState { .. })));
+
+        akinator.start().await?;
+        assert_eq!(akinator.state(), GameState::InProgress);
+
+        akinator.answer(Answer::Yes).await?;
+        akinator.win().await?;
+        assert_eq!(akinator.state(), GameState::AwaitingConfirmation);
+
+        let state = Akinator::from_state(akinator.save_state()?)?.state();
+        assert_eq!(state, GameState::AwaitingConfirmation);
+
+        assert_eq!(akinator.confirm_guess()?.name, "Name");
+        assert_eq!(akinator.state(), GameState::Finished);
+
+        let err = akinator.answer(Answer::Yes).await.unwrap_err();
+        assert_eq!(err.to_string(), "Cannot answer a question while the game is finished");
+
+        akinator.reset();
+        assert_eq!(akinator.state(), GameState::NotStarted);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// snapshots copy the state of the game at the time they are taken
+    async fn test_snapshot() -> Result<()> {
+        let mut akinator = Server::new().akinator()?;
+
+        assert_eq!(akinator.snapshot().question, None);
+
+        akinator.start().await?;
+        let snapshot = akinator.snapshot();
+
+        akinator.answer(Answer::Yes).await?;
+        akinator.win().await?;
+
+        assert_eq!(snapshot.question.as_deref(), Some("Question 0"));
+        assert_eq!((snapshot.step, snapshot.state), (0, GameState::InProgress));
+        assert!(snapshot.guesses.is_empty());
+
+        let snapshot = akinator.snapshot();
+        assert_eq!(snapshot.step, 1);
+        assert_eq!(snapshot.guesses.len(), 1);
+        assert_eq!(snapshot.state, GameState::AwaitingConfirmation);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// rejecting every guess goes back to asking questions
+    async fn test_reject_all() -> Result<()> {
+        let mut akinator = Server::new().akinator()?;
+
+        akinator.start().await?;
+        akinator.win().await?;
+        akinator.reject_guess("1");
+
+        assert_eq!(akinator.state(), GameState::InProgress);
+        assert!(matches!(akinator.confirm_guess(), Err(Error::WrongState { .. })));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::Result;
+    use akinator_rs::statistics::{AnswerCounts, GameStatistics};
+
+    #[test]
+    /// a game that has not been started has empty statistics
+    fn test_empty_statistics() -> Result<()> {
+        assert_eq!(Akinator::new()?.statistics(), GameStatistics::default());
+
+        Ok(())
+    }
+
+    #[test]
+    /// answer counts are retrieved per answer and totalled
+    fn test_answer_counts() {
+        let counts = AnswerCounts { yes: 3, no: 2, probably_not: 1, ..AnswerCounts::default() };
+
+        assert_eq!(counts.get(Answer::Yes), 3);
+        assert_eq!(counts.get(Answer::Idk), 0);
+        assert_eq!(counts.total(), 6);
+    }
+}
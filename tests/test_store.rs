@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::Result;
+    use akinator_rs::store::{MemoryStore, SessionStore};
+
+    #[tokio::test]
+    /// games are stored by key until they are deleted or expire
+    async fn test_memory_store() -> Result<()> {
+        let store = MemoryStore::new();
+        let mut akinator = Akinator::new()?;
+        akinator.step = 3;
+        let game = akinator.save_state()?;
+
+        store.put("kept", &game, Duration::MAX).await?;
+        store.put("deleted", &game, Duration::from_secs(60)).await?;
+        store.put("expired", &game, Duration::ZERO).await?;
+
+        let kept = store.get("kept").await?.expect("game should be stored");
+        assert_eq!(Akinator::from_state(kept)?.step, 3);
+        assert!(store.get("missing").await?.is_none());
+
+        store.delete("deleted").await?;
+        assert!(store.get("deleted").await?.is_none());
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.evict_expired(), 1);
+        assert!(store.get("expired").await?.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::Result;
+    use akinator_rs::strategy::{AnswerStrategy, Always, Random, Scripted};
+
+    #[test]
+    /// the built-in strategies give the expected answers
+    fn test_strategies() -> Result<()> {
+        let akinator = Akinator::new()?;
+
+        assert_eq!(Always(Answer::No).answer("", &akinator), Some(Answer::No));
+
+        let mut scripted = Scripted::new([Answer::Yes, Answer::Idk]);
+        assert_eq!(scripted.answer("", &akinator), Some(Answer::Yes));
+        assert_eq!(scripted.answer("", &akinator), Some(Answer::Idk));
+        assert_eq!(scripted.answer("", &akinator), None);
+
+        let answers = |mut random: Random| {
+            (0..10)
+                .map(|_| random.answer("", &akinator))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(answers(Random::with_seed(42)), answers(Random::with_seed(42)));
+
+        Ok(())
+    }
+}
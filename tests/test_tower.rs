@@ -0,0 +1,33 @@
+#![cfg(feature = "tower")]
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use akinator_rs::Akinator;
+    use akinator_rs::error::{Error, Result};
+    use tower::ServiceBuilder;
+
+    #[tokio::test]
+    /// requests go through the provided tower layers
+    async fn test_with_layer() -> Result<()> {
+        let mut akinator = Akinator::new()?
+            .with_layer(
+                ServiceBuilder::new()
+                    .timeout(Duration::ZERO)
+                    .map_future(|future| async move {
+                        tokio::time::sleep(Duration::from_secs(5)).await;
+                        future.await
+                    }),
+            );
+
+        let err = akinator.start().await.unwrap_err();
+
+        assert!(
+            matches!(err.root(), Error::TransportError(err) if err.is::<tower::timeout::error::Elapsed>()),
+            "{err:?}",
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::Akinator;
+    use akinator_rs::enums::{Answer, Language, Theme};
+    use akinator_rs::error::Result;
+    use akinator_rs::transcript::{Action, Transcript, TranscriptStep};
+    use reqwest::StatusCode;
+
+    use crate::common::Server;
+
+    /// plays a game answering yes, going back then answering no, and returns its transcript
+    async fn record() -> Result<Transcript> {
+        let mut akinator = Server::new().akinator()?;
+
+        akinator.start().await?;
+        akinator.answer(Answer::Yes).await?;
+        akinator.back().await?;
+        akinator.answer(Answer::No).await?;
+        akinator.win().await?;
+
+        Ok(akinator.transcript())
+    }
+
+    #[test]
+    /// transcripts round-trip through JSON
+    fn test_transcript_json() -> Result<()> {
+        let mut transcript = Akinator::new()?
+            .with_language(Language::French)
+            .with_theme(Theme::Animals)
+            .transcript();
+
+        transcript.steps.push(TranscriptStep {
+            step: 0,
+            question: Some("Is your animal a mammal?".to_string()),
+            action: Action::Answer(Answer::ProbablyNot),
+            progression: 12.5,
+            elapsed_ms: 1500,
+            latency_ms: 200,
+        });
+
+        let json = transcript.to_json()?;
+        assert!(json.contains(r#""language": "french""#));
+        assert!(json.contains(r#""answer": "probably_not""#));
+
+        assert_eq!(Transcript::from_json(&json)?, transcript);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// replaying a transcript re-submits its steps, comparing the questions and best guesses,
+    /// with the game taking the transcript's configuration
+    async fn test_replay() -> Result<()> {
+        let mut transcript = record().await?;
+        transcript.language = Language::French;
+
+        let mut akinator = Server::new().akinator()?;
+        let report = akinator.replay(&transcript).await?;
+
+        assert_eq!(report.divergence, None);
+        assert_eq!(report.failure, None);
+        assert_eq!(report.steps_replayed, 3);
+        assert_eq!(report.guess_matches, Some(true));
+        assert_eq!(report.transcript.steps.len(), 3);
+        assert_eq!(akinator.language, Language::French);
+
+        transcript.steps[2].question = Some("Another question".to_string());
+
+        let report = Server::new().akinator()?.replay(&transcript).await?;
+        let divergence = report.divergence.expect("the replay should diverge");
+        assert_eq!(divergence.index, Some(2));
+        assert_eq!(divergence.actual.as_deref(), Some("Question 0"));
+        assert_eq!(report.steps_replayed, 2);
+        assert_eq!(report.guess_matches, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// a failing step stops the replay, which is reported along with the steps replayed so far
+    async fn test_replay_failure() -> Result<()> {
+        let transcript = record().await?;
+
+        let report = Server::new()
+            .with_response("/ws/cancel_answer", StatusCode::OK, r#"cb({"completion": "WARN - NO QUESTION"})"#)
+            .akinator()?
+            .replay(&transcript)
+            .await?;
+
+        let failure = report.failure.expect("the replay should fail");
+        assert_eq!(failure.index, Some(1));
+        assert_eq!(failure.kind, "NoMoreQuestions");
+        assert_eq!(report.divergence, None);
+        assert_eq!(report.steps_replayed, 1);
+        assert_eq!(report.guess_matches, None);
+        assert_eq!(report.transcript.steps.len(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use akinator_rs::enums::Answer;
+    use akinator_rs::error::{Error, Result};
+    use akinator_rs::typestate::Game;
+    use reqwest::StatusCode;
+
+    use crate::common::Server;
+
+    #[tokio::test]
+    /// a game moves through each state, exposing only the calls valid in it
+    async fn test_typestate() -> Result<()> {
+        let akinator = Server::new().akinator()?;
+
+        let mut game = Game::new(akinator).start().await?;
+        assert_eq!(game.question(), "Question 0");
+
+        while !game.progression.is_confident(80.0) {
+            game.answer(Answer::Yes).await?;
+        }
+        assert_eq!(game.question(), "Question 2");
+
+        let game = game.win().await?;
+        assert_eq!(game.result().first.as_ref().map(|guess| guess.name.as_str()), Some("Name"));
+
+        let game = game.resume();
+        assert!(game.rejected_guesses().contains("1"));
+
+        let game = game.win().await?;
+        assert!(game.result().first.is_none());
+
+        let game = game.reset().start().await?;
+        assert_eq!(game.step, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// a failed transition gives back the game in its previous state
+    async fn test_transition_error() -> Result<()> {
+        let akinator = Server::new().akinator()?;
+
+        let game = Game::new(akinator).start().await?;
+        let unavailable = Server::new().with_response("/new_session", StatusCode::SERVICE_UNAVAILABLE, "");
+        let game = Game::new(game.into_inner().with_backend(unavailable));
+        let err = game.start().await.unwrap_err();
+
+        assert!(matches!(err.error.root(), Error::HttpStatus { .. }), "{err:?}");
+        assert_eq!(err.game.step, 0);
+        assert!(err.game.current_question.is_none());
+
+        Ok(())
+    }
+}